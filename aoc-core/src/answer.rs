@@ -0,0 +1,97 @@
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+use serde::{Serialize, Serializer};
+
+/// Result of a puzzle part. Almost all answers are integers, the rest is
+/// kept as text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::Number(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Number(value as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value).map_or_else(|_| Self::Text(value.to_string()), Self::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// Integers become [`Answer::Number`], anything else is kept as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse()
+            .map_or_else(|_| Self::Text(s.to_string()), Self::Number))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => n.fmt(f),
+            Self::Text(s) => s.fmt(f),
+        }
+    }
+}
+
+/// Numbers are serialized as numbers, text as strings.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Number(n) => serializer.serialize_i128(*n),
+            Self::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(42u64), Answer::Number(42));
+        assert_eq!(Answer::from(-1i64), Answer::Number(-1));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("-12".parse(), Ok(Answer::Number(-12)));
+        assert_eq!("A1".parse(), Ok(Answer::Text("A1".to_string())));
+    }
+}
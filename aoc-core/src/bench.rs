@@ -0,0 +1,132 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+
+use crate::{Answer, Run};
+
+/// Spread of a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Returns `None` when there are no samples.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+
+        let median = match samples.len() {
+            0 => return None,
+            n if n % 2 == 1 => samples[n / 2],
+            n => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        };
+
+        Some(Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+/// Timings of one part over repeated runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub answer: Answer,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Calls `run` up to `runs` times, stopping early once `budget` is spent.
+///
+/// At least one run is always done, so slow days still get a measurement.
+/// Every run must give the same answer.
+pub fn bench(runs: usize, budget: Duration, mut run: impl FnMut() -> Result<Run>) -> Result<Bench> {
+    let start = Instant::now();
+    let mut answer = None;
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+
+    while parse.is_empty() || (parse.len() < runs && start.elapsed() < budget) {
+        let result = run()?;
+
+        match &answer {
+            None => answer = Some(result.answer),
+            Some(answer) if *answer != result.answer => {
+                return Err(anyhow!(
+                    "Answer changed between runs: {answer} vs {}",
+                    result.answer
+                ))
+            }
+            Some(_) => {}
+        }
+
+        parse.push(result.parse);
+        solve.push(result.solve);
+    }
+
+    Ok(Bench {
+        answer: answer.ok_or(anyhow!("No runs"))?,
+        runs: parse.len(),
+        parse: Stats::new(&parse).ok_or(anyhow!("No runs"))?,
+        solve: Stats::new(&solve).ok_or(anyhow!("No runs"))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::new(&[]), None);
+
+        let stats = Stats::new(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(5)));
+
+        let stats = Stats::new(&[ms(4), ms(1), ms(2), ms(8)]).unwrap();
+        assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(8)));
+    }
+
+    #[test]
+    fn test_bench() {
+        let mut count = 0;
+        let bench = bench(3, Duration::MAX, || {
+            count += 1;
+
+            Ok(Run {
+                answer: Answer::from(42),
+                parse: ms(count),
+                solve: ms(10 * count),
+            })
+        })
+        .unwrap();
+
+        assert_eq!(bench.runs, 3);
+        assert_eq!(bench.answer, Answer::Number(42));
+        assert_eq!(bench.parse.median, ms(2));
+        assert_eq!(bench.solve.max, ms(30));
+    }
+
+    #[test]
+    fn test_bench_budget() {
+        let bench = bench(100, Duration::ZERO, || {
+            Ok(Run {
+                answer: Answer::from(1),
+                parse: ms(1),
+                solve: ms(1),
+            })
+        })
+        .unwrap();
+
+        assert_eq!(bench.runs, 1);
+    }
+}
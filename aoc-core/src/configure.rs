@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::{solution, Part, Run, Solution};
+
+/// Command line setting of a day, such as `--dictionary german`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Setting {
+    pub name: &'static str,
+    /// Name of the value in the help, `None` for a switch taking no value.
+    pub value: Option<&'static str>,
+    pub help: &'static str,
+}
+
+impl Setting {
+    /// Setting given as `--name VALUE` or `--name=VALUE`.
+    pub const fn value(name: &'static str, value: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            value: Some(value),
+            help,
+        }
+    }
+
+    /// Setting given as `--name` alone.
+    pub const fn switch(name: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            value: None,
+            help,
+        }
+    }
+}
+
+/// Settings given on the command line, see [`Configure`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    values: BTreeMap<String, String>,
+}
+
+impl Settings {
+    /// Value of the setting `name`, if it was given.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// Whether the setting `name` was given, such as a switch.
+    pub fn is_set(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Reads `--name VALUE`, `--name=VALUE` and `--switch` arguments of the
+    /// `declared` settings.
+    pub fn parse_args<I>(args: I, declared: &[Setting]) -> Result<Self>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut settings = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg, None),
+            };
+
+            let setting = name
+                .strip_prefix("--")
+                .and_then(|name| declared.iter().find(|s| s.name == name))
+                .ok_or_else(|| match declared {
+                    [] => anyhow!("Unexpected argument: {arg}, there are no settings"),
+                    _ => anyhow!(
+                        "Unexpected argument: {arg}, expected one of:\n{}",
+                        usage(declared).trim_end()
+                    ),
+                })?;
+
+            let value = match (setting.value, value) {
+                (Some(_), Some(value)) => value,
+                (Some(_), None) => args
+                    .next()
+                    .map(|value| value.as_ref().to_string())
+                    .ok_or_else(|| anyhow!("Missing value for {name}"))?,
+                (None, Some(_)) => return Err(anyhow!("{name} takes no value")),
+                (None, None) => String::new(),
+            };
+
+            settings.set(setting.name, &value);
+        }
+
+        Ok(settings)
+    }
+}
+
+/// One line per setting with its help, for error messages and listings.
+pub fn usage(declared: &[Setting]) -> String {
+    let flags = declared
+        .iter()
+        .map(|s| match s.value {
+            Some(value) => format!("--{} {value}", s.name),
+            None => format!("--{}", s.name),
+        })
+        .collect::<Vec<_>>();
+
+    let width = flags.iter().map(String::len).max().unwrap_or_default();
+
+    flags
+        .iter()
+        .zip(declared)
+        .map(|(flag, s)| format!("  {flag:width$}  {}\n", s.help))
+        .collect()
+}
+
+/// A day whose input can be read in more than one way, chosen with
+/// [`Setting`]s on the command line.
+pub trait Configure: Solution {
+    /// Settings read by `parse_with`.
+    const SETTINGS: &'static [Setting];
+
+    /// Like [`Solution::parse`], which must give the same result as empty
+    /// `settings`.
+    fn parse_with(input: &str, settings: &Settings) -> Result<Self>;
+}
+
+/// Answer of `part` of `C` for `input` read with `settings`, like
+/// [`run`](crate::run).
+pub fn run_with<C: Configure>(input: &str, part: Part, settings: &Settings) -> Result<Run> {
+    solution::timed(|| C::parse_with(input, settings), part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    /// Sum of the numbers of the input, or their product with `--product`.
+    struct Total {
+        numbers: Vec<u64>,
+        product: bool,
+        offset: u64,
+    }
+
+    impl Solution for Total {
+        const EVENT: &'static str = "test";
+        const DAY: u8 = 1;
+
+        fn parse(input: &str) -> Result<Self> {
+            Self::parse_with(input, &Settings::default())
+        }
+
+        fn part1(&self) -> Result<Answer> {
+            let total = match self.product {
+                true => self.numbers.iter().product::<u64>(),
+                false => self.numbers.iter().sum(),
+            };
+
+            Ok((total + self.offset).into())
+        }
+    }
+
+    impl Configure for Total {
+        const SETTINGS: &'static [Setting] = &[
+            Setting::switch("product", "Multiply instead of adding"),
+            Setting::value("offset", "N", "Added to the total"),
+        ];
+
+        fn parse_with(input: &str, settings: &Settings) -> Result<Self> {
+            Ok(Self {
+                numbers: input
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()?,
+                product: settings.is_set("product"),
+                offset: settings.get("offset").unwrap_or("0").parse()?,
+            })
+        }
+    }
+
+    fn parse(args: &[&str]) -> Result<Settings> {
+        Settings::parse_args(args, Total::SETTINGS)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let settings = parse(&["--product", "--offset", "5"]).unwrap();
+        assert!(settings.is_set("product"));
+        assert_eq!(settings.get("offset"), Some("5"));
+
+        let settings = parse(&["--offset=7"]).unwrap();
+        assert!(!settings.is_set("product"));
+        assert_eq!(settings.get("offset"), Some("7"));
+
+        assert!(parse(&[]).unwrap().is_empty());
+
+        for invalid in [&["--offset"][..], &["--product=1"], &["--sum"], &["offset"]] {
+            assert!(parse(invalid).is_err(), "{invalid:?}");
+        }
+
+        let err = Settings::parse_args(["--product"], &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unexpected argument: --product, there are no settings"
+        );
+    }
+
+    #[test]
+    fn test_usage() {
+        assert_eq!(
+            usage(Total::SETTINGS),
+            "  --product   Multiply instead of adding\n  --offset N  Added to the total\n"
+        );
+    }
+
+    #[test]
+    fn test_run_with() {
+        let settings = parse(&["--product", "--offset=1"]).unwrap();
+
+        assert_eq!(
+            run_with::<Total>("2 3 4", Part::One, &settings)
+                .unwrap()
+                .answer,
+            Answer::from(25u64)
+        );
+        assert_eq!(
+            run_with::<Total>("2 3 4", Part::One, &Settings::default())
+                .unwrap()
+                .answer,
+            Answer::from(9u64)
+        );
+    }
+}
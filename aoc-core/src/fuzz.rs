@@ -0,0 +1,62 @@
+use crate::Solution;
+
+/// Runs `S` on arbitrary bytes, the body of every fuzz target.
+///
+/// Invalid input must be rejected with an error, by `parse` or by one of the
+/// parts, so any panic is a bug.
+pub fn fuzz<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(solution) = S::parse(input) {
+        for &part in S::PARTS {
+            let _ = solution.solve(part);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{anyhow, Result};
+
+    use super::*;
+    use crate::Answer;
+
+    /// Sum of the numbers, the first line being a divisor for part 2.
+    struct Numbers(Vec<u64>);
+
+    impl Solution for Numbers {
+        const EVENT: &'static str = "test";
+        const DAY: u8 = 1;
+
+        fn parse(input: &str) -> Result<Self> {
+            let numbers = input
+                .lines()
+                .map(|line| line.parse())
+                .collect::<Result<_, _>>()?;
+
+            Ok(Self(numbers))
+        }
+
+        fn part1(&self) -> Result<Answer> {
+            Ok(self.0.iter().sum::<u64>().into())
+        }
+
+        fn part2(&self) -> Result<Answer> {
+            let (divisor, rest) = self.0.split_first().ok_or(anyhow!("No divisor"))?;
+
+            rest.iter()
+                .map(|n| n.checked_div(*divisor).ok_or(anyhow!("Division by zero")))
+                .sum::<Result<u64>>()
+                .map(Answer::from)
+        }
+    }
+
+    #[test]
+    fn test_fuzz() {
+        for data in [&b"2\n4\n6"[..], b"", b"0\n1", b"x", b"\xff\xfe"] {
+            fuzz::<Numbers>(data);
+        }
+    }
+}
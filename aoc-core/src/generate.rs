@@ -0,0 +1,199 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::Solution;
+
+/// Size parameter of a generator, such as the width of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    pub help: &'static str,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: u64, help: &'static str) -> Self {
+        Self {
+            name,
+            default,
+            help,
+        }
+    }
+}
+
+/// `name=value` pairs given on the command line, such as `width=100,height=50`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, u64>,
+}
+
+impl Params {
+    /// Value of the parameter `name`.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is neither given nor declared by the generator, which is a
+    /// bug in the generator since [`generate`] fills in the defaults.
+    pub fn get(&self, name: &str) -> u64 {
+        match self.values.get(name) {
+            Some(&value) => value,
+            None => panic!("Undeclared generator parameter: {name}"),
+        }
+    }
+
+    /// Value of `name` as a size, at least `min`.
+    pub fn size(&self, name: &str, min: usize) -> usize {
+        (self.get(name) as usize).max(min)
+    }
+
+    pub fn set(&mut self, name: &str, value: u64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.values
+            .iter()
+            .map(|(name, &value)| (name.as_str(), value))
+    }
+}
+
+impl FromStr for Params {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut params = Self::default();
+
+        for pair in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("Invalid parameter: {pair}, expected name=value"))?;
+
+            let value = value
+                .trim()
+                .parse()
+                .map_err(|e| anyhow!("Invalid value of {}: {e}", name.trim()))?;
+
+            params.set(name.trim(), value);
+        }
+
+        Ok(params)
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (name, value)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+
+            write!(f, "{name}={value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Random puzzle input of a day, for testing how solutions scale.
+///
+/// The output must be accepted by [`Solution::parse`]. Given the same random
+/// numbers and parameters, it must always be the same.
+pub trait Generate: Solution {
+    /// Parameters read by `generate`, with their defaults.
+    const PARAMS: &'static [Param];
+
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String;
+}
+
+/// Input of `G` for `seed`, with `overrides` replacing the default parameters.
+///
+/// The random numbers come from ChaCha8, so the output of a seed doesn't
+/// depend on the platform or the version of `rand`.
+pub fn generate<G: Generate>(seed: u64, overrides: &Params) -> Result<String> {
+    let mut params = Params::default();
+
+    for param in G::PARAMS {
+        params.set(param.name, param.default);
+    }
+
+    for (name, value) in overrides.iter() {
+        if !G::PARAMS.iter().any(|p| p.name == name) {
+            let known = G::PARAMS.iter().map(|p| p.name).collect::<Vec<_>>();
+
+            return Err(anyhow!(
+                "Day {} has no parameter {name}, expected one of: {}",
+                G::DAY,
+                known.join(", ")
+            ));
+        }
+
+        params.set(name, value);
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    Ok(G::generate(&mut rng, &params))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    /// Lines of random numbers.
+    struct Numbers(usize);
+
+    impl Solution for Numbers {
+        const EVENT: &'static str = "test";
+        const DAY: u8 = 1;
+
+        fn parse(input: &str) -> Result<Self> {
+            Ok(Self(input.lines().count()))
+        }
+
+        fn part1(&self) -> Result<Answer> {
+            Ok(self.0.into())
+        }
+    }
+
+    impl Generate for Numbers {
+        const PARAMS: &'static [Param] = &[Param::new("lines", 3, "Number of lines")];
+
+        fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+            (0..params.get("lines"))
+                .map(|_| format!("{}\n", rng.gen_range(0..1000)))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        let params = "width=10, height = 5".parse::<Params>().unwrap();
+
+        assert_eq!(params.get("width"), 10);
+        assert_eq!(params.get("height"), 5);
+        assert_eq!(params.to_string(), "height=5,width=10");
+
+        assert!("width".parse::<Params>().is_err());
+        assert!("width=x".parse::<Params>().is_err());
+        assert_eq!("".parse::<Params>().unwrap(), Params::default());
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Numbers>(7, &Params::default()).unwrap();
+
+        assert_eq!(input.lines().count(), 3);
+        assert_eq!(generate::<Numbers>(7, &Params::default()).unwrap(), input);
+        assert_ne!(generate::<Numbers>(8, &Params::default()).unwrap(), input);
+
+        let params = "lines=10".parse().unwrap();
+        assert_eq!(generate::<Numbers>(7, &params).unwrap().lines().count(), 10);
+
+        let params = "width=10".parse().unwrap();
+        assert!(generate::<Numbers>(7, &params).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    env,
+    ffi::OsStr,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+/// Overrides the directory searched for `EVENT/day-NN.txt` files.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+const INPUTS_DIR: &str = "inputs";
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        event: String,
+        day: u8,
+        searched: Vec<PathBuf>,
+    },
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Stdin(io::Error),
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NotFound { .. } => None,
+            Self::Read { source, .. } => Some(source),
+            Self::Stdin(source) => Some(source),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound {
+                event,
+                day,
+                searched,
+            } => {
+                write!(f, "input for {event} day {day} not found, searched:")?;
+
+                for path in searched {
+                    write!(f, "\n  - {}", path.display())?;
+                }
+
+                write!(
+                    f,
+                    "\npass a path, `-` for stdin, or set {INPUTS_ENV} to the inputs directory"
+                )
+            }
+            Self::Read { path, .. } => write!(f, "cannot read input {}", path.display()),
+            Self::Stdin(_) => write!(f, "cannot read input from stdin"),
+        }
+    }
+}
+
+pub fn file_name(day: u8) -> String {
+    format!("day-{day:02}.txt")
+}
+
+/// `EVENT/day-NN.txt`, where the files of a day are kept within the inputs
+/// and answers directories.
+pub fn relative_path(event: &str, day: u8) -> PathBuf {
+    Path::new(event).join(file_name(day))
+}
+
+/// Reads the puzzle input for `day` of `event`.
+///
+/// - An explicit `path` is read as is, `-` means stdin.
+/// - Otherwise `EVENT/day-NN.txt` is looked up in `$AOC_INPUTS`, or in the
+///   nearest `inputs` directory of the current directory or its ancestors.
+pub fn read(event: &str, day: u8, path: Option<&Path>) -> Result<String, InputError> {
+    match path {
+        Some(path) if path.as_os_str() == OsStr::new("-") => {
+            let mut input = String::new();
+
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+
+            Ok(input)
+        }
+        Some(path) => read_file(path),
+        None => read_file(&locate(event, day)?),
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// SHA-256 of `input` in hex, telling inputs apart in reports.
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Paths where `file` is looked up: `$env` if set, otherwise `dir` in the
+/// current directory and each of its ancestors.
+pub(crate) fn candidates(env: &str, dir: &str, file: &Path) -> Vec<PathBuf> {
+    if let Some(dir) = env::var_os(env) {
+        return vec![PathBuf::from(dir).join(file)];
+    }
+
+    env::current_dir()
+        .map(|cwd| cwd.ancestors().map(|d| d.join(dir).join(file)).collect())
+        .unwrap_or_default()
+}
+
+fn locate(event: &str, day: u8) -> Result<PathBuf, InputError> {
+    let searched = candidates(INPUTS_ENV, INPUTS_DIR, &relative_path(event, day));
+
+    searched
+        .iter()
+        .find(|p| p.is_file())
+        .cloned()
+        .ok_or_else(|| InputError::NotFound {
+            event: event.to_string(),
+            day,
+            searched,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name(7), "day-07.txt");
+        assert_eq!(relative_path("2023", 7), Path::new("2023/day-07.txt"));
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = read("2023", 1, Some(Path::new("does-not-exist.txt"))).unwrap_err();
+
+        assert!(matches!(err, InputError::Read { .. }));
+        assert_eq!(err.to_string(), "cannot read input does-not-exist.txt");
+    }
+}
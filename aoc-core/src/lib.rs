@@ -0,0 +1,27 @@
+//! Common interface implemented by every day, used by the `aoc` runner.
+
+mod answer;
+mod bench;
+mod configure;
+mod fuzz;
+mod generate;
+pub mod input;
+pub mod math;
+mod parse;
+pub mod registry;
+mod render;
+mod report;
+mod simulation;
+mod solution;
+pub mod trace;
+
+pub use answer::Answer;
+pub use bench::{bench, Bench, Stats};
+pub use configure::{run_with, usage, Configure, Setting, Settings};
+pub use fuzz::fuzz;
+pub use generate::{generate, Generate, Param, Params};
+pub use parse::{parse_all, ParseError};
+pub use render::{render, Render};
+pub use report::{Format, Report};
+pub use simulation::{simulate, Simulate, Simulation};
+pub use solution::{main, main_configured, run, Part, Run, Solution};
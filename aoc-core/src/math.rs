@@ -0,0 +1,195 @@
+//! Number theory shared by the days that combine cycles.
+//!
+//! Everything works on 128-bit integers and reports overflow as `None`
+//! instead of wrapping.
+
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, `None` on overflow. `lcm(0, n)` is 0.
+pub fn lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all `values`, 1 when there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = u128>) -> Option<u128> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// Returns `(g, x, y)` with `g = gcd(a, b) >= 0` and `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Inverse of `a` modulo `m`, in `0..m`. `None` unless `a` and `m` are
+/// coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// The set of integers `x` with `x ≡ residue (mod modulus)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Congruence {
+    /// Always in `0..modulus`.
+    pub residue: i128,
+    pub modulus: i128,
+}
+
+impl Congruence {
+    /// `None` when `modulus` is not positive.
+    pub fn new(residue: i128, modulus: i128) -> Option<Self> {
+        (modulus > 0).then(|| Self {
+            residue: residue.rem_euclid(modulus),
+            modulus,
+        })
+    }
+
+    /// Intersection of both sets, for moduli that need not be coprime.
+    ///
+    /// `None` when the congruences contradict each other or the combined
+    /// modulus overflows.
+    pub fn combine(self, other: Self) -> Option<Self> {
+        let (g, p, _) = extended_gcd(self.modulus, other.modulus);
+        let diff = other.residue - self.residue;
+
+        if diff % g != 0 {
+            return None;
+        }
+
+        let modulus = (self.modulus / g).checked_mul(other.modulus)?;
+
+        // self.residue + self.modulus * k solves both for k = diff / g * p.
+        let step = other.modulus / g;
+        let k = ((diff / g) % step).checked_mul(p % step)?.rem_euclid(step);
+        let residue = self.modulus.checked_mul(k)?.checked_add(self.residue)?;
+
+        Self::new(residue, modulus)
+    }
+
+    /// Smallest member that is at least `min`.
+    pub fn first_from(&self, min: i128) -> Option<i128> {
+        let offset = (self.residue - min).rem_euclid(self.modulus);
+
+        min.checked_add(offset)
+    }
+}
+
+/// Chinese Remainder Theorem: the congruence satisfied exactly by the
+/// solutions of all `congruences`.
+///
+/// Moduli need not be coprime. `None` when there is no solution or the
+/// combined modulus overflows, and `Congruence(0, 1)` when there are no
+/// congruences at all.
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    congruences.into_iter().try_fold(
+        Congruence {
+            residue: 0,
+            modulus: 1,
+        },
+        Congruence::combine,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u128::MAX, u128::MAX - 1), None);
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(lcm_all([]), Some(1));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for a in -20..20 {
+            for b in -20..20 {
+                let (g, x, y) = extended_gcd(a, b);
+
+                assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i128);
+                assert_eq!(a * x + b * y, g, "{a} {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(1, 0), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        let c = |r, m| Congruence::new(r, m).unwrap();
+
+        assert_eq!(crt([c(2, 3), c(3, 5), c(2, 7)]), Some(c(23, 105)));
+        assert_eq!(crt([c(3, 4), c(5, 6)]), Some(c(11, 12)));
+        assert_eq!(crt([c(0, 4), c(1, 6)]), None);
+        assert_eq!(crt([]), Some(c(0, 1)));
+    }
+
+    #[test]
+    fn test_crt_brute_force() {
+        for m1 in 1..12 {
+            for m2 in 1..12 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let expected = (0..m1 * m2).find(|x| x % m1 == r1 && x % m2 == r2);
+                        let combined = Congruence::new(r1, m1)
+                            .unwrap()
+                            .combine(Congruence::new(r2, m2).unwrap());
+
+                        assert_eq!(combined.map(|c| c.residue), expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_first_from() {
+        let c = Congruence::new(3, 10).unwrap();
+
+        assert_eq!(c.first_from(0), Some(3));
+        assert_eq!(c.first_from(4), Some(13));
+        assert_eq!(c.first_from(13), Some(13));
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt::Display;
+
+use nom::{
+    error::{Error, ErrorKind},
+    IResult,
+};
+
+/// Parse failure located in the puzzle input.
+///
+/// Displayed as the position, what was expected there and the offending line
+/// with a caret under the column:
+///
+/// ```text
+/// line 2, column 9: expected digits
+///   2 | Game 2: x blue
+///     |         ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The line containing the failure, without its line ending.
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error at byte `offset` of `input`.
+    pub fn new(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let text = input[line_start..].lines().next().unwrap_or_default();
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.trim_end_matches('\r').to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error at the start of `rest`, a slice of `input` as left by a parser.
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        Self::new(input, offset(input, rest), expected)
+    }
+
+    pub fn from_nom(input: &str, err: nom::Err<Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => Self::new(input, input.len(), "more input"),
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::at(input, e.input, expected(e.code)),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "  {number} | {}", self.text)?;
+        write!(f, "  {margin} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+/// Byte offset of `rest` in `input`, falling back to treating `rest` as a
+/// suffix when it points elsewhere.
+fn offset(input: &str, rest: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let position = rest.as_ptr() as usize;
+
+    if (start..=start + input.len()).contains(&position) {
+        position - start
+    } else {
+        input.len().saturating_sub(rest.len())
+    }
+}
+
+fn expected(kind: ErrorKind) -> String {
+    let expected = match kind {
+        ErrorKind::Tag | ErrorKind::TagClosure => "a keyword or separator",
+        ErrorKind::Char => "a specific character",
+        ErrorKind::OneOf => "one of the allowed characters",
+        ErrorKind::NoneOf => "a character other than the excluded ones",
+        ErrorKind::Digit => "digits",
+        ErrorKind::HexDigit => "hexadecimal digits",
+        ErrorKind::OctDigit => "octal digits",
+        ErrorKind::Alpha => "letters",
+        ErrorKind::AlphaNumeric => "letters or digits",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "a line ending",
+        ErrorKind::Eof => "end of input",
+        ErrorKind::Float => "a number",
+        ErrorKind::MapRes | ErrorKind::MapOpt | ErrorKind::Verify => "a valid value",
+        ErrorKind::Alt => "one of the alternatives",
+        ErrorKind::Many1 | ErrorKind::Many1Count | ErrorKind::SeparatedNonEmptyList => {
+            "at least one item"
+        }
+        ref kind => kind.description(),
+    };
+
+    expected.to_string()
+}
+
+/// Runs a nom `parser` over the whole `input`.
+///
+/// Only whitespace may be left over, anything else is reported as an error
+/// where the parser stopped.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    let (rest, output) = parser(input).map_err(|e| ParseError::from_nom(input, e))?;
+
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "end of input"));
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{digit1, newline},
+        combinator::map_res,
+        multi::separated_list1,
+        sequence::preceded,
+    };
+
+    use super::*;
+
+    fn numbers(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list1(newline, preceded(tag("n="), map_res(digit1, str::parse)))(input)
+    }
+
+    #[test]
+    fn test_position() {
+        let input = "n=1\nn=2\nn=x\n";
+        let err = parse_all(input, numbers).unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "n=x");
+    }
+
+    #[test]
+    fn test_nom_error() {
+        let input = "n=1\nn=x";
+        let err = ParseError::from_nom(input, numbers(&input[4..]).unwrap_err());
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected digits\n  2 | n=x\n    |   ^"
+        );
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        assert_eq!(parse_all("n=1\nn=2\n\n", numbers), Ok(vec![1, 2]));
+
+        let err = parse_all("n=1\nn=2\n\nfoo", numbers).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "end of input");
+    }
+}
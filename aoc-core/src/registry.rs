@@ -0,0 +1,251 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{Context, Result};
+
+use crate::{input, Answer, ParseError, Part};
+
+/// Overrides the directory searched for `EVENT/day-NN.txt` answer files.
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+
+const ANSWERS_DIR: &str = "answers";
+
+/// What the puzzle website said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    Ok,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 4] = [Self::Ok, Self::Wrong, Self::TooHigh, Self::TooLow];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    part: Part,
+    verdict: Verdict,
+    answer: Answer,
+}
+
+/// Known answers of one day.
+///
+/// Stored in `answers/EVENT/day-NN.txt` next to the inputs, one
+/// `part verdict answer` line per submission:
+///
+/// ```text
+/// # part verdict answer
+/// 2 too-high 477631455277114
+/// 2 ok 238815727638557
+/// ```
+///
+/// The answer is the rest of the line, so multi-line answers can't be
+/// recorded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+/// Result of comparing a computed answer with the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing is known that contradicts the answer.
+    Unknown,
+    Confirmed,
+    /// Another answer was confirmed.
+    Differs(Answer),
+    /// The same answer was submitted before and rejected.
+    Rejected(Verdict),
+    /// The answer is at least a known too high answer.
+    TooHigh(i128),
+    /// The answer is at most a known too low answer.
+    TooLow(i128),
+}
+
+impl Check {
+    pub fn is_wrong(&self) -> bool {
+        !matches!(self, Self::Unknown | Self::Confirmed)
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "is not confirmed yet"),
+            Self::Confirmed => write!(f, "is confirmed"),
+            Self::Differs(expected) => write!(f, "differs from the confirmed answer {expected}"),
+            Self::Rejected(verdict) => write!(f, "was already rejected as {verdict}"),
+            Self::TooHigh(bound) => write!(f, "is not below {bound}, which is too high"),
+            Self::TooLow(bound) => write!(f, "is not above {bound}, which is too low"),
+        }
+    }
+}
+
+impl Registry {
+    /// Loads the registry of `day` of `event`, empty when it has no file
+    /// yet.
+    pub fn load(event: &str, day: u8) -> Result<Self> {
+        match Self::locate(event, day) {
+            Some(path) => Self::load_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn load_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Cannot read answers {}", path.display()))?;
+
+        content
+            .parse()
+            .with_context(|| format!("Invalid answers {}", path.display()))
+    }
+
+    fn locate(event: &str, day: u8) -> Option<PathBuf> {
+        input::candidates(ANSWERS_ENV, ANSWERS_DIR, &input::relative_path(event, day))
+            .into_iter()
+            .find(|p| p.is_file())
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Check {
+        let entries = self.entries.iter().filter(|e| e.part == part);
+        let mut check = Check::Unknown;
+
+        for entry in entries {
+            let found = match (entry.verdict, &entry.answer, answer) {
+                (Verdict::Ok, expected, _) if expected == answer => Check::Confirmed,
+                (Verdict::Ok, expected, _) => Check::Differs(expected.clone()),
+                (verdict, rejected, _) if rejected == answer => Check::Rejected(verdict),
+                (Verdict::TooHigh, &Answer::Number(bound), &Answer::Number(n)) if n >= bound => {
+                    Check::TooHigh(bound)
+                }
+                (Verdict::TooLow, &Answer::Number(bound), &Answer::Number(n)) if n <= bound => {
+                    Check::TooLow(bound)
+                }
+                _ => continue,
+            };
+
+            // A confirmed answer settles it, otherwise report the first problem.
+            match found {
+                Check::Confirmed | Check::Differs(_) => return found,
+                found if check == Check::Unknown => check = found,
+                _ => {}
+            }
+        }
+
+        check
+    }
+}
+
+impl FromStr for Registry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut entries = Vec::new();
+
+        for line in s.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (part, rest) = line.split_once(' ').unwrap_or((line, ""));
+            let part = part
+                .parse::<u8>()
+                .ok()
+                .and_then(|p| Part::try_from(p).ok())
+                .ok_or_else(|| ParseError::at(s, part, "part 1 or 2"))?;
+
+            let rest = rest.trim_start();
+            let (verdict, answer) = rest.split_once(' ').unwrap_or((rest, ""));
+            let verdict = Verdict::ALL
+                .into_iter()
+                .find(|v| v.name() == verdict)
+                .ok_or_else(|| ParseError::at(s, verdict, "ok, wrong, too-high or too-low"))?;
+
+            let answer = answer.trim();
+            if answer.is_empty() {
+                return Err(ParseError::at(s, answer, "an answer"));
+            }
+
+            entries.push(Entry {
+                part,
+                verdict,
+                answer: answer.parse().unwrap_or_else(|e| match e {}),
+            });
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "# part verdict answer
+1 wrong 12
+1 too-low 10
+1 too-high 100
+2 too-high 477631455277114
+2 ok 238815727638557
+";
+
+    #[test]
+    fn test_check() {
+        let registry = ANSWERS.parse::<Registry>().unwrap();
+        let check = |part, n: i64| registry.check(part, &Answer::from(n));
+
+        assert_eq!(check(Part::One, 50), Check::Unknown);
+        assert_eq!(check(Part::One, 12), Check::Rejected(Verdict::Wrong));
+        assert_eq!(check(Part::One, 10), Check::Rejected(Verdict::TooLow));
+        assert_eq!(check(Part::One, 5), Check::TooLow(10));
+        assert_eq!(check(Part::One, 120), Check::TooHigh(100));
+        assert_eq!(check(Part::Two, 238815727638557), Check::Confirmed);
+        assert_eq!(
+            check(Part::Two, 1),
+            Check::Differs(Answer::from(238815727638557i64))
+        );
+    }
+
+    #[test]
+    fn test_text_answer() {
+        let registry = "1 ok ABC DEF".parse::<Registry>().unwrap();
+
+        assert_eq!(
+            registry.check(Part::One, &"ABC DEF".into()),
+            Check::Confirmed
+        );
+        assert!(registry.check(Part::One, &42.into()).is_wrong());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let err = "1 ok 5\n3 ok 5".parse::<Registry>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = "1 ok 5\n2 maybe 5".parse::<Registry>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::Result;
+use grid::{Grid, Pixel};
+
+use crate::{Part, Solution};
+
+/// Picture of the state behind the answer of a grid based day, such as the
+/// tiles a beam energizes or the path a search found.
+pub trait Render: Solution {
+    fn render(&self, part: Part) -> Result<Grid<Pixel>>;
+}
+
+/// Picture of `part` of `R` for `input`, see [`grid::ImageFormat`] for how it
+/// is shown.
+pub fn render<R: Render>(input: &str, part: Part) -> Result<Grid<Pixel>> {
+    R::parse(input)?.render(part)
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::Rgb;
+
+    use super::*;
+    use crate::Answer;
+
+    /// A row of `#`, coloured by part.
+    struct Bar(usize);
+
+    impl Solution for Bar {
+        const EVENT: &'static str = "test";
+        const DAY: u8 = 1;
+
+        fn parse(input: &str) -> Result<Self> {
+            Ok(Self(input.trim().len()))
+        }
+
+        fn part1(&self) -> Result<Answer> {
+            Ok(self.0.into())
+        }
+    }
+
+    impl Render for Bar {
+        fn render(&self, part: Part) -> Result<Grid<Pixel>> {
+            let colour = match part {
+                Part::One => Rgb::RED,
+                Part::Two => Rgb::BLUE,
+            };
+
+            Ok(Grid::from_rows(vec![vec![
+                Pixel::new('#', colour);
+                self.0
+            ]])?)
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = render::<Bar>("xxx\n", Part::Two).unwrap();
+
+        assert_eq!(grid.to_string(), "###\n");
+        assert_eq!(grid[grid::Position(0, 0)].colour, Rgb::BLUE);
+        assert!(render::<Bar>("", Part::One).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use crate::{input, Answer, Part, Run};
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One [`Report`] per line, as JSON.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(anyhow!("Unknown format: {s}, expected text or json")),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => "text".fmt(f),
+            Self::Json => "json".fmt(f),
+        }
+    }
+}
+
+/// Result of one part for other tools, see [`Format::Json`].
+///
+/// Every field is always present. Durations are in nanoseconds, and
+/// `input_hash` is the SHA-256 of the input, see [`input::hash`]. Fields that
+/// don't apply, such as the answer of a failed part, are `null`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub event: String,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub parse_ns: Option<u128>,
+    pub solve_ns: Option<u128>,
+    pub input_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Report {
+    /// Report of solving `part` of `day` of `event` with `input`.
+    pub fn new(event: &str, day: u8, part: Part, input: &str, result: &Result<Run>) -> Self {
+        let mut report = Self::empty(event, day, part);
+
+        report.input_hash = Some(input::hash(input));
+
+        match result {
+            Ok(run) => {
+                report.answer = Some(run.answer.clone());
+                report.parse_ns = Some(run.parse.as_nanos());
+                report.solve_ns = Some(run.solve.as_nanos());
+            }
+            Err(e) => report.error = Some(format!("{e:#}")),
+        }
+
+        report
+    }
+
+    /// Report of a part that could not run, for example without input.
+    pub fn failed(event: &str, day: u8, part: Part, error: &anyhow::Error) -> Self {
+        Self {
+            error: Some(format!("{error:#}")),
+            ..Self::empty(event, day, part)
+        }
+    }
+
+    fn empty(event: &str, day: u8, part: Part) -> Self {
+        Self {
+            event: event.to_string(),
+            day,
+            part: part.number(),
+            answer: None,
+            parse_ns: None,
+            solve_ns: None,
+            input_hash: None,
+            error: None,
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Single line of JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert_eq!(Format::Text.to_string(), "text");
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let run = Run {
+            answer: Answer::Number(35),
+            parse: Duration::from_nanos(1200),
+            solve: Duration::from_micros(3),
+        };
+
+        let report = Report::new("2023", 5, Part::One, "abc", &Ok(run));
+
+        assert_eq!(
+            report.to_json().unwrap(),
+            "{\"event\":\"2023\",\"day\":5,\"part\":1,\"answer\":35,\"parse_ns\":1200,\"solve_ns\":3000,\
+             \"input_hash\":\"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\",\
+             \"error\":null}"
+        );
+    }
+
+    #[test]
+    fn test_json_error() {
+        let error = anyhow!("z3 not found");
+        let report = Report::new("2023", 24, Part::Two, "", &Err(error));
+
+        assert!(!report.is_ok());
+        assert_eq!(report.answer, None);
+        assert_eq!(report.error.as_deref(), Some("z3 not found"));
+
+        let report = Report::failed("2023", 3, Part::Two, &anyhow!("input not found"));
+        let json = report.to_json().unwrap();
+
+        assert!(json.contains("\"input_hash\":null,\"error\":\"input not found\""));
+    }
+
+    #[test]
+    fn test_json_text_answer() {
+        let report = Report {
+            answer: Some(Answer::Text("a\"b".to_string())),
+            ..Report::empty("2023", 21, Part::Two)
+        };
+
+        assert!(report.to_json().unwrap().contains("\"answer\":\"a\\\"b\""));
+    }
+}
@@ -0,0 +1,99 @@
+use anyhow::Result;
+
+use crate::{Part, Solution};
+
+/// Puzzle state that evolves one step at a time, such as a platform being
+/// tilted or modules reacting to button presses.
+pub trait Simulation {
+    /// Moves to the next step, unless the simulation [`is_done`].
+    ///
+    /// [`is_done`]: Simulation::is_done
+    fn step(&mut self) -> Result<()>;
+
+    /// Current state as text, to be printed as it is.
+    fn state(&self) -> String;
+
+    /// Whether the state reached the one the answer is read from.
+    fn is_done(&self) -> bool;
+}
+
+/// A day that can be followed step by step.
+pub trait Simulate: Solution {
+    /// The simulation behind `part`, at its first step.
+    fn simulation(&self, part: Part) -> Result<Box<dyn Simulation>>;
+}
+
+/// Simulation of `part` of `S` for `input`, see [`Simulate`].
+pub fn simulate<S: Simulate>(input: &str, part: Part) -> Result<Box<dyn Simulation>> {
+    S::parse(input)?.simulation(part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    /// Counts down from the input, by one for part 1 and by two for part 2.
+    struct Countdown(u64);
+
+    impl Solution for Countdown {
+        const EVENT: &'static str = "test";
+        const DAY: u8 = 1;
+
+        fn parse(input: &str) -> Result<Self> {
+            Ok(Self(input.trim().parse()?))
+        }
+
+        fn part1(&self) -> Result<Answer> {
+            Ok(self.0.into())
+        }
+    }
+
+    struct Counter {
+        value: u64,
+        by: u64,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> Result<()> {
+            self.value = self.value.saturating_sub(self.by);
+
+            Ok(())
+        }
+
+        fn state(&self) -> String {
+            self.value.to_string()
+        }
+
+        fn is_done(&self) -> bool {
+            self.value == 0
+        }
+    }
+
+    impl Simulate for Countdown {
+        fn simulation(&self, part: Part) -> Result<Box<dyn Simulation>> {
+            let by = match part {
+                Part::One => 1,
+                Part::Two => 2,
+            };
+
+            Ok(Box::new(Counter { value: self.0, by }))
+        }
+    }
+
+    #[test]
+    fn test_simulate() {
+        let mut simulation = simulate::<Countdown>("3\n", Part::Two).unwrap();
+
+        assert_eq!(simulation.state(), "3");
+        assert!(!simulation.is_done());
+
+        simulation.step().unwrap();
+        simulation.step().unwrap();
+
+        assert_eq!(simulation.state(), "0");
+        assert!(simulation.is_done());
+
+        assert!(simulate::<Countdown>("x", Part::One).is_err());
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    env,
+    ffi::OsString,
+    fmt::Display,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{input, Answer, Configure, Format, Report, Setting, Settings};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            _ => Err(anyhow!("Invalid part: {value}")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.number().fmt(f)
+    }
+}
+
+/// A single day of the calendar.
+///
+/// `parse` turns the puzzle input into the day's state, which is then shared
+/// by both parts. Parts that need to mutate the state work on a copy.
+pub trait Solution: Sized {
+    /// Event the day belongs to, such as `2023` or the name of a private
+    /// puzzle set. Inputs and answers are looked up in a directory named
+    /// after it.
+    const EVENT: &'static str;
+
+    const DAY: u8;
+
+    /// The last day of the event only has one part.
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    fn parse(input: &str) -> Result<Self>;
+
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer> {
+        Err(anyhow!("Day {} has no part 2", Self::DAY))
+    }
+
+    fn solve(&self, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+/// Answer of one part together with the time spent on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+pub fn run<S: Solution>(input: &str, part: Part) -> Result<Run> {
+    timed(|| S::parse(input), part)
+}
+
+/// Times `parse`, and then solving `part` with what it returns.
+pub(crate) fn timed<S: Solution>(parse: impl FnOnce() -> Result<S>, part: Part) -> Result<Run> {
+    let start = Instant::now();
+    let solution = parse()?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solution.solve(part)?;
+    let solve = start.elapsed();
+
+    Ok(Run {
+        answer,
+        parse,
+        solve,
+    })
+}
+
+/// Command line of the per-day binaries:
+/// `[--format text|json] [SETTINGS] [PATH]`, with the `declared` settings.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Args {
+    format: Format,
+    path: Option<PathBuf>,
+    settings: Settings,
+}
+
+impl Args {
+    fn parse(args: impl IntoIterator<Item = OsString>, declared: &[Setting]) -> Result<Self> {
+        let mut parsed = Self::default();
+        let mut settings = Vec::new();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let format = match arg.to_str() {
+                Some("--format") => Some(
+                    args.next()
+                        .ok_or_else(|| anyhow!("Missing value for --format"))?
+                        .to_string_lossy()
+                        .into_owned(),
+                ),
+                Some(s) => s.strip_prefix("--format=").map(str::to_string),
+                None => None,
+            };
+
+            match format {
+                Some(format) => parsed.format = format.parse()?,
+                None if arg.to_str().is_some_and(|s| s.starts_with("--")) => {
+                    let arg = arg.to_string_lossy().into_owned();
+                    let takes_value = declared
+                        .iter()
+                        .any(|s| s.value.is_some() && arg.strip_prefix("--") == Some(s.name));
+
+                    settings.push(arg);
+
+                    if takes_value {
+                        settings.extend(args.next().map(|v| v.to_string_lossy().into_owned()));
+                    }
+                }
+                None if parsed.path.is_none() => parsed.path = Some(PathBuf::from(arg)),
+                None => return Err(anyhow!("Unexpected argument: {}", arg.to_string_lossy())),
+            }
+        }
+
+        parsed.settings = Settings::parse_args(settings, declared)?;
+
+        Ok(parsed)
+    }
+}
+
+/// Entry point shared by the per-day binaries.
+///
+/// The optional argument is the input path, see [`input::read`]. With
+/// `--format json` every part is printed as a [`Report`] on its own line.
+pub fn main<S: Solution>() -> Result<()> {
+    main_with(&[], |input, _| S::parse(input))
+}
+
+/// [`main`] of a day that also takes its settings on the command line, see
+/// [`Configure`].
+pub fn main_configured<C: Configure>() -> Result<()> {
+    main_with(C::SETTINGS, C::parse_with)
+}
+
+fn main_with<S: Solution>(
+    declared: &[Setting],
+    parse: impl Fn(&str, &Settings) -> Result<S>,
+) -> Result<()> {
+    let args = Args::parse(env::args_os().skip(1), declared)?;
+
+    if args.format == Format::Json {
+        return main_json(&args, parse);
+    }
+
+    let input = input::read(S::EVENT, S::DAY, args.path.as_deref())?;
+
+    let solution = parse(&input, &args.settings)?;
+
+    for &part in S::PARTS {
+        println!("Part {part}: {}", solution.solve(part)?);
+    }
+
+    Ok(())
+}
+
+/// Reports every part, even after a failure, and fails at the end if one did.
+fn main_json<S: Solution>(args: &Args, parse: impl Fn(&str, &Settings) -> Result<S>) -> Result<()> {
+    let input = input::read(S::EVENT, S::DAY, args.path.as_deref());
+
+    let reports = S::PARTS.iter().map(|&part| match &input {
+        Ok(input) => {
+            let run = timed(|| parse(input, &args.settings), part);
+
+            Report::new(S::EVENT, S::DAY, part, input, &run)
+        }
+        Err(e) => Report::failed(S::EVENT, S::DAY, part, &anyhow!("{e:#}")),
+    });
+
+    let mut failed = 0;
+
+    for report in reports {
+        println!("{}", report.to_json()?);
+
+        if !report.is_ok() {
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        return Err(anyhow!("{failed} part(s) failed"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args> {
+        Args::parse(args.iter().map(OsString::from), &[])
+    }
+
+    #[test]
+    fn test_args() {
+        assert_eq!(parse(&[]).unwrap(), Args::default());
+
+        let args = parse(&["--format", "json", "input.txt"]).unwrap();
+        assert_eq!(args.format, Format::Json);
+        assert_eq!(args.path, Some(PathBuf::from("input.txt")));
+
+        let args = parse(&["-", "--format=text"]).unwrap();
+        assert_eq!(args.format, Format::Text);
+        assert_eq!(args.path, Some(PathBuf::from("-")));
+    }
+
+    #[test]
+    fn test_args_invalid() {
+        assert!(parse(&["--format"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert!(parse(&["--verbose", "a.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_args_settings() {
+        let declared = [
+            Setting::value("limit", "N", "Largest value"),
+            Setting::switch("strict", "Reject more"),
+        ];
+        let args = ["--limit", "3", "in.txt", "--strict", "--format=json"];
+
+        let args = Args::parse(args.map(OsString::from), &declared).unwrap();
+        assert_eq!(args.format, Format::Json);
+        assert_eq!(args.path, Some(PathBuf::from("in.txt")));
+        assert_eq!(args.settings.get("limit"), Some("3"));
+        assert!(args.settings.is_set("strict"));
+
+        let args = ["--strict", "in.txt"].map(OsString::from);
+        assert_eq!(
+            Args::parse(args, &declared).unwrap().path,
+            Some(PathBuf::from("in.txt"))
+        );
+    }
+}
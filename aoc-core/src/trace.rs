@@ -0,0 +1,216 @@
+use std::{cell::RefCell, fmt::Display, rc::Rc, str::FromStr};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Step a solver took on the way to an answer, see [`emit`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Event {
+    /// What happened, such as `map` for values going through a map.
+    pub kind: &'static str,
+    pub fields: Map<String, Value>,
+}
+
+impl Event {
+    fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            fields: Map::new(),
+        }
+    }
+
+    pub fn field(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.fields.insert(name.to_string(), value.into());
+        self
+    }
+}
+
+/// `kind name=value ...`, with strings unquoted.
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn text(value: &Value) -> String {
+            match value {
+                Value::String(s) => s.clone(),
+                Value::Array(values) => {
+                    format!(
+                        "[{}]",
+                        values.iter().map(text).collect::<Vec<_>>().join(", ")
+                    )
+                }
+                value => value.to_string(),
+            }
+        }
+
+        self.kind.fmt(f)?;
+
+        for (name, value) in &self.fields {
+            write!(f, " {name}={}", text(value))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Kinds of events passed on by [`capture`], parsed from `all` or a comma
+/// separated list of kinds.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter(Option<Vec<String>>);
+
+impl Filter {
+    /// Every kind.
+    pub fn all() -> Self {
+        Self(None)
+    }
+
+    pub fn matches(&self, kind: &str) -> bool {
+        self.0
+            .as_ref()
+            .is_none_or(|kinds| kinds.iter().any(|k| k == kind))
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::all());
+        }
+
+        let kinds = s
+            .split(',')
+            .map(str::trim)
+            .map(|kind| match kind {
+                "" => Err(format!("empty kind in {s:?}")),
+                kind => Ok(kind.to_string()),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self(Some(kinds)))
+    }
+}
+
+struct Tracer {
+    filter: Filter,
+    sink: Box<dyn FnMut(&Event)>,
+}
+
+thread_local! {
+    static TRACER: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+}
+
+/// Passes the event made by `build` to the sink of the enclosing
+/// [`capture`], if its filter accepts `kind`.
+///
+/// Outside of a capture `build` isn't called, so that solvers can trace in
+/// their hot loops. `build` must not emit events itself.
+pub fn emit(kind: &'static str, build: impl FnOnce(Event) -> Event) {
+    TRACER.with_borrow_mut(|tracer| {
+        if let Some(tracer) = tracer.as_mut().filter(|t| t.filter.matches(kind)) {
+            (tracer.sink)(&build(Event::new(kind)));
+        }
+    });
+}
+
+/// Restores the tracer of an outer capture, even when `f` panics.
+struct Restore(Option<Tracer>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        TRACER.set(self.0.take());
+    }
+}
+
+/// Runs `f`, passing the events it emits on the current thread and accepted
+/// by `filter` to `sink`.
+pub fn capture<T>(filter: Filter, sink: impl FnMut(&Event) + 'static, f: impl FnOnce() -> T) -> T {
+    let _restore = Restore(TRACER.replace(Some(Tracer {
+        filter,
+        sink: Box::new(sink),
+    })));
+
+    f()
+}
+
+/// Runs `f` like [`capture`], returning the events it emitted along with
+/// its result.
+pub fn record<T>(filter: Filter, f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    let events = Rc::new(RefCell::new(Vec::new()));
+
+    let result = capture(
+        filter,
+        {
+            let events = events.clone();
+            move |e: &Event| events.borrow_mut().push(e.clone())
+        },
+        f,
+    );
+
+    (result, events.take())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(n: u64) {
+        for i in 0..n {
+            emit("step", |e| e.field("i", i));
+        }
+
+        emit("done", |e| {
+            e.field("steps", n).field("names", vec!["a", "b"])
+        });
+    }
+
+    fn events(filter: &str, f: impl FnOnce()) -> Vec<String> {
+        let ((), events) = record(filter.parse().unwrap(), f);
+
+        events.iter().map(Event::to_string).collect()
+    }
+
+    #[test]
+    fn test_capture() {
+        assert_eq!(
+            events("all", || count(2)),
+            ["step i=0", "step i=1", "done names=[a, b] steps=2"]
+        );
+        assert_eq!(events("done", || count(2)), ["done names=[a, b] steps=2"]);
+        assert!(events("other,kinds", || count(2)).is_empty());
+
+        // Nothing is recorded outside of a capture.
+        count(1);
+    }
+
+    #[test]
+    fn test_nested() {
+        let mut inner = Vec::new();
+
+        let outer = events("step", || {
+            count(1);
+            inner = events("done", || count(1));
+            count(2);
+        });
+
+        assert_eq!(outer, ["step i=0", "step i=0", "step i=1"]);
+        assert_eq!(inner, ["done names=[a, b] steps=1"]);
+    }
+
+    #[test]
+    fn test_json() {
+        let event = Event::new("map").field("from", "seed").field("count", 2);
+
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            "{\"kind\":\"map\",\"fields\":{\"count\":2,\"from\":\"seed\"}}"
+        );
+    }
+
+    #[test]
+    fn test_filter() {
+        assert!("all".parse::<Filter>().unwrap().matches("map"));
+        assert!("map, card".parse::<Filter>().unwrap().matches("card"));
+        assert!(!"map".parse::<Filter>().unwrap().matches("card"));
+        assert!("map,".parse::<Filter>().is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path, time::Duration};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_core::{Bench, Part};
+
+/// Median timings of a previous benchmark, keyed by event, day and part.
+///
+/// Stored as plain text, one `event day part parse_ns solve_ns` line per
+/// part, so that it can be diffed and committed next to the code.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline(BTreeMap<(String, u8, Part), Timing>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl From<&Bench> for Timing {
+    fn from(bench: &Bench) -> Self {
+        Self {
+            parse: bench.parse.median,
+            solve: bench.solve.median,
+        }
+    }
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Cannot read baseline {}", path.display()))?;
+
+        content
+            .parse()
+            .with_context(|| format!("Invalid baseline {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Cannot write baseline {}", path.display()))
+    }
+
+    pub fn get(&self, event: &str, day: u8, part: Part) -> Option<Timing> {
+        self.0.get(&(event.to_string(), day, part)).copied()
+    }
+
+    pub fn insert(&mut self, event: &str, day: u8, part: Part, timing: Timing) {
+        self.0.insert((event.to_string(), day, part), timing);
+    }
+
+    /// Overwrites the timings of the parts present in `other`.
+    pub fn extend(&mut self, other: Self) {
+        self.0.extend(other.0);
+    }
+}
+
+impl std::str::FromStr for Baseline {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut baseline = Self::default();
+
+        let lines = s
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'));
+
+        for (i, line) in lines {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+
+            let [event, day, part, parse, solve] = fields[..] else {
+                return Err(anyhow!("Line {}: expected 5 fields: {line}", i + 1));
+            };
+
+            let number = |s: &str| {
+                s.parse::<u64>()
+                    .with_context(|| format!("Line {}: invalid number: {s}", i + 1))
+            };
+
+            let part = Part::try_from(u8::try_from(number(part)?)?)?;
+            let timing = Timing {
+                parse: Duration::from_nanos(number(parse)?),
+                solve: Duration::from_nanos(number(solve)?),
+            };
+
+            baseline.insert(event, u8::try_from(number(day)?)?, part, timing);
+        }
+
+        Ok(baseline)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# event day part parse_ns solve_ns")?;
+
+        for ((event, day, part), timing) in &self.0 {
+            writeln!(
+                f,
+                "{event} {day} {part} {} {}",
+                timing.parse.as_nanos(),
+                timing.solve.as_nanos()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Relative change from `old` to `new`, `0.25` meaning 25% slower.
+pub fn change(old: Duration, new: Duration) -> f64 {
+    if old.is_zero() {
+        return 0.0;
+    }
+
+    new.as_secs_f64() / old.as_secs_f64() - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut baseline = Baseline::default();
+        let timing = Timing {
+            parse: Duration::from_nanos(1500),
+            solve: Duration::from_millis(22),
+        };
+
+        baseline.insert("2023", 12, Part::Two, timing);
+        baseline.insert("2023", 3, Part::One, timing);
+        baseline.insert("2022", 12, Part::Two, timing);
+
+        let text = baseline.to_string();
+        assert_eq!(
+            text,
+            "# event day part parse_ns solve_ns\n\
+             2022 12 2 1500 22000000\n\
+             2023 3 1 1500 22000000\n\
+             2023 12 2 1500 22000000\n"
+        );
+        assert_eq!(text.parse::<Baseline>().unwrap(), baseline);
+        assert_eq!(baseline.get("2023", 12, Part::Two), Some(timing));
+        assert_eq!(baseline.get("2023", 12, Part::One), None);
+        assert_eq!(baseline.get("2021", 12, Part::Two), None);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!("2023 1 1 100".parse::<Baseline>().is_err());
+        assert!("2023 1 3 100 100".parse::<Baseline>().is_err());
+        assert!("1 1 100 100".parse::<Baseline>().is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let ms = Duration::from_millis;
+
+        assert!((change(ms(100), ms(150)) - 0.5).abs() < 1e-9);
+        assert!((change(ms(100), ms(50)) + 0.5).abs() < 1e-9);
+        assert_eq!(change(ms(0), ms(50)), 0.0);
+    }
+}
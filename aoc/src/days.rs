@@ -0,0 +1,201 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use aoc_core::{
+    Configure, Generate, Param, Params, Part, Render, Run, Setting, Settings, Simulate, Simulation,
+};
+use grid::{Grid, Pixel};
+
+type RenderFn = fn(&str, Part) -> Result<Grid<Pixel>>;
+type SimulateFn = fn(&str, Part) -> Result<Box<dyn Simulation>>;
+type ConfigureFn = fn(&str, Part, &Settings) -> Result<Run>;
+
+/// Type-erased entry of the calendar, so that all days of all events fit
+/// into one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub event: &'static str,
+    pub day: u8,
+    pub parts: &'static [Part],
+    /// Parameters of the input generator.
+    pub params: &'static [Param],
+    /// Settings taken on the command line, see [`Configure`].
+    pub settings: &'static [Setting],
+    run: fn(&str, Part) -> Result<Run>,
+    generate: fn(u64, &Params) -> Result<String>,
+    render: Option<RenderFn>,
+    simulate: Option<SimulateFn>,
+    configure: Option<ConfigureFn>,
+}
+
+impl Day {
+    const fn new<S: Generate>() -> Self {
+        Self {
+            event: S::EVENT,
+            day: S::DAY,
+            parts: S::PARTS,
+            params: S::PARAMS,
+            settings: &[],
+            run: aoc_core::run::<S>,
+            generate: aoc_core::generate::<S>,
+            render: None,
+            simulate: None,
+            configure: None,
+        }
+    }
+
+    /// The same day, which can also draw its state, see [`Render`].
+    const fn rendered<S: Render>(self) -> Self {
+        Self {
+            render: Some(aoc_core::render::<S>),
+            ..self
+        }
+    }
+
+    /// The same day, which can also be followed step by step, see
+    /// [`Simulate`].
+    const fn simulated<S: Simulate>(self) -> Self {
+        Self {
+            simulate: Some(aoc_core::simulate::<S>),
+            ..self
+        }
+    }
+
+    /// The same day, which can also read its input with settings, see
+    /// [`Configure`].
+    const fn configured<S: Configure>(self) -> Self {
+        Self {
+            settings: S::SETTINGS,
+            configure: Some(aoc_core::run_with::<S>),
+            ..self
+        }
+    }
+
+    pub fn run(&self, input: &str, part: Part) -> Result<Run> {
+        (self.run)(input, part)
+    }
+
+    /// Like [`run`](Self::run), reading the input with `settings` of the
+    /// day, see [`aoc_core::run_with`].
+    pub fn run_with(&self, input: &str, part: Part, settings: &Settings) -> Result<Run> {
+        match self.configure {
+            Some(configure) => configure(input, part, settings),
+            None if settings.is_empty() => self.run(input, part),
+            None => Err(anyhow!("{self} takes no settings")),
+        }
+    }
+
+    /// Settings of the day in `args`, such as `--dictionary german`.
+    pub fn settings(&self, args: &[String]) -> Result<Settings> {
+        Settings::parse_args(args, self.settings)
+    }
+
+    /// Random input for `seed`, see [`aoc_core::generate`].
+    pub fn generate(&self, seed: u64, params: &Params) -> Result<String> {
+        (self.generate)(seed, params)
+    }
+
+    /// Picture of `part` for `input`, see [`aoc_core::render`].
+    pub fn render(&self, input: &str, part: Part) -> Result<Grid<Pixel>> {
+        let render = self.render.ok_or(anyhow!("{self} cannot be rendered"))?;
+
+        render(input, part)
+    }
+
+    /// Simulation of `part` for `input`, see [`aoc_core::simulate`].
+    pub fn simulate(&self, input: &str, part: Part) -> Result<Box<dyn Simulation>> {
+        let simulate = self.simulate.ok_or(anyhow!("{self} cannot be simulated"))?;
+
+        simulate(input, part)
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.event, self.day)
+    }
+}
+
+/// Every day of every event, grouped by event and in calendar order.
+pub const DAYS: &[Day] = &[
+    Day::new::<day_01::Day01>().configured::<day_01::Day01>(),
+    Day::new::<day_02::Day02>().configured::<day_02::Day02>(),
+    Day::new::<day_03::Day03>(),
+    Day::new::<day_04::Day04>(),
+    Day::new::<day_05::Day05>(),
+    Day::new::<day_06::Day06>(),
+    Day::new::<day_07::Day07>(),
+    Day::new::<day_08::Day08>(),
+    Day::new::<day_09::Day09>(),
+    Day::new::<day_10::Day10>().rendered::<day_10::Day10>(),
+    Day::new::<day_11::Day11>(),
+    Day::new::<day_12::Day12>(),
+    Day::new::<day_13::Day13>(),
+    Day::new::<day_14::Day14>()
+        .rendered::<day_14::Day14>()
+        .simulated::<day_14::Day14>(),
+    Day::new::<day_15::Day15>(),
+    Day::new::<day_16::Day16>().rendered::<day_16::Day16>(),
+    Day::new::<day_17::Day17>().rendered::<day_17::Day17>(),
+    Day::new::<day_18::Day18>(),
+    Day::new::<day_19::Day19>(),
+    Day::new::<day_20::Day20>().simulated::<day_20::Day20>(),
+    Day::new::<day_21::Day21>().simulated::<day_21::Day21>(),
+    Day::new::<day_22::Day22>().simulated::<day_22::Day22>(),
+    Day::new::<day_23::Day23>().rendered::<day_23::Day23>(),
+    Day::new::<day_24::Day24>(),
+    Day::new::<day_25::Day25>(),
+];
+
+/// Names of the events, in the order of [`DAYS`].
+pub fn events() -> Vec<&'static str> {
+    let mut events: Vec<&str> = Vec::new();
+
+    for day in DAYS {
+        if !events.contains(&day.event) {
+            events.push(day.event);
+        }
+    }
+
+    events
+}
+
+/// Days of `event`, an error naming the known events if there are none.
+pub fn of_event(event: &str) -> Result<Vec<&'static Day>> {
+    let days = DAYS.iter().filter(|d| d.event == event).collect::<Vec<_>>();
+
+    if days.is_empty() {
+        return Err(anyhow!(
+            "Event not found: {event}, expected one of {}",
+            events().join(", ")
+        ));
+    }
+
+    Ok(days)
+}
+
+pub fn find(event: &str, day: u8) -> Result<&'static Day> {
+    of_event(event)?
+        .into_iter()
+        .find(|d| d.day == day)
+        .ok_or(anyhow!("Day not found: {event} day {day}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(events(), ["2023"]);
+        assert_eq!(of_event("2023").unwrap().len(), 25);
+        assert_eq!(find("2023", 8).unwrap().to_string(), "2023 day 8");
+
+        let err = find("2015", 1).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Event not found: 2015, expected one of 2023"
+        );
+        assert!(find("2023", 26).is_err());
+    }
+}
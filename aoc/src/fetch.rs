@@ -0,0 +1,381 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_core::input::{self, InputError};
+use serde::Deserialize;
+
+use crate::days::Day;
+
+/// Where inputs are downloaded from, unless the config says otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides the path of the config file.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+/// Overrides the session token of the config file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Settings of the input downloads, read from `config.toml` in the user's
+/// config directory:
+///
+/// ```toml
+/// session = "53616c74..."
+/// # base_url = "https://adventofcode.com"
+/// # cache_dir = "/somewhere/else"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    /// Defaults to `aoc/inputs` in the user's data directory.
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Loads `$AOC_CONFIG`, or `aoc/config.toml` in the user's config
+    /// directory, empty when there is no such file. `$AOC_SESSION` takes
+    /// precedence over the session of the file.
+    pub fn load() -> Result<Self> {
+        let path = env::var_os(CONFIG_ENV)
+            .map(PathBuf::from)
+            .or_else(|| user_dir("XDG_CONFIG_HOME", ".config").map(|d| d.join("aoc/config.toml")));
+
+        let mut config = match path {
+            Some(path) if path.is_file() => Self::load_file(&path)?,
+            _ => Self::default(),
+        };
+
+        if let Some(session) = env::var_os(SESSION_ENV) {
+            config.session = Some(session.to_string_lossy().into_owned());
+        }
+
+        Ok(config)
+    }
+
+    pub fn load_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Cannot read config {}", path.display()))?;
+
+        toml::from_str(&content).with_context(|| format!("Invalid config {}", path.display()))
+    }
+}
+
+/// `$env`, or `fallback` in the home directory, following the XDG base
+/// directory layout.
+fn user_dir(env: &str, fallback: &str) -> Option<PathBuf> {
+    env::var_os(env)
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))
+}
+
+/// Downloads inputs, keeping each one in a cache so that it is requested
+/// only once.
+///
+/// Inputs differ between accounts, so the cache has one directory per
+/// session token, named after its hash.
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Fetcher {
+    /// Fetcher of the inputs of the account behind `session`, cached in
+    /// `cache_dir`.
+    pub fn new(base_url: &str, session: &str, cache_dir: &Path) -> Self {
+        let user = &input::hash(session)[..16];
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            cache_dir: cache_dir.join(user),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(TIMEOUT)
+                .build(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let session = config.session.as_deref().ok_or(anyhow!(
+            "No session token, set session in the config or {SESSION_ENV}"
+        ))?;
+
+        let cache_dir = match &config.cache_dir {
+            Some(dir) => dir.clone(),
+            None => user_dir("XDG_DATA_HOME", ".local/share")
+                .map(|d| d.join("aoc/inputs"))
+                .ok_or(anyhow!("No data directory, set cache_dir in the config"))?,
+        };
+
+        Ok(Self::new(
+            config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL),
+            session,
+            &cache_dir,
+        ))
+    }
+
+    /// Where the input of `day` of `event` is cached.
+    pub fn cache_path(&self, event: &str, day: u8) -> PathBuf {
+        self.cache_dir.join(input::relative_path(event, day))
+    }
+
+    /// Input of `day` of `event`, downloaded unless it is cached already.
+    pub fn fetch(&self, event: &str, day: u8) -> Result<String> {
+        let path = self.cache_path(event, day);
+
+        if path.is_file() {
+            return fs::read_to_string(&path)
+                .with_context(|| format!("Cannot read cached input {}", path.display()));
+        }
+
+        let input = self.download(event, day)?;
+
+        // Written next to the final path first, so that an interrupted write
+        // is never taken for a cached input.
+        let dir = path.parent().unwrap_or(&self.cache_dir);
+        let partial = path.with_extension("part");
+
+        fs::create_dir_all(dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+        fs::write(&partial, &input)
+            .and_then(|_| fs::rename(&partial, &path))
+            .with_context(|| format!("Cannot cache input {}", path.display()))?;
+
+        Ok(input)
+    }
+
+    fn download(&self, event: &str, day: u8) -> Result<String> {
+        let url = format!("{}/{event}/day/{day}/input", self.base_url);
+
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(400 | 401, _) => {
+                    anyhow!("Session token rejected by {url}, it may have expired")
+                }
+                ureq::Error::Status(404, _) => anyhow!("No input at {url}, is it unlocked yet?"),
+                e => anyhow!(e).context(format!("Cannot download {url}")),
+            })?;
+
+        let input = response
+            .into_string()
+            .with_context(|| format!("Cannot download {url}"))?;
+
+        if input.trim().is_empty() {
+            return Err(anyhow!("Empty input at {url}"));
+        }
+
+        Ok(input)
+    }
+}
+
+/// Input of `day` like [`input::read`], downloaded and cached by a
+/// [`Fetcher`] when it is not found locally and a session token is set.
+pub fn read(day: &Day, path: Option<&Path>) -> Result<String> {
+    let not_found = match input::read(day.event, day.day, path) {
+        Err(e @ InputError::NotFound { .. }) => e,
+        result => return Ok(result?),
+    };
+
+    let config = Config::load()?;
+
+    if config.session.is_none() {
+        return Err(anyhow!(
+            "{not_found}\nor set {SESSION_ENV}, or session in the config, to download it"
+        ));
+    }
+
+    Fetcher::from_config(&config)?.fetch(day.event, day.day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        process,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread::{self, JoinHandle},
+    };
+
+    use tiny_http::{Header, Response};
+
+    use super::*;
+
+    const SESSION: &str = "53616c7465645f5f";
+
+    /// Stand-in for the puzzle website, serving fixture files by URL path to
+    /// requests with the right session cookie.
+    struct Server {
+        server: Arc<tiny_http::Server>,
+        requests: Arc<AtomicUsize>,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl Server {
+        fn start(files: &'static [(&'static str, &'static str)]) -> Self {
+            let server = Arc::new(tiny_http::Server::http("127.0.0.1:0").unwrap());
+            let requests = Arc::new(AtomicUsize::new(0));
+
+            let thread = thread::spawn({
+                let (server, requests) = (server.clone(), requests.clone());
+
+                move || {
+                    for request in server.incoming_requests() {
+                        requests.fetch_add(1, Ordering::SeqCst);
+
+                        let cookie = format!("session={SESSION}");
+                        let logged_in = request
+                            .headers()
+                            .iter()
+                            .any(|h| h.field.equiv("Cookie") && h.value == cookie);
+
+                        let response = match files.iter().find(|(url, _)| *url == request.url()) {
+                            _ if !logged_in => {
+                                Response::from_string("Log in").with_status_code(400)
+                            }
+                            Some((_, content)) => Response::from_string(*content),
+                            None => Response::from_string("Not found").with_status_code(404),
+                        };
+
+                        let header = Header::from_bytes("Content-Type", "text/plain").unwrap();
+                        request.respond(response.with_header(header)).unwrap();
+                    }
+                }
+            });
+
+            Self {
+                server,
+                requests,
+                thread: Some(thread),
+            }
+        }
+
+        fn url(&self) -> String {
+            format!("http://{}", self.server.server_addr())
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Drop for Server {
+        fn drop(&mut self) {
+            self.server.unblock();
+
+            if let Some(thread) = self.thread.take() {
+                thread.join().unwrap();
+            }
+        }
+    }
+
+    const FILES: &[(&str, &str)] = &[
+        (
+            "/2023/day/1/input",
+            include_str!("../../day-01/src/sample-input.txt"),
+        ),
+        (
+            "/2023/day/2/input",
+            include_str!("../../day-02/src/sample-input.txt"),
+        ),
+    ];
+
+    /// Empty directory for the cache of one test.
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn test_fetch_once() {
+        let server = Server::start(FILES);
+        let dir = cache_dir("once");
+        let fetcher = Fetcher::new(&format!("{}/", server.url()), SESSION, &dir);
+
+        assert_eq!(fetcher.fetch("2023", 2).unwrap(), FILES[1].1);
+        assert_eq!(fetcher.fetch("2023", 2).unwrap(), FILES[1].1);
+        assert_eq!(server.requests(), 1);
+
+        let path = fetcher.cache_path("2023", 2);
+        assert!(path.starts_with(&dir));
+        assert!(path.ends_with("2023/day-02.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), FILES[1].1);
+
+        // A new fetcher still finds the cached input.
+        let fetcher = Fetcher::new(&server.url(), SESSION, &dir);
+        assert_eq!(fetcher.fetch("2023", 2).unwrap(), FILES[1].1);
+        assert_eq!(server.requests(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let server = Server::start(FILES);
+        let dir = cache_dir("errors");
+
+        let fetcher = Fetcher::new(&server.url(), SESSION, &dir);
+        let err = fetcher.fetch("2023", 3).unwrap_err();
+        assert!(err.to_string().starts_with("No input at "), "{err}");
+        assert!(!fetcher.cache_path("2023", 3).exists());
+
+        let fetcher = Fetcher::new(&server.url(), "expired", &dir);
+        let err = fetcher.fetch("2023", 1).unwrap_err();
+        assert!(
+            err.to_string().starts_with("Session token rejected"),
+            "{err}"
+        );
+        assert!(!fetcher.cache_path("2023", 1).exists());
+
+        assert_eq!(server.requests(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_cache_per_user() {
+        let server = Server::start(FILES);
+        let dir = cache_dir("users");
+
+        let first = Fetcher::new(&server.url(), SESSION, &dir);
+        let second = Fetcher::new(&server.url(), "another", &dir);
+
+        assert_ne!(first.cache_path("2023", 1), second.cache_path("2023", 1));
+        assert_eq!(first.fetch("2023", 1).unwrap(), FILES[0].1);
+        assert!(!second.cache_path("2023", 1).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_config() {
+        let config: Config =
+            toml::from_str("session = \"abc\"\nbase_url = \"http://localhost\"\n").unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url.as_deref(), Some("http://localhost"));
+        assert_eq!(config.cache_dir, None);
+
+        assert!(toml::from_str::<Config>("token = \"abc\"").is_err());
+        assert!(Fetcher::from_config(&Config::default()).is_err());
+    }
+}
@@ -0,0 +1,756 @@
+mod baseline;
+mod days;
+mod fetch;
+mod pool;
+mod stepper;
+
+use std::{
+    fs,
+    io::{self, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_core::{
+    registry::{Check, Registry},
+    trace, Answer, Format, Params, Part, Report, Run, Settings,
+};
+use clap::{Args, Parser, Subcommand};
+use grid::ImageFormat;
+use serde::Serialize;
+
+use crate::{
+    baseline::{Baseline, Timing},
+    days::{Day, DAYS},
+    fetch::Fetcher,
+    pool::{Outcome, Task},
+    stepper::Stepper,
+};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions, by event and day")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, every day of an event, or every day with `--all`
+    Run(RunArgs),
+
+    /// Time parsing and solving over repeated runs
+    Bench(BenchArgs),
+
+    /// Print random puzzle input, the same for the same seed
+    Gen(GenArgs),
+
+    /// Follow a simulation step by step, reading commands from stdin
+    Step(StepArgs),
+
+    /// Download inputs with the session token of the config, unless cached
+    Fetch(FetchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Event to solve, such as 2023
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    event: Option<String>,
+
+    /// Day to solve, every day of the event in a table when omitted
+    day: Option<u8>,
+
+    /// Part to solve, both parts when omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input, `-` for stdin, defaults to `inputs/EVENT/day-NN.txt`, or
+    /// to a download with the session token of the config
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Solve every day of every event and print a table of answers and
+    /// timings
+    #[arg(long)]
+    all: bool,
+
+    /// Output format, `json` prints one object per part and line
+    #[arg(long, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Draw the state behind the answers instead: `ansi` prints it, `ppm`
+    /// and `svg` write `EVENT-day-NN-part-N.ppm` or `.svg`
+    #[arg(long, requires = "day", conflicts_with = "format")]
+    render: Option<ImageFormat>,
+
+    /// Parts of a table solved at the same time, one per CPU by default
+    #[arg(long, short, conflicts_with = "day")]
+    jobs: Option<NonZeroUsize>,
+
+    /// Give up on a part of a table after this many seconds, no limit by
+    /// default
+    #[arg(long, conflicts_with = "day")]
+    timeout: Option<u64>,
+
+    /// Print the steps the solver takes to stderr, as text or as JSON lines
+    /// with `--format json`, optionally only those of some kinds such as
+    /// `map,card`
+    #[arg(
+        long,
+        value_name = "KINDS",
+        requires = "day",
+        conflicts_with = "render",
+        num_args = 0..=1,
+        default_missing_value = "all"
+    )]
+    trace: Option<trace::Filter>,
+
+    /// Settings of the day after `--`, such as `-- --dictionary german` for
+    /// day 1 of 2023
+    #[arg(
+        last = true,
+        requires = "day",
+        conflicts_with = "render",
+        value_name = "SETTINGS"
+    )]
+    settings: Vec<String>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Event of the day
+    event: String,
+
+    /// Day to generate input for
+    day: u8,
+
+    /// Seed of the random numbers
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Size parameters as `name=value,...`, see `--list`
+    #[arg(long)]
+    params: Option<Params>,
+
+    /// List the parameters of the day with their defaults instead
+    #[arg(long)]
+    list: bool,
+}
+
+#[derive(Args)]
+struct StepArgs {
+    /// Event of the day
+    event: String,
+
+    /// Day to simulate
+    day: u8,
+
+    /// Part to simulate
+    #[arg(default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Puzzle input, `-` for stdin, defaults to `inputs/EVENT/day-NN.txt`, or
+    /// to a download with the session token of the config
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Event to download inputs of
+    event: String,
+
+    /// Day to download, every day of the event when omitted
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Event to benchmark, every event when omitted
+    event: Option<String>,
+
+    /// Day to benchmark, every day of the event when omitted
+    day: Option<u8>,
+
+    /// Part to benchmark, both parts when omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input, `-` for stdin, defaults to `inputs/EVENT/day-NN.txt`, or
+    /// to a download with the session token of the config
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Maximum number of runs per part
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Stop repeating a part after this many seconds, it always runs once
+    #[arg(long, default_value_t = 10)]
+    budget: u64,
+
+    /// Store the median timings in this file, merged with its content
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Compare with the median timings of a saved baseline
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Slowdown in percent reported as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+fn parts(day: &Day, part: Option<u8>) -> Result<Vec<Part>> {
+    match part {
+        Some(part) => {
+            let part = Part::try_from(part)?;
+
+            if !day.parts.contains(&part) {
+                return Err(anyhow!("{day} has no part {part}"));
+            }
+
+            Ok(vec![part])
+        }
+        None => Ok(day.parts.to_vec()),
+    }
+}
+
+/// Days selected by `event` and `day`, every day of every event when both
+/// are omitted.
+fn select(event: Option<&str>, day: Option<u8>) -> Result<Vec<&'static Day>> {
+    match (event, day) {
+        (Some(event), Some(day)) => Ok(vec![days::find(event, day)?]),
+        (Some(event), None) => days::of_event(event),
+        (None, _) => Ok(DAYS.iter().collect()),
+    }
+}
+
+/// Known answers of `day`, which only apply to its default input read
+/// without settings.
+fn registry(day: &Day, input: Option<&Path>, settings: &Settings) -> Result<Registry> {
+    match input {
+        None if settings.is_empty() => Registry::load(day.event, day.day),
+        _ => Ok(Registry::default()),
+    }
+}
+
+/// Warns when `answer` is known to be wrong.
+fn check_answer(registry: &Registry, day: &Day, part: Part, answer: &Answer) {
+    let check = registry.check(part, answer);
+
+    if check.is_wrong() {
+        eprintln!("warning: {day} part {part}: answer {answer} {check}");
+    }
+}
+
+/// Prints `report` as a JSON line, returns whether the part succeeded.
+fn print_report(report: &Report, day: &Day, registry: &Registry) -> Result<bool> {
+    println!("{}", report.to_json()?);
+
+    if let (Ok(part), Some(answer)) = (Part::try_from(report.part), &report.answer) {
+        check_answer(registry, day, part, answer);
+    }
+
+    Ok(report.is_ok())
+}
+
+/// Trace event of `--trace --format json`.
+#[derive(Serialize)]
+struct TraceLine<'a> {
+    event: &'static str,
+    day: u8,
+    part: u8,
+    #[serde(flatten)]
+    trace: &'a trace::Event,
+}
+
+/// Runs `part` of `day` with `settings`, printing the trace events asked
+/// for by `args` to stderr.
+fn run_part(
+    day: &'static Day,
+    part: Part,
+    input: &str,
+    settings: &Settings,
+    args: &RunArgs,
+) -> Result<Run> {
+    let Some(filter) = args.trace.clone() else {
+        return day.run_with(input, part, settings);
+    };
+
+    let format = args.format;
+
+    trace::capture(
+        filter,
+        move |e: &trace::Event| match format {
+            Format::Text => eprintln!("{day} part {part}: {e}"),
+            Format::Json => {
+                let line = TraceLine {
+                    event: day.event,
+                    day: day.day,
+                    part: part.number(),
+                    trace: e,
+                };
+
+                match serde_json::to_string(&line) {
+                    Ok(json) => eprintln!("{json}"),
+                    Err(err) => eprintln!("warning: {day}: cannot write trace {e}: {err}"),
+                }
+            }
+        },
+        || day.run_with(input, part, settings),
+    )
+}
+
+/// JSON output of `run_day`, which keeps going after errors.
+fn run_json(day: &'static Day, args: &RunArgs) -> Result<()> {
+    let settings = day.settings(&args.settings)?;
+    let input = fetch::read(day, args.input.as_deref());
+    let registry = registry(day, args.input.as_deref(), &settings).unwrap_or_else(|e| {
+        eprintln!("warning: {day}: {e:#}");
+        Registry::default()
+    });
+
+    let mut failed = 0;
+
+    for part in parts(day, args.part)? {
+        let report = match &input {
+            Ok(input) => Report::new(
+                day.event,
+                day.day,
+                part,
+                input,
+                &run_part(day, part, input, &settings, args),
+            ),
+            Err(e) => Report::failed(day.event, day.day, part, &anyhow!("{e:#}")),
+        };
+
+        if !print_report(&report, day, &registry)? {
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        return Err(anyhow!("{failed} part(s) failed"));
+    }
+
+    Ok(())
+}
+
+fn run_day(args: RunArgs) -> Result<()> {
+    let event = args.event.as_deref().ok_or(anyhow!("Missing event"))?;
+    let number = args.day.ok_or(anyhow!("Missing day"))?;
+    let day = days::find(event, number)?;
+
+    if args.format == Format::Json {
+        return run_json(day, &args);
+    }
+
+    if let Some(format) = args.render {
+        return render(day, &args, format);
+    }
+
+    let settings = day.settings(&args.settings)?;
+    let input = fetch::read(day, args.input.as_deref())?;
+    let registry = registry(day, args.input.as_deref(), &settings)?;
+
+    for part in parts(day, args.part)? {
+        let run = run_part(day, part, &input, &settings, &args)?;
+
+        let answer = run.answer.to_string();
+        let timings = format!("(parse {:.2?}, solve {:.2?})", run.parse, run.solve);
+
+        // Listings, such as the report of day 1, go below their part.
+        match answer.contains('\n') {
+            true => println!("{day} part {part}: {timings}\n{answer}"),
+            false => println!("{day} part {part}: {answer} {timings}"),
+        }
+
+        check_answer(&registry, day, part, &run.answer);
+    }
+
+    Ok(())
+}
+
+/// Draws each part of `day`, to stdout or to a file in the current
+/// directory depending on `format`.
+fn render(day: &Day, args: &RunArgs, format: ImageFormat) -> Result<()> {
+    let input = fetch::read(day, args.input.as_deref())?;
+
+    for part in parts(day, args.part)? {
+        let image = day.render(&input, part)?.render(format);
+
+        match format.extension() {
+            None => {
+                println!("{day} part {part}:");
+                io::stdout().write_all(&image)?;
+            }
+            Some(extension) => {
+                let path = format!("{}-day-{:02}-part-{part}.{extension}", day.event, day.day);
+
+                fs::write(&path, image).with_context(|| format!("Failed to write {path}"))?;
+
+                println!("{day} part {part}: {path}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Part of a table of [`run_all`], and its input.
+struct Row {
+    day: &'static Day,
+    part: Part,
+    input: Result<Arc<str>, String>,
+    registry: Arc<Registry>,
+}
+
+/// How a part of [`run_all`] went.
+enum Status {
+    Ok(Run),
+    /// Known to be wrong by the registry.
+    Wrong(Run, Check),
+    Error(String),
+    Timeout,
+}
+
+impl Status {
+    fn new(outcome: Outcome<Result<Run>>, row: &Row) -> Self {
+        match outcome {
+            Outcome::Done(Ok(run)) => match row.registry.check(row.part, &run.answer) {
+                check if check.is_wrong() => Self::Wrong(run, check),
+                _ => Self::Ok(run),
+            },
+            Outcome::Done(Err(e)) => Self::Error(format!("{e:#}")),
+            Outcome::Panicked(message) => Self::Error(format!("panicked: {message}")),
+            Outcome::TimedOut => Self::Timeout,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Ok(_) => "ok",
+            Self::Wrong(..) => "wrong",
+            Self::Error(_) => "error",
+            Self::Timeout => "timeout",
+        }
+    }
+}
+
+/// Every day of the event, or of every event with `--all`, solved in
+/// parallel and summed up in a table.
+fn run_all(args: RunArgs) -> Result<()> {
+    let mut rows = Vec::new();
+
+    for day in select(args.event.as_deref(), None)? {
+        let input = fetch::read(day, None)
+            .map(Arc::from)
+            .map_err(|e| format!("{e:#}"));
+        let registry = Arc::new(
+            registry(day, None, &Settings::default()).unwrap_or_else(|e| {
+                eprintln!("warning: {day}: {e:#}");
+                Registry::default()
+            }),
+        );
+
+        for &part in day.parts {
+            rows.push(Row {
+                day,
+                part,
+                input: input.clone(),
+                registry: registry.clone(),
+            });
+        }
+    }
+
+    let tasks = rows
+        .iter()
+        .map(|row| {
+            let (day, part, input) = (row.day, row.part, row.input.clone());
+
+            Box::new(move || day.run(&input.map_err(|e| anyhow!(e))?, part)) as Task<_>
+        })
+        .collect();
+
+    let jobs = args
+        .jobs
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+    let timeout = args.timeout.map(Duration::from_secs);
+
+    let start = Instant::now();
+    let outcomes = pool::run(tasks, jobs, timeout);
+    let elapsed = start.elapsed();
+
+    let statuses = outcomes
+        .into_iter()
+        .zip(&rows)
+        .map(|(outcome, row)| Status::new(outcome, row))
+        .collect::<Vec<_>>();
+
+    match args.format {
+        Format::Json => print_reports(&rows, &statuses, timeout)?,
+        Format::Text => print_table(&rows, &statuses, timeout),
+    }
+
+    let count = |name| statuses.iter().filter(|s| s.name() == name).count();
+
+    eprintln!(
+        "{} part(s) in {elapsed:.2?}: {} ok, {} wrong, {} error, {} timeout",
+        statuses.len(),
+        count("ok"),
+        count("wrong"),
+        count("error"),
+        count("timeout")
+    );
+
+    let failed = count("error") + count("timeout");
+
+    if failed > 0 {
+        return Err(anyhow!("{failed} part(s) failed"));
+    }
+
+    Ok(())
+}
+
+fn print_table(rows: &[Row], statuses: &[Status], timeout: Option<Duration>) {
+    println!(
+        "{:<6}  {:>3}  {:>4}  {:<7}  {:>10}  {:>10}  Answer",
+        "Event", "Day", "Part", "Status", "Parse", "Solve"
+    );
+
+    for (row, status) in rows.iter().zip(statuses) {
+        let (parse, solve, answer) = match status {
+            Status::Ok(run) => (Some(run.parse), Some(run.solve), run.answer.to_string()),
+            Status::Wrong(run, check) => (
+                Some(run.parse),
+                Some(run.solve),
+                format!("{} ({check})", run.answer),
+            ),
+            Status::Error(message) => (None, None, message.clone()),
+            Status::Timeout => (
+                None,
+                None,
+                format!("still running after {:?}", timeout.unwrap_or_default()),
+            ),
+        };
+
+        let duration = |d: Option<Duration>| d.map_or(String::new(), |d| format!("{d:.2?}"));
+
+        println!(
+            "{:<6}  {:>3}  {:>4}  {:<7}  {:>10}  {:>10}  {answer}",
+            row.day.event,
+            row.day.day,
+            row.part,
+            status.name(),
+            duration(parse),
+            duration(solve)
+        );
+    }
+}
+
+fn print_reports(rows: &[Row], statuses: &[Status], timeout: Option<Duration>) -> Result<()> {
+    for (row, status) in rows.iter().zip(statuses) {
+        let (day, part) = (row.day, row.part);
+
+        let report = match (status, &row.input) {
+            (Status::Ok(run) | Status::Wrong(run, _), Ok(input)) => {
+                Report::new(day.event, day.day, part, input, &Ok(run.clone()))
+            }
+            (Status::Error(message), Ok(input)) => {
+                Report::new(day.event, day.day, part, input, &Err(anyhow!("{message}")))
+            }
+            (Status::Timeout, _) => Report::failed(
+                day.event,
+                day.day,
+                part,
+                &anyhow!("still running after {:?}", timeout.unwrap_or_default()),
+            ),
+            (_, Err(e)) => Report::failed(day.event, day.day, part, &anyhow!("{e}")),
+        };
+
+        print_report(&report, day, &row.registry)?;
+    }
+
+    Ok(())
+}
+
+/// Timing differences below this are noise, whatever the relative change.
+const NOISE: Duration = Duration::from_micros(100);
+
+fn is_regression(old: Duration, new: Duration, threshold: f64) -> bool {
+    new.saturating_sub(old) > NOISE && baseline::change(old, new) * 100.0 > threshold
+}
+
+fn bench(args: BenchArgs) -> Result<()> {
+    let days = select(args.event.as_deref(), args.day)?;
+
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    let mut results = Baseline::default();
+    let mut regressions = 0;
+
+    println!(
+        "{:<6}  {:>3}  {:>4}  {:>4}  {:>10} {:>10} {:>10}  {:>10} {:>10} {:>10}  Change",
+        "Event", "Day", "Part", "Runs", "Parse min", "median", "max", "Solve min", "median", "max"
+    );
+
+    for day in days {
+        let input = fetch::read(day, args.input.as_deref());
+
+        for part in parts(day, args.part)? {
+            let result = input
+                .as_ref()
+                .map_err(|e| anyhow!("{e:#}"))
+                .and_then(|input| {
+                    aoc_core::bench(args.runs as usize, Duration::from_secs(args.budget), || {
+                        day.run(input, part)
+                    })
+                });
+
+            let bench = match result {
+                Ok(bench) => bench,
+                Err(e) => {
+                    println!(
+                        "{:<6}  {:>3}  {:>4}  error: {e:#}",
+                        day.event, day.day, part
+                    );
+                    continue;
+                }
+            };
+
+            let timing = Timing::from(&bench);
+            let change = match baseline
+                .as_ref()
+                .and_then(|b| b.get(day.event, day.day, part))
+            {
+                Some(old) => {
+                    let regressed = is_regression(old.parse, timing.parse, args.threshold)
+                        || is_regression(old.solve, timing.solve, args.threshold);
+
+                    if regressed {
+                        regressions += 1;
+                    }
+
+                    format!(
+                        "parse {:+.1}%, solve {:+.1}%{}",
+                        baseline::change(old.parse, timing.parse) * 100.0,
+                        baseline::change(old.solve, timing.solve) * 100.0,
+                        if regressed { "  REGRESSION" } else { "" }
+                    )
+                }
+                None if baseline.is_some() => "not in baseline".to_string(),
+                None => String::new(),
+            };
+
+            println!(
+                "{:<6}  {:>3}  {:>4}  {:>4}  {:>10.2?} {:>10.2?} {:>10.2?}  {:>10.2?} {:>10.2?} {:>10.2?}  {change}",
+                day.event,
+                day.day,
+                part,
+                bench.runs,
+                bench.parse.min,
+                bench.parse.median,
+                bench.parse.max,
+                bench.solve.min,
+                bench.solve.median,
+                bench.solve.max,
+            );
+
+            results.insert(day.event, day.day, part, timing);
+        }
+    }
+
+    if let Some(path) = args.save {
+        let mut saved = if path.exists() {
+            Baseline::load(&path)?
+        } else {
+            Baseline::default()
+        };
+
+        saved.extend(results);
+        saved.save(&path)?;
+    }
+
+    if regressions > 0 {
+        return Err(anyhow!(
+            "{regressions} part(s) slower than the baseline by more than {}%",
+            args.threshold
+        ));
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenArgs) -> Result<()> {
+    let day = days::find(&args.event, args.day)?;
+
+    if args.list {
+        for param in day.params {
+            println!("{:<14} {:>10}  {}", param.name, param.default, param.help);
+        }
+
+        return Ok(());
+    }
+
+    let params = args.params.unwrap_or_default();
+
+    print!("{}", day.generate(args.seed, &params)?);
+
+    Ok(())
+}
+
+fn step(args: StepArgs) -> Result<()> {
+    let day = days::find(&args.event, args.day)?;
+    let part = parts(day, Some(args.part))?[0];
+
+    if args.input.as_deref() == Some(Path::new("-")) {
+        return Err(anyhow!(
+            "Commands are read from stdin, give the input as a file"
+        ));
+    }
+
+    let input = fetch::read(day, args.input.as_deref())?;
+
+    let mut stepper = Stepper::new(|| day.simulate(&input, part))?;
+
+    stepper.run(io::stdin().lock(), io::stdout())?;
+    println!();
+
+    Ok(())
+}
+
+/// Downloads the selected inputs, prints where they are cached.
+fn fetch(args: FetchArgs) -> Result<()> {
+    let fetcher = Fetcher::from_config(&fetch::Config::load()?)?;
+
+    for day in select(Some(&args.event), args.day)? {
+        fetcher.fetch(day.event, day.day)?;
+
+        println!(
+            "{day}: {}",
+            fetcher.cache_path(day.event, day.day).display()
+        );
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) if args.day.is_none() => run_all(args),
+        Command::Run(args) => run_day(args),
+        Command::Bench(args) => bench(args),
+        Command::Gen(args) => generate(args),
+        Command::Step(args) => step(args),
+        Command::Fetch(args) => fetch(args),
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    any::Any,
+    collections::{HashMap, VecDeque},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex, PoisonError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Work given to [`run`].
+pub type Task<T> = Box<dyn FnOnce() -> T + Send>;
+
+/// What became of a task of [`run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Done(T),
+    /// The task panicked, with this message.
+    Panicked(String),
+    /// The task was still running when its time was up.
+    TimedOut,
+}
+
+enum Message<T> {
+    Started(usize, Instant),
+    Finished(usize, Outcome<T>),
+}
+
+type Queue<T> = Arc<Mutex<VecDeque<(usize, Task<T>)>>>;
+
+/// Runs `tasks` on up to `threads` threads, returns their outcomes in the
+/// order of the tasks.
+///
+/// Threads can't be stopped, so a task still running after `timeout` is
+/// left behind: it is reported as timed out right away, and a new thread
+/// takes over the tasks that are left.
+pub fn run<T: Send + 'static>(
+    tasks: Vec<Task<T>>,
+    threads: usize,
+    timeout: Option<Duration>,
+) -> Vec<Outcome<T>> {
+    let count = tasks.len();
+    let queue: Queue<T> = Arc::new(Mutex::new(tasks.into_iter().enumerate().collect()));
+    let (sender, receiver) = mpsc::channel();
+
+    for _ in 0..threads.clamp(1, count.max(1)) {
+        spawn_worker(&queue, &sender);
+    }
+
+    let mut outcomes: Vec<Option<Outcome<T>>> = (0..count).map(|_| None).collect();
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+    let mut left = count;
+
+    while left > 0 {
+        let deadline = timeout
+            .zip(running.values().min())
+            .map(|(t, &start)| start + t);
+
+        // The sender kept here means the channel never disconnects.
+        let message = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match message {
+            Ok(Message::Started(i, start)) => {
+                running.insert(i, start);
+            }
+            Ok(Message::Finished(i, outcome)) => {
+                // Tasks that timed out are no longer waited for.
+                if running.remove(&i).is_some() {
+                    outcomes[i] = Some(outcome);
+                    left -= 1;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let expired = running
+                    .iter()
+                    .filter(|&(_, &start)| timeout.is_some_and(|t| now >= start + t))
+                    .map(|(&i, _)| i)
+                    .collect::<Vec<_>>();
+
+                for i in expired {
+                    running.remove(&i);
+                    outcomes[i] = Some(Outcome::TimedOut);
+                    left -= 1;
+
+                    spawn_worker(&queue, &sender);
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    outcomes.into_iter().flatten().collect()
+}
+
+/// Thread taking tasks from `queue` until it is empty.
+fn spawn_worker<T: Send + 'static>(queue: &Queue<T>, sender: &Sender<Message<T>>) {
+    let (queue, sender) = (queue.clone(), sender.clone());
+
+    thread::spawn(move || loop {
+        let Some((i, task)) = queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front()
+        else {
+            break;
+        };
+
+        if sender.send(Message::Started(i, Instant::now())).is_err() {
+            break;
+        }
+
+        let outcome = match panic::catch_unwind(AssertUnwindSafe(task)) {
+            Ok(value) => Outcome::Done(value),
+            Err(payload) => Outcome::Panicked(panic_message(payload)),
+        };
+
+        if sender.send(Message::Finished(i, outcome)).is_err() {
+            break;
+        }
+    });
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sleep(ms: u64) -> Task<u64> {
+        Box::new(move || {
+            thread::sleep(Duration::from_millis(ms));
+            ms
+        })
+    }
+
+    #[test]
+    fn test_order() {
+        let tasks = [200, 50, 150, 0, 100].map(sleep).into();
+        let start = Instant::now();
+
+        assert_eq!(
+            run(tasks, 5, None),
+            [200, 50, 150, 0, 100].map(Outcome::Done)
+        );
+
+        // Run side by side, not one after the other.
+        assert!(start.elapsed() < Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let tasks = vec![sleep(2000), sleep(0), sleep(10)];
+        let start = Instant::now();
+
+        // The thread left behind is replaced, so the other tasks still run.
+        assert_eq!(
+            run(tasks, 1, Some(Duration::from_millis(100))),
+            [Outcome::TimedOut, Outcome::Done(0), Outcome::Done(10)]
+        );
+        assert!(start.elapsed() < Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_panic() {
+        let tasks: Vec<Task<u64>> = vec![sleep(0), Box::new(|| panic!("boom")), sleep(1)];
+
+        assert_eq!(
+            run(tasks, 2, None),
+            [
+                Outcome::Done(0),
+                Outcome::Panicked("boom".to_string()),
+                Outcome::Done(1)
+            ]
+        );
+        assert!(run::<u64>(vec![], 4, None).is_empty());
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Result};
+use aoc_core::Simulation;
+
+const HELP: &str = "\
+n, next [N]   advance N steps, 1 by default, or press enter
+j, jump K     go to step K, starting over when it is behind
+p, print      print the current state
+h, help       print this list
+q, quit       stop, like the end of the input
+";
+
+/// Command typed at the prompt of [`Stepper::run`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Next(u64),
+    Jump(u64),
+    Print,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut words = s.split_whitespace();
+        let name = words.next().unwrap_or("next");
+        let argument = words.next();
+
+        if let Some(word) = words.next() {
+            return Err(anyhow!("Unexpected argument: {word}"));
+        }
+
+        let number = || {
+            argument
+                .map(str::parse::<u64>)
+                .transpose()
+                .map_err(|e| anyhow!("Invalid argument of {name}: {e}"))
+        };
+
+        let command = match name {
+            "n" | "next" => Self::Next(number()?.unwrap_or(1)),
+            "j" | "jump" => Self::Jump(number()?.ok_or(anyhow!("Missing step to jump to"))?),
+            "p" | "print" => Self::Print,
+            "h" | "help" | "?" => Self::Help,
+            "q" | "quit" => Self::Quit,
+            _ => return Err(anyhow!("Unknown command: {name}, type help for the list")),
+        };
+
+        match (command, argument) {
+            (Self::Print | Self::Help | Self::Quit, Some(_)) => {
+                Err(anyhow!("{name} takes no argument"))
+            }
+            _ => Ok(command),
+        }
+    }
+}
+
+/// Interactive driver of a [`Simulation`], which counts the steps taken.
+///
+/// Simulations only go forward, so jumping back starts a new one.
+pub struct Stepper<F> {
+    start: F,
+    simulation: Box<dyn Simulation>,
+    step: u64,
+}
+
+impl<F> Stepper<F>
+where
+    F: Fn() -> Result<Box<dyn Simulation>>,
+{
+    /// Stepper at the first step of the simulations made by `start`.
+    pub fn new(start: F) -> Result<Self> {
+        Ok(Self {
+            simulation: start()?,
+            start,
+            step: 0,
+        })
+    }
+
+    /// Takes up to `count` steps, fewer when the simulation is done first.
+    pub fn advance(&mut self, count: u64) -> Result<()> {
+        for _ in 0..count {
+            if self.simulation.is_done() {
+                break;
+            }
+
+            self.simulation.step()?;
+            self.step += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Goes to `step`, or to the last step when the simulation ends before.
+    pub fn jump(&mut self, step: u64) -> Result<()> {
+        if step < self.step {
+            self.simulation = (self.start)()?;
+            self.step = 0;
+        }
+
+        self.advance(step - self.step)
+    }
+
+    /// Reads commands from `input` until it ends or `quit`, see `help`.
+    ///
+    /// Invalid commands are reported to `output` and skipped, errors of the
+    /// simulation stop the stepper.
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> Result<()> {
+        write!(output, "{}", self.status())?;
+        output.flush()?;
+
+        for line in input.lines() {
+            match line?.parse() {
+                Ok(Command::Next(count)) => self.advance(count)?,
+                Ok(Command::Jump(step)) => self.jump(step)?,
+                Ok(Command::Print) => write!(output, "{}", self.simulation.state())?,
+                Ok(Command::Help) => write!(output, "{HELP}")?,
+                Ok(Command::Quit) => return Ok(()),
+                Err(e) => writeln!(output, "error: {e}")?,
+            }
+
+            write!(output, "{}", self.status())?;
+            output.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Prompt showing the current step.
+    fn status(&self) -> String {
+        match self.simulation.is_done() {
+            true => format!("step {} (done)> ", self.step),
+            false => format!("step {}> ", self.step),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up to 5.
+    struct Counter(u64);
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> Result<()> {
+            self.0 += 1;
+
+            Ok(())
+        }
+
+        fn state(&self) -> String {
+            format!("count {}\n", self.0)
+        }
+
+        fn is_done(&self) -> bool {
+            self.0 == 5
+        }
+    }
+
+    fn stepper() -> Stepper<impl Fn() -> Result<Box<dyn Simulation>>> {
+        Stepper::new(|| Ok(Box::new(Counter(0)) as Box<dyn Simulation>)).unwrap()
+    }
+
+    #[test]
+    fn test_command() {
+        assert_eq!("".parse::<Command>().unwrap(), Command::Next(1));
+        assert_eq!("n 10".parse::<Command>().unwrap(), Command::Next(10));
+        assert_eq!(" jump  3 ".parse::<Command>().unwrap(), Command::Jump(3));
+        assert_eq!("p".parse::<Command>().unwrap(), Command::Print);
+        assert_eq!("quit".parse::<Command>().unwrap(), Command::Quit);
+
+        for invalid in ["j", "n x", "n 1 2", "p 1", "run"] {
+            assert!(invalid.parse::<Command>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_jump() {
+        let mut stepper = stepper();
+
+        stepper.advance(3).unwrap();
+        assert_eq!(stepper.simulation.state(), "count 3\n");
+
+        stepper.jump(1).unwrap();
+        assert_eq!(stepper.step, 1);
+        assert_eq!(stepper.simulation.state(), "count 1\n");
+
+        stepper.jump(100).unwrap();
+        assert_eq!(stepper.step, 5);
+        assert!(stepper.simulation.is_done());
+    }
+
+    #[test]
+    fn test_run() {
+        let mut output = Vec::new();
+
+        stepper()
+            .run(&b"n 2\np\nx\nj 9\nq\nn\n"[..], &mut output)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "step 0> step 2> count 2\n\
+             step 2> error: Unknown command: x, type help for the list\n\
+             step 2> step 5 (done)> "
+        );
+    }
+}
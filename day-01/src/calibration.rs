@@ -0,0 +1,281 @@
+use std::fmt::Display;
+
+use crate::{find_first_and_last, Matcher, NumberReader};
+
+/// Part of a line a value was read from, by byte position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    fn new(line: &'a str, start: usize, end: usize) -> Self {
+        Self {
+            start,
+            end,
+            text: &line[start..end],
+        }
+    }
+}
+
+/// Value of a line together with where it comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    /// Number of the line, from 1.
+    pub line: usize,
+    pub first: Span<'a>,
+    pub last: Span<'a>,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalibrationError {
+    NotFound { line: usize },
+    TooLarge { line: usize },
+}
+
+type Result<T, E = CalibrationError> = std::result::Result<T, E>;
+
+impl CalibrationError {
+    pub fn line(&self) -> usize {
+        match *self {
+            Self::NotFound { line } | Self::TooLarge { line } => line,
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self {
+            Self::NotFound { .. } => "no value found",
+            Self::TooLarge { .. } => "value too large",
+        }
+    }
+}
+
+impl Display for CalibrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line(), self.reason())
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+/// How the value of a line is read.
+#[derive(Debug, Clone)]
+pub enum Reading {
+    /// The first and last keys of the matcher, such as digits, side by side.
+    Digits(Matcher),
+    /// The first and last numbers added up, see [`NumberReader::line_value`].
+    Numbers(NumberReader),
+}
+
+impl Reading {
+    /// Value of `text`, the line numbered `line`.
+    pub fn calibrate<'a>(&self, line: usize, text: &'a str) -> Result<Calibration<'a>> {
+        let not_found = CalibrationError::NotFound { line };
+        let too_large = CalibrationError::TooLarge { line };
+
+        let (first, last, value) = match self {
+            Self::Digits(matcher) => {
+                let (first, last) = find_first_and_last(text, matcher).map_err(|_| not_found)?;
+                let value = format!("{}{}", first.value, last.value)
+                    .parse()
+                    .map_err(|_| too_large.clone())?;
+
+                (
+                    Span::new(text, first.start, first.end),
+                    Span::new(text, last.start, last.end),
+                    value,
+                )
+            }
+            Self::Numbers(reader) => {
+                let numbers = reader.numbers(text).map_err(|_| too_large.clone())?;
+                let (first, last) = numbers.first().zip(numbers.last()).ok_or(not_found)?;
+
+                (
+                    Span::new(text, first.start, first.end),
+                    Span::new(text, last.start, last.end),
+                    first.value.checked_add(last.value).ok_or(too_large)?,
+                )
+            }
+        };
+
+        Ok(Calibration {
+            line,
+            first,
+            last,
+            value,
+        })
+    }
+
+    /// [`calibrate`](Self::calibrate) of every line of `input`.
+    pub fn calibrations<'a>(
+        &'a self,
+        input: &'a str,
+    ) -> impl Iterator<Item = Result<Calibration<'a>>> + 'a {
+        (1..)
+            .zip(input.lines())
+            .map(|(line, text)| self.calibrate(line, text))
+    }
+
+    /// Sum of the values of the lines of `input`. Lines without a value
+    /// are an error, unless `lenient` where they are skipped.
+    pub fn total(&self, input: &str, lenient: bool) -> Result<u64> {
+        let mut total = 0u64;
+
+        for calibration in self.calibrations(input) {
+            let calibration = match calibration {
+                Ok(calibration) => calibration,
+                Err(_) if lenient => continue,
+                Err(e) => return Err(e),
+            };
+
+            total = total
+                .checked_add(calibration.value)
+                .ok_or(CalibrationError::TooLarge {
+                    line: calibration.line,
+                })?;
+        }
+
+        Ok(total)
+    }
+
+    /// Listing of the lines of `input` with their values, the spans they
+    /// come from marked below, and the total of those that have one:
+    ///
+    /// ```text
+    /// 1  29 | two1nine
+    ///       | ^^^ ^^^^
+    /// 2   - | abc
+    ///       | no value found
+    /// total 29, 1 line(s) skipped
+    /// ```
+    pub fn report(&self, input: &str) -> String {
+        let lines = self
+            .calibrations(input)
+            .zip(input.lines())
+            .collect::<Vec<_>>();
+
+        let number_width = lines.len().to_string().len();
+        let value_width = lines
+            .iter()
+            .filter_map(|(c, _)| c.as_ref().ok())
+            .map(|c| c.value.to_string().len())
+            .max()
+            .unwrap_or(1);
+
+        let mut report = String::new();
+        let mut total = 0u128;
+        let mut skipped = 0;
+
+        for (number, (calibration, text)) in (1..).zip(&lines) {
+            let (value, below) = match calibration {
+                Ok(c) => {
+                    total += u128::from(c.value);
+                    (c.value.to_string(), markers(text, &[c.first, c.last]))
+                }
+                Err(e) => {
+                    skipped += 1;
+                    ("-".to_string(), e.reason().to_string())
+                }
+            };
+
+            report += &format!("{number:>number_width$}  {value:>value_width$} | {text}\n");
+            report += &format!("{:>number_width$}  {:>value_width$} | {below}\n", "", "");
+        }
+
+        report += &format!("total {total}");
+
+        if skipped > 0 {
+            report += &format!(", {skipped} line(s) skipped");
+        }
+
+        report
+    }
+}
+
+/// `^` below the characters of `line` in `spans`.
+fn markers(line: &str, spans: &[Span]) -> String {
+    line.char_indices()
+        .map(
+            |(i, _)| match spans.iter().any(|s| (s.start..s.end).contains(&i)) {
+                true => '^',
+                false => ' ',
+            },
+        )
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::digits_and_words;
+
+    fn reading() -> Reading {
+        Reading::Digits(Matcher::new(&digits_and_words()))
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let reading = reading();
+        let calibration = reading.calibrate(4, "xtwone3").unwrap();
+
+        assert_eq!(calibration.line, 4);
+        assert_eq!(calibration.value, 23);
+        assert_eq!(calibration.first, Span::new("xtwone3", 1, 4));
+        assert_eq!(calibration.first.text, "two");
+        assert_eq!(calibration.last.text, "3");
+
+        assert_eq!(
+            reading.calibrate(5, "nothing"),
+            Err(CalibrationError::NotFound { line: 5 })
+        );
+    }
+
+    #[test]
+    fn test_total() {
+        let reading = reading();
+        let input = "1abc2\nxyz\nthree\n";
+
+        let err = reading.total(input, false).unwrap_err();
+        assert_eq!(err, CalibrationError::NotFound { line: 2 });
+        assert_eq!(err.to_string(), "line 2: no value found");
+
+        assert_eq!(reading.total(input, true).unwrap(), 12 + 33);
+    }
+
+    #[test]
+    fn test_numbers() {
+        let reading = Reading::Numbers(NumberReader::english());
+        let calibration = reading.calibrate(1, "a twenty-one b 100").unwrap();
+
+        assert_eq!(calibration.first.text, "twenty-one");
+        assert_eq!(calibration.last.text, "100");
+        assert_eq!(calibration.value, 121);
+
+        assert_eq!(
+            reading.calibrate(2, "99999999999999999999"),
+            Err(CalibrationError::TooLarge { line: 2 })
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let report = reading().report("two1nine\nabc\nxtwone\n");
+
+        assert_eq!(
+            report,
+            "\
+1  29 | two1nine
+      | ^^^ ^^^^
+2   - | abc
+      | no value found
+3  21 | xtwone
+      |  ^^^^^
+total 50, 1 line(s) skipped"
+        );
+    }
+}
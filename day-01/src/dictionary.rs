@@ -0,0 +1,259 @@
+use std::{collections::HashMap, fmt::Display, fs, ops::RangeInclusive, path::Path, str::FromStr};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_core::ParseError;
+use toml::Spanned;
+
+/// Values words can have, as the first and last of a line are put side by
+/// side.
+const DIGITS: RangeInclusive<u32> = 0..=9;
+
+const EXPECTED_DIGIT: &str = "a digit from 0 to 9";
+
+/// Number words known without a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Builtin {
+    English,
+    German,
+    Czech,
+    /// Roman numerals from `I` to `IX`, in capitals.
+    Roman,
+}
+
+impl Builtin {
+    pub const ALL: [Builtin; 4] = [Self::English, Self::German, Self::Czech, Self::Roman];
+
+    fn entries(self) -> [&'static str; 9] {
+        match self {
+            Self::English => [
+                "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+            ],
+            Self::German => [
+                "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+            ],
+            Self::Czech => [
+                "jedna", "dva", "tři", "čtyři", "pět", "šest", "sedm", "osm", "devět",
+            ],
+            Self::Roman => ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"],
+        }
+    }
+
+    /// The words of the digits `1` to `9`.
+    pub fn words(self) -> HashMap<String, u32> {
+        (1..)
+            .zip(self.entries())
+            .map(|(n, w)| (w.to_string(), n))
+            .collect()
+    }
+}
+
+impl FromStr for Builtin {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|b| b.to_string() == s)
+            .ok_or_else(|| anyhow!("Unknown dictionary: {s}"))
+    }
+}
+
+impl Display for Builtin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::English => "english",
+            Self::German => "german",
+            Self::Czech => "czech",
+            Self::Roman => "roman",
+        }
+        .fmt(f)
+    }
+}
+
+/// Words in a file, either TOML when its extension is `.toml`:
+///
+/// ```toml
+/// one = 1
+/// "dvě" = 2
+/// ```
+///
+/// or one `word value` pair per line otherwise, with `#` starting comments.
+/// Values are digits, from 0 to 9.
+pub fn load(path: &Path) -> Result<HashMap<String, u32>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Cannot read dictionary {}", path.display()))?;
+
+    let words = match path.extension().and_then(|e| e.to_str()) {
+        Some("toml") => parse_toml(&content),
+        _ => parse_text(&content),
+    };
+
+    words.with_context(|| format!("Invalid dictionary {}", path.display()))
+}
+
+/// Words of the `word = value` pairs of `content`.
+pub fn parse_toml(content: &str) -> Result<HashMap<String, u32>> {
+    let entries: HashMap<String, Spanned<u32>> = toml::from_str(content)?;
+
+    entries
+        .into_iter()
+        .map(|(word, value)| match DIGITS.contains(value.get_ref()) {
+            true => Ok((word, value.into_inner())),
+            false => Err(ParseError::new(content, value.span().start, EXPECTED_DIGIT).into()),
+        })
+        .collect()
+}
+
+/// Words of the `word value` lines of `content`.
+pub fn parse_text(content: &str) -> Result<HashMap<String, u32>> {
+    let mut words = HashMap::new();
+
+    for line in content.lines() {
+        let entry = line.split_once('#').map_or(line, |(entry, _)| entry);
+
+        let (word, value) = match entry.split_whitespace().collect::<Vec<_>>()[..] {
+            [] => continue,
+            [word, value] => (word, value),
+            _ => return Err(ParseError::at(content, line, "`word value`").into()),
+        };
+
+        let value = value
+            .parse()
+            .ok()
+            .filter(|value| DIGITS.contains(value))
+            .ok_or_else(|| ParseError::at(content, value, EXPECTED_DIGIT))?;
+
+        insert(&mut words, word, value)?;
+    }
+
+    Ok(words)
+}
+
+/// Adds `word`, unless it is already there with another value.
+fn insert(words: &mut HashMap<String, u32>, word: &str, value: u32) -> Result<()> {
+    match words.insert(word.to_string(), value) {
+        Some(previous) if previous != value => Err(anyhow!(
+            "Conflicting values of {word}: {previous} and {value}"
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Where the words of a dictionary come from, a [`Builtin`] name or else the
+/// path of a file, see [`load`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Builtin(Builtin),
+    File(String),
+}
+
+impl Source {
+    /// Sources of a comma separated list such as `german,roman,words.toml`.
+    pub fn parse_list(s: &str) -> Vec<Self> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| match s.parse() {
+                Ok(builtin) => Self::Builtin(builtin),
+                Err(_) => Self::File(s.to_string()),
+            })
+            .collect()
+    }
+
+    pub fn words(&self) -> Result<HashMap<String, u32>> {
+        match self {
+            Self::Builtin(builtin) => Ok(builtin.words()),
+            Self::File(path) => load(Path::new(path)),
+        }
+    }
+}
+
+/// [`digits`](crate::digits) together with the words of all `sources`.
+pub fn dictionary(sources: &[Source]) -> Result<HashMap<String, u32>> {
+    let mut words = crate::digits();
+
+    for source in sources {
+        for (word, value) in source.words()? {
+            insert(&mut words, &word, value)?;
+        }
+    }
+
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn test_builtin() {
+        assert_eq!(Builtin::German.words()["fünf"], 5);
+        assert_eq!(Builtin::Roman.words()["IX"], 9);
+        assert_eq!("czech".parse::<Builtin>().unwrap(), Builtin::Czech);
+        assert!("klingon".parse::<Builtin>().is_err());
+    }
+
+    #[test]
+    fn test_parse_text() {
+        let words = parse_text("# Numbers\none 1\n\n  dvě\t2  # Czech\n").unwrap();
+
+        assert_eq!(words, HashMap::from([("one".into(), 1), ("dvě".into(), 2)]));
+        assert!(parse_text("one\n").is_err());
+        assert!(parse_text("one 1 2\n").is_err());
+        assert!(parse_text("one x\n").is_err());
+        assert!(parse_text("one 1\none 2\n").is_err());
+    }
+
+    #[test]
+    fn test_digits() {
+        let err = parse_text("one 1\nten 10\n").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.expected, "a digit from 0 to 9");
+
+        let err = parse_toml("uno = 1\ndiez = 10\n").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column), (2, 8));
+
+        assert_eq!(parse_text("zero 0\n").unwrap()["zero"], 0);
+        assert!(parse_toml("minus = -1\n").is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = env::temp_dir().join(format!("day-01-dictionary-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let toml = dir.join("words.toml");
+        fs::write(&toml, "uno = 1\n\"dvě\" = 2\n").unwrap();
+        let text = dir.join("words.txt");
+        fs::write(&text, "uno 1\n").unwrap();
+        let conflict = dir.join("conflict.txt");
+        fs::write(&conflict, "V 4\n").unwrap();
+
+        assert_eq!(load(&toml).unwrap()["dvě"], 2);
+        assert_eq!(load(&text).unwrap()["uno"], 1);
+        assert!(load(&dir.join("missing.txt")).is_err());
+
+        let sources = Source::parse_list(&format!("roman, {}", text.display()));
+        assert_eq!(sources[0], Source::Builtin(Builtin::Roman));
+
+        let words = dictionary(&sources).unwrap();
+        assert_eq!(words.len(), 9 + 9 + 1);
+        assert_eq!(words["uno"], 1);
+
+        let sources = Source::parse_list(&format!("roman,{}", conflict.display()));
+        assert!(dictionary(&sources).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_duplicates() {
+        // The same word with the same value is fine.
+        let words = dictionary(&Source::parse_list("english,english")).unwrap();
+        assert_eq!(words.len(), 18);
+    }
+}
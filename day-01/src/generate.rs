@@ -0,0 +1,63 @@
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day01;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+impl Generate for Day01 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("lines", 1000, "Number of lines"),
+        Param::new("length", 40, "Maximum number of characters per line"),
+    ];
+
+    /// Letters mixed with numerals and spelled out digits, every line has at
+    /// least one numeral so that both parts can solve it.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let length = params.size("length", 1);
+        let mut input = String::new();
+
+        for _ in 0..params.get("lines") {
+            let target = rng.gen_range(1..=length);
+            let mut line = String::new();
+
+            while line.len() < target {
+                match rng.gen_range(0..10) {
+                    0..=1 => line.push(char::from(b'1' + rng.gen_range(0..9))),
+                    2..=3 => line.push_str(WORDS.choose(rng).unwrap()),
+                    _ => line.push(char::from(b'a' + rng.gen_range(0..26))),
+                }
+            }
+
+            let numeral = char::from(b'1' + rng.gen_range(0..9));
+            let at = rng.gen_range(0..=line.len());
+            line.insert(at, numeral);
+
+            input.push_str(&line);
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day01>(seed, &"lines=100".parse().unwrap()).unwrap();
+            let day = Day01::parse(&input).unwrap();
+
+            assert_eq!(input.lines().count(), 100);
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
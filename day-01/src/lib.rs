@@ -0,0 +1,181 @@
+//! Day 1: Trebuchet?!
+//!
+//! Every line of the calibration document hides a value made of its first
+//! and last digit. Part 1 only knows numerals, part 2 also spelled out
+//! digits such as `two`, see [`digits`] and [`digits_and_words`]. Lines
+//! are searched with a [`Matcher`], part 2 can use other words with
+//! `--dictionary`, see [`dictionary`]. With `--numbers`, lines hold whole
+//! numbers instead, see [`NumberReader`]. Lines without a value stop the
+//! sum with their number, see [`Reading`], unless `--lenient` skips them;
+//! `--report` lists where the values come from.
+
+mod calibration;
+pub mod dictionary;
+mod generate;
+mod matcher;
+mod numbers;
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use aoc_core::{Answer, Configure, Part, Setting, Settings, Solution};
+
+pub use calibration::{Calibration, CalibrationError, Reading, Span};
+pub use dictionary::{Builtin, Source};
+pub use matcher::{Match, Matcher, Matches};
+pub use numbers::{Number, NumberReader};
+
+/// Keys of `matcher` found in `haystack` that start first and that end
+/// last, the longest of those starting or ending at the same position, so
+/// that `VIII` is 8 from both sides.
+pub fn find_first_and_last(haystack: &str, matcher: &Matcher) -> Result<(Match, Match)> {
+    let mut matches = matcher.matches(haystack);
+    let found = matches.next().ok_or_else(|| anyhow!("Key not found"))?;
+
+    Ok(matches.fold((found, found), |(first, last), found| {
+        (
+            std::cmp::min_by_key(first, found, |m| (m.start, std::cmp::Reverse(m.end))),
+            std::cmp::max_by_key(last, found, |m| (m.end, std::cmp::Reverse(m.start))),
+        )
+    }))
+}
+
+/// Value of the leftmost key of `matcher` found in `haystack`.
+pub fn find_first(haystack: &str, matcher: &Matcher) -> Result<u32> {
+    Ok(find_first_and_last(haystack, matcher)?.0.value)
+}
+
+/// Value of the key of `matcher` found in `haystack` that ends last.
+pub fn find_last(haystack: &str, matcher: &Matcher) -> Result<u32> {
+    Ok(find_first_and_last(haystack, matcher)?.1.value)
+}
+
+/// Calibration value of a single line, its first and last digit combined.
+pub fn calibration_value(line: &str, matcher: &Matcher) -> Result<u32> {
+    let (first, last) = find_first_and_last(line, matcher)?;
+
+    Ok(format!("{}{}", first.value, last.value).parse::<u32>()?)
+}
+
+/// Sum of the calibration values of all lines, see [`Reading::total`].
+pub fn process(input: &str, map: &HashMap<String, u32>) -> Result<u64> {
+    Ok(Reading::Digits(Matcher::new(map)).total(input, false)?)
+}
+
+/// The numerals `1` to `9`.
+pub fn digits() -> HashMap<String, u32> {
+    (1..=9).map(|n| (n.to_string(), n)).collect()
+}
+
+/// [`digits`] together with their English names, `one` to `nine`.
+pub fn digits_and_words() -> HashMap<String, u32> {
+    Builtin::English
+        .words()
+        .into_iter()
+        .chain(digits())
+        .collect()
+}
+
+pub fn process_part_1(input: &str) -> Result<u64> {
+    process(input, &digits())
+}
+
+pub fn process_part_2(input: &str) -> Result<u64> {
+    process(input, &digits_and_words())
+}
+
+/// The calibration document, lines are only interpreted by the parts.
+pub struct Day01 {
+    input: String,
+    /// Words of part 2, [`digits_and_words`] by default.
+    words: HashMap<String, u32>,
+    /// Whether lines hold whole numbers rather than digits.
+    numbers: bool,
+    /// Whether the answers are listings, see [`Reading::report`].
+    report: bool,
+    /// Whether lines without a value are skipped.
+    lenient: bool,
+}
+
+impl Day01 {
+    /// How the lines are read in `part`.
+    pub fn reading(&self, part: Part) -> Reading {
+        match (part, self.numbers) {
+            (Part::One, false) => Reading::Digits(Matcher::new(&digits())),
+            (Part::Two, false) => Reading::Digits(Matcher::new(&self.words)),
+            (Part::One, true) => Reading::Numbers(NumberReader::numerals()),
+            (Part::Two, true) => Reading::Numbers(NumberReader::english()),
+        }
+    }
+
+    fn answer(&self, part: Part) -> Result<Answer> {
+        let reading = self.reading(part);
+
+        if self.report {
+            return Ok(reading.report(&self.input).into());
+        }
+
+        Ok(reading.total(&self.input, self.lenient)?.into())
+    }
+}
+
+impl Solution for Day01 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, &Settings::default())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        self.answer(Part::One)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        self.answer(Part::Two)
+    }
+}
+
+impl Configure for Day01 {
+    const SETTINGS: &'static [Setting] = &[
+        Setting::value(
+            "dictionary",
+            "LIST",
+            "Words of part 2 besides the digits, built-in (english, german, czech, roman) or \
+             files, such as german,words.toml",
+        ),
+        Setting::switch(
+            "numbers",
+            "Read whole numbers, such as 42 or forty-two in part 2, and add up the first and last \
+             of each line",
+        ),
+        Setting::switch(
+            "report",
+            "Answer with a listing of the lines, their values and where they come from",
+        ),
+        Setting::switch("lenient", "Skip the lines without a value"),
+    ];
+
+    fn parse_with(input: &str, settings: &Settings) -> Result<Self> {
+        let numbers = settings.is_set("numbers");
+
+        if numbers && settings.is_set("dictionary") {
+            return Err(anyhow!(
+                "--numbers only reads English words, not --dictionary"
+            ));
+        }
+
+        let words = match settings.get("dictionary") {
+            Some(list) => dictionary::dictionary(&Source::parse_list(list))?,
+            None => digits_and_words(),
+        };
+
+        Ok(Self {
+            input: input.to_string(),
+            words,
+            numbers,
+            report: settings.is_set("report"),
+            lenient: settings.is_set("lenient"),
+        })
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::{HashMap, VecDeque};
+
+/// Occurrence of a key of a [`Matcher`], by byte position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Transitions by byte, sorted.
+    next: Vec<(u8, usize)>,
+    /// Node of the longest proper suffix that is also a prefix of some key.
+    fail: usize,
+    /// Length and value of the key ending here.
+    key: Option<(usize, u32)>,
+    /// Closest node down the `fail` links where a key ends.
+    output: Option<usize>,
+}
+
+/// Finds all the keys of a dictionary in a single pass over a text, in the
+/// way of Aho-Corasick.
+///
+/// The keys form a trie, linked to the longest suffix of each prefix, so
+/// that the text is read only once however many keys there are.
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    nodes: Vec<Node>,
+}
+
+impl Matcher {
+    /// Matcher of the keys of `map`, empty keys never match.
+    pub fn new(map: &HashMap<String, u32>) -> Self {
+        let mut matcher = Self {
+            nodes: vec![Node::default()],
+        };
+
+        for (key, &value) in map.iter().filter(|(key, _)| !key.is_empty()) {
+            let mut state = 0;
+
+            for &byte in key.as_bytes() {
+                state = match matcher.child(state, byte) {
+                    Ok(child) => child,
+                    Err(index) => {
+                        let child = matcher.nodes.len();
+                        matcher.nodes.push(Node::default());
+                        matcher.nodes[state].next.insert(index, (byte, child));
+                        child
+                    }
+                };
+            }
+
+            matcher.nodes[state].key = Some((key.len(), value));
+        }
+
+        matcher.link();
+        matcher
+    }
+
+    /// Sets the `fail` and `output` links, shallowest nodes first so that
+    /// the links they point to are already set.
+    fn link(&mut self) {
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(parent) = queue.pop_front() {
+            for (byte, node) in self.nodes[parent].next.clone() {
+                let fail = match parent {
+                    0 => 0,
+                    _ => self.step(self.nodes[parent].fail, byte),
+                };
+
+                self.nodes[node].fail = fail;
+                self.nodes[node].output = match self.nodes[fail].key {
+                    Some(_) => Some(fail),
+                    None => self.nodes[fail].output,
+                };
+
+                queue.push_back(node);
+            }
+        }
+    }
+
+    /// Child of `state` by `byte`, or where it would be inserted.
+    fn child(&self, state: usize, byte: u8) -> Result<usize, usize> {
+        let next = &self.nodes[state].next;
+
+        next.binary_search_by_key(&byte, |&(b, _)| b)
+            .map(|index| next[index].1)
+    }
+
+    /// State after reading `byte` in `state`.
+    fn step(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            match self.child(state, byte) {
+                Ok(child) => return child,
+                Err(_) if state == 0 => return 0,
+                Err(_) => state = self.nodes[state].fail,
+            }
+        }
+    }
+
+    /// Every occurrence of the keys in `haystack`, overlapping ones
+    /// included, by end and then from the longest.
+    pub fn matches<'a>(&'a self, haystack: &'a str) -> Matches<'a> {
+        Matches {
+            matcher: self,
+            haystack: haystack.as_bytes(),
+            end: 0,
+            state: 0,
+            pending: None,
+        }
+    }
+}
+
+/// Iterator of [`Matcher::matches`].
+#[derive(Debug, Clone)]
+pub struct Matches<'a> {
+    matcher: &'a Matcher,
+    haystack: &'a [u8],
+    /// Bytes read so far.
+    end: usize,
+    state: usize,
+    /// Node of the next key ending at `end`.
+    pending: Option<usize>,
+}
+
+impl Iterator for Matches<'_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while let Some(node) = self.pending {
+                let node = &self.matcher.nodes[node];
+                self.pending = node.output;
+
+                if let Some((length, value)) = node.key {
+                    return Some(Match {
+                        start: self.end - length,
+                        end: self.end,
+                        value,
+                    });
+                }
+            }
+
+            let &byte = self.haystack.get(self.end)?;
+
+            self.end += 1;
+            self.state = self.matcher.step(self.state, byte);
+            self.pending = Some(self.state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(keys: &[(&str, u32)]) -> Matcher {
+        Matcher::new(&keys.iter().map(|&(k, v)| (k.to_string(), v)).collect())
+    }
+
+    fn found(matcher: &Matcher, haystack: &str) -> Vec<(usize, u32)> {
+        matcher
+            .matches(haystack)
+            .map(|m| (m.start, m.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let matcher = matcher(&[("one", 1), ("two", 2), ("eight", 8), ("2", 2)]);
+
+        assert_eq!(found(&matcher, "twone"), [(0, 2), (2, 1)]);
+        assert_eq!(found(&matcher, "eightwo2"), [(0, 8), (4, 2), (7, 2)]);
+        assert_eq!(found(&matcher, "tw on"), []);
+    }
+
+    #[test]
+    fn test_suffixes() {
+        let matcher = matcher(&[("he", 1), ("she", 2), ("his", 3), ("hers", 4), ("", 5)]);
+
+        assert_eq!(
+            matcher
+                .matches("ushers")
+                .map(|m| (m.start, m.end, m.value))
+                .collect::<Vec<_>>(),
+            [(1, 4, 2), (2, 4, 1), (2, 6, 4)]
+        );
+        assert_eq!(
+            found(&matcher, "ahishers"),
+            [(1, 3), (3, 2), (4, 1), (4, 4)]
+        );
+        assert_eq!(found(&matcher, ""), []);
+    }
+
+    #[test]
+    fn test_naive() {
+        let keys = [
+            ("a", 1),
+            ("ab", 2),
+            ("bab", 3),
+            ("bc", 4),
+            ("é", 5),
+            ("cé", 6),
+        ];
+        let matcher = matcher(&keys);
+        let haystack = "abcabababcébcéaab";
+
+        let mut naive = (0..haystack.len())
+            .filter(|&start| haystack.is_char_boundary(start))
+            .flat_map(|start| {
+                keys.iter()
+                    .filter(move |(key, _)| haystack[start..].starts_with(key))
+                    .map(move |&(_, value)| (start, value))
+            })
+            .collect::<Vec<_>>();
+        naive.sort();
+
+        let mut found = found(&matcher, haystack);
+        found.sort();
+
+        assert_eq!(found, naive);
+    }
+}
@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::Matcher;
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [(&str, u32); 4] = [
+    ("hundred", 100),
+    ("thousand", 1_000),
+    ("million", 1_000_000),
+    ("billion", 1_000_000_000),
+];
+
+/// Whole number found in a line, by byte position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub start: usize,
+    pub end: usize,
+    pub value: u64,
+}
+
+/// Piece of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    /// Digits, a number on their own.
+    Numeral(u64),
+    /// `zero` to `nineteen`.
+    Unit(u64),
+    /// `twenty` to `ninety`.
+    Tens(u64),
+    Hundred,
+    /// `thousand` and up.
+    Scale(u64),
+}
+
+impl Token {
+    fn word(value: u32) -> Self {
+        match value {
+            0..=19 => Self::Unit(value.into()),
+            20..=99 => Self::Tens(value.into()),
+            100 => Self::Hundred,
+            _ => Self::Scale(value.into()),
+        }
+    }
+}
+
+/// Number being read from its tokens, such as `three hundred forty`.
+#[derive(Debug, Clone, Copy)]
+struct Phrase {
+    start: usize,
+    end: usize,
+    /// Value of the scales done with, such as the thousands.
+    total: u64,
+    /// Value below the last scale.
+    current: u64,
+    /// The last scale, later ones must be smaller.
+    scale: Option<u64>,
+    last: Token,
+}
+
+impl Phrase {
+    fn new(start: usize, end: usize, token: Token) -> Self {
+        let (total, current, scale) = match token {
+            Token::Numeral(n) | Token::Unit(n) | Token::Tens(n) => (0, n, None),
+            // `hundred` alone is taken as one hundred, and so on.
+            Token::Hundred => (0, 100, None),
+            Token::Scale(scale) => (scale, 0, Some(scale)),
+        };
+
+        Self {
+            start,
+            end,
+            total,
+            current,
+            scale,
+            last: token,
+        }
+    }
+
+    /// Adds `token`, separated from the phrase by `gap`, unless it starts
+    /// another number. Returns whether it was added.
+    fn push(&mut self, gap: &str, end: usize, token: Token) -> Result<bool> {
+        let after_scale = matches!(self.last, Token::Hundred | Token::Scale(_));
+        let joined = match gap {
+            "" | " " | "-" => true,
+            "and" | " and " => after_scale,
+            _ => false,
+        };
+
+        let extends = joined
+            && match (self.last, token) {
+                (Token::Tens(_), Token::Unit(n)) => (1..=9).contains(&n),
+                (Token::Hundred | Token::Scale(_), Token::Unit(n)) => n > 0,
+                (Token::Hundred | Token::Scale(_), Token::Tens(_)) => true,
+                (Token::Unit(_) | Token::Tens(_), Token::Hundred) => self.current < 100,
+                (Token::Unit(_) | Token::Tens(_) | Token::Hundred, Token::Scale(s)) => {
+                    self.scale.is_none_or(|scale| s < scale)
+                }
+                _ => false,
+            };
+
+        if !extends {
+            return Ok(false);
+        }
+
+        let too_large = || anyhow!("Number too large at {}", self.start);
+
+        match token {
+            Token::Unit(n) | Token::Tens(n) => self.current += n,
+            Token::Hundred => self.current *= 100,
+            Token::Scale(scale) => {
+                self.total = self
+                    .current
+                    .checked_mul(scale)
+                    .and_then(|n| n.checked_add(self.total))
+                    .ok_or_else(too_large)?;
+                self.current = 0;
+                self.scale = Some(scale);
+            }
+            Token::Numeral(_) => unreachable!("numerals never extend a phrase"),
+        }
+
+        self.end = end;
+        self.last = token;
+
+        Ok(true)
+    }
+
+    fn number(&self) -> Number {
+        Number {
+            start: self.start,
+            end: self.end,
+            value: self.total + self.current,
+        }
+    }
+}
+
+/// Reads whole numbers from lines: numerals such as `123`, and optionally
+/// English number words such as `twenty-one` or `three hundred forty`.
+///
+/// Words are taken from the left, the longest first, so that `eighteen` is
+/// not `eight`. They only form one number when next to each other or
+/// separated by a space, a hyphen, or `and` after a scale, and a number
+/// ends before a unit followed by a scale it cannot take, as in `one
+/// thousand two thousand`.
+#[derive(Debug, Clone)]
+pub struct NumberReader {
+    words: Option<Matcher>,
+}
+
+impl NumberReader {
+    /// Reader of numerals only.
+    pub fn numerals() -> Self {
+        Self { words: None }
+    }
+
+    /// Reader of numerals and English number words.
+    pub fn english() -> Self {
+        let units = (0..).zip(UNITS);
+        let tens = (20..).step_by(10).zip(TENS);
+        let scales = SCALES.iter().map(|&(word, value)| (value, word));
+
+        let words = units
+            .chain(tens)
+            .chain(scales)
+            .map(|(value, word)| (word.to_string(), value))
+            .collect::<HashMap<_, _>>();
+
+        Self {
+            words: Some(Matcher::new(&words)),
+        }
+    }
+
+    /// Pieces of numbers in `line`, by position and without overlaps.
+    fn tokens(&self, line: &str) -> Result<Vec<(usize, usize, Token)>> {
+        let mut tokens = Vec::new();
+        let mut digits = None;
+
+        for (i, c) in line.char_indices().chain([(line.len(), ' ')]) {
+            match (c.is_ascii_digit(), digits) {
+                (true, None) => digits = Some(i),
+                (false, Some(start)) => {
+                    let value = line[start..i]
+                        .parse()
+                        .map_err(|_| anyhow!("Number too large at {start}"))?;
+
+                    tokens.push((start, i, Token::Numeral(value)));
+                    digits = None;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(words) = &self.words {
+            tokens.extend(
+                words
+                    .matches(line)
+                    .map(|m| (m.start, m.end, Token::word(m.value))),
+            );
+        }
+
+        tokens.sort_by_key(|&(start, end, _)| (start, std::cmp::Reverse(end)));
+
+        let mut end = 0;
+        tokens.retain(|&(start, token_end, _)| {
+            let keep = start >= end;
+            if keep {
+                end = token_end;
+            }
+            keep
+        });
+
+        Ok(tokens)
+    }
+
+    /// Numbers of `line`, from left to right.
+    pub fn numbers(&self, line: &str) -> Result<Vec<Number>> {
+        let mut numbers = Vec::new();
+        let mut phrase: Option<Phrase> = None;
+        // Tokens of `phrase`.
+        let mut read = Vec::new();
+
+        for (start, end, token) in self.tokens(line)? {
+            if let Some(phrase) = &mut phrase {
+                if phrase.push(&line[phrase.end..start], end, token)? {
+                    read.push((start, end, token));
+                    continue;
+                }
+
+                if let Some((head, tail, split)) = split(line, &read, (start, end, token))? {
+                    numbers.push(head.number());
+                    *phrase = tail;
+                    read.drain(..split);
+                    read.push((start, end, token));
+                    continue;
+                }
+
+                numbers.push(phrase.number());
+            }
+
+            phrase = Some(Phrase::new(start, end, token));
+            read = vec![(start, end, token)];
+        }
+
+        numbers.extend(phrase.map(|p| p.number()));
+
+        Ok(numbers)
+    }
+
+    /// First and last numbers of `line` added up, the same number twice when
+    /// there is only one.
+    pub fn line_value(&self, line: &str) -> Result<u64> {
+        let numbers = self.numbers(line)?;
+
+        match (numbers.first(), numbers.last()) {
+            (Some(first), Some(last)) => first
+                .value
+                .checked_add(last.value)
+                .ok_or_else(|| anyhow!("Line value too large")),
+            _ => Err(anyhow!("Number not found")),
+        }
+    }
+}
+
+/// Phrase of `tokens`, if they form a single number.
+fn phrase(line: &str, tokens: &[(usize, usize, Token)]) -> Result<Option<Phrase>> {
+    let Some((&(start, end, token), rest)) = tokens.split_first() else {
+        return Ok(None);
+    };
+
+    let mut phrase = Phrase::new(start, end, token);
+
+    for &(start, end, token) in rest {
+        if !phrase.push(&line[phrase.end..start], end, token)? {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(phrase))
+}
+
+/// Splits the phrase of `tokens` before a unit or tens that `next` can
+/// extend instead, such as `two` in `one thousand two thousand`. Returns the
+/// phrases before and from the split, the latter extended by `next`, and the
+/// index of the split, the longest such tail first.
+fn split(
+    line: &str,
+    tokens: &[(usize, usize, Token)],
+    next: (usize, usize, Token),
+) -> Result<Option<(Phrase, Phrase, usize)>> {
+    for i in 1..tokens.len() {
+        if !matches!(tokens[i].2, Token::Unit(_) | Token::Tens(_)) {
+            continue;
+        }
+
+        let Some(mut tail) = phrase(line, &tokens[i..])? else {
+            continue;
+        };
+
+        if !tail.push(&line[tail.end..next.0], next.1, next.2)? {
+            continue;
+        }
+
+        if let Some(head) = phrase(line, &tokens[..i])? {
+            return Ok(Some((head, tail, i)));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(reader: &NumberReader, line: &str) -> Vec<u64> {
+        reader
+            .numbers(line)
+            .unwrap()
+            .iter()
+            .map(|n| n.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_compounds() {
+        let reader = NumberReader::english();
+
+        assert_eq!(values(&reader, "twenty-one"), [21]);
+        assert_eq!(values(&reader, "xtwentyoney"), [21]);
+        assert_eq!(values(&reader, "three hundred forty"), [340]);
+        assert_eq!(values(&reader, "one hundred and five"), [105]);
+        assert_eq!(values(&reader, "nineteen hundred eighty-four"), [1984]);
+        assert_eq!(
+            values(&reader, "two million three hundred thousand and seven"),
+            [2_300_007]
+        );
+        assert_eq!(values(&reader, "hundred"), [100]);
+        assert_eq!(values(&reader, "eighteen"), [18]);
+    }
+
+    #[test]
+    fn test_separate() {
+        let reader = NumberReader::english();
+
+        assert_eq!(values(&reader, "eight three"), [8, 3]);
+        assert_eq!(values(&reader, "twenty thirty"), [20, 30]);
+        assert_eq!(values(&reader, "twenty eleven"), [20, 11]);
+        assert_eq!(values(&reader, "five and six"), [5, 6]);
+        assert_eq!(values(&reader, "one thousand two thousand"), [1000, 2000]);
+        assert_eq!(
+            values(&reader, "one thousand two hundred thousand"),
+            [1000, 200_000]
+        );
+        assert_eq!(values(&reader, "two hundred three hundred"), [200, 300]);
+        assert_eq!(
+            values(&reader, "one million twenty-one thousand one million"),
+            [1_021_000, 1_000_000]
+        );
+        assert_eq!(values(&reader, "twelve34five"), [12, 34, 5]);
+        assert_eq!(values(&reader, "one, two"), [1, 2]);
+    }
+
+    #[test]
+    fn test_positions() {
+        let numbers = NumberReader::english().numbers("ab forty-two 7").unwrap();
+
+        assert_eq!(
+            numbers
+                .iter()
+                .map(|n| (n.start, n.end, n.value))
+                .collect::<Vec<_>>(),
+            [(3, 12, 42), (13, 14, 7)]
+        );
+    }
+
+    #[test]
+    fn test_numerals() {
+        let reader = NumberReader::numerals();
+
+        assert_eq!(values(&reader, "a12b345c6"), [12, 345, 6]);
+        assert!(values(&reader, "one two").is_empty());
+        assert_eq!(reader.line_value("a12b345c6").unwrap(), 18);
+        assert_eq!(reader.line_value("x7").unwrap(), 14);
+        assert!(reader.line_value("x").is_err());
+        assert!(reader.numbers("99999999999999999999").is_err());
+    }
+}
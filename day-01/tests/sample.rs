@@ -0,0 +1,122 @@
+use anyhow::Result;
+use aoc_core::{Answer, Configure, Settings, Solution};
+
+use day_01::{
+    calibration_value, digits, digits_and_words, find_first, find_last, process, process_part_1,
+    process_part_2, CalibrationError, Day01, Matcher,
+};
+
+#[test]
+fn part1() -> Result<()> {
+    assert_eq!(
+        process_part_1(include_str!("../src/sample-input.txt"))?,
+        142
+    );
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    assert_eq!(
+        process_part_2(include_str!("../src/sample-input-2.txt"))?,
+        281
+    );
+
+    Ok(())
+}
+
+#[test]
+fn calibration_values() -> Result<()> {
+    let digits = Matcher::new(&digits());
+    let digits_and_words = Matcher::new(&digits_and_words());
+
+    assert_eq!(calibration_value("treb7uchet", &digits)?, 77);
+    assert_eq!(calibration_value("eightwothree", &digits_and_words)?, 83);
+    assert_eq!(calibration_value("twone", &digits_and_words)?, 21);
+    assert!(calibration_value("trebuchet", &digits).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn overlapping_keys() -> Result<()> {
+    let map = [("1", 1), ("12", 12), ("2", 2), ("23", 23)]
+        .map(|(k, v)| (k.to_string(), v))
+        .into();
+    let matcher = Matcher::new(&map);
+
+    // The longest key wins among those starting at the same position.
+    assert_eq!(find_first("x123", &matcher)?, 12);
+    assert_eq!(find_last("x123", &matcher)?, 23);
+    assert_eq!(process("a1b\n123\n", &map)?, 11 + 1223);
+
+    Ok(())
+}
+
+#[test]
+fn dictionaries() -> Result<()> {
+    let mut settings = Settings::default();
+    settings.set("dictionary", "german, roman");
+
+    let day = Day01::parse_with("xVIIIy\nzweiundvierzig\nsechs7\n", &settings)?;
+    assert_eq!(day.part2()?, Answer::from(88 + 24 + 67u32));
+
+    settings.set("dictionary", "english,klingon");
+    assert!(Day01::parse_with("one\n", &settings).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn numbers() -> Result<()> {
+    let mut settings = Settings::default();
+    settings.set("numbers", "");
+
+    let input = "twenty-one apples and 3 pears\n12 one hundred and five 7\nxseventeen4\n";
+    let day = Day01::parse_with(input, &settings)?;
+
+    assert_eq!(day.part1()?, Answer::from(3 + 3 + 12 + 7 + 4 + 4u64));
+    assert_eq!(day.part2()?, Answer::from(21 + 3 + 12 + 7 + 17 + 4u64));
+
+    settings.set("dictionary", "german");
+    assert!(Day01::parse_with(input, &settings).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn lenient_and_report() -> Result<()> {
+    let input = "1abc2\nnothing\ntreb7uchet\n";
+
+    let day = Day01::parse(input)?;
+    let err = day.part1().unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<CalibrationError>(),
+        Some(&CalibrationError::NotFound { line: 2 })
+    );
+
+    let mut settings = Settings::default();
+    settings.set("lenient", "");
+    let day = Day01::parse_with(input, &settings)?;
+    assert_eq!(day.part1()?, Answer::from(12 + 77u64));
+
+    settings.set("report", "");
+    let day = Day01::parse_with(input, &settings)?;
+    let Answer::Text(report) = day.part1()? else {
+        panic!("expected a listing");
+    };
+    assert!(report.starts_with("1  12 | 1abc2\n      | ^   ^\n"));
+    assert!(report.ends_with("total 89, 1 line(s) skipped"));
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day01::parse(include_str!("../src/sample-input-2.txt"))?;
+
+    assert_eq!(day.part2()?, Answer::from(281u32));
+
+    Ok(())
+}
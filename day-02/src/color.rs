@@ -1,18 +1,40 @@
-use nom::{branch::alt, bytes::complete::tag, combinator::value, IResult};
+use std::fmt::Display;
 
-#[derive(Debug, Clone, Copy)]
-pub enum Color {
-    Red,
-    Green,
-    Blue,
-}
+use nom::{
+    bytes::complete::take_while,
+    character::complete::satisfy,
+    combinator::{map, recognize},
+    sequence::pair,
+    IResult,
+};
+
+/// Colour of a cube, any name such as `red` or `dark_blue`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Color(String);
 
 impl Color {
+    pub fn new(name: &str) -> Self {
+        Self(name.to_string())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
     pub fn parse(input: &str) -> IResult<&str, Self> {
-        alt((
-            value(Self::Blue, tag("blue")),
-            value(Self::Red, tag("red")),
-            value(Self::Green, tag("green")),
-        ))(input)
+        // A letter, then letters, digits or underscores.
+        map(
+            recognize(pair(
+                satisfy(char::is_alphabetic),
+                take_while(|c: char| c.is_alphanumeric() || c == '_'),
+            )),
+            Self::new,
+        )(input)
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
     }
 }
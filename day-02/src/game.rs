@@ -7,12 +7,13 @@ use nom::{
     IResult,
 };
 
-use crate::set::Set;
+use crate::{color::Color, set::Set};
 
+/// A game and the sets of cubes revealed during it.
 #[derive(Debug, Clone)]
 pub struct Game {
     pub id: u32,
-    sets: Vec<Set>,
+    pub sets: Vec<Set>,
 }
 
 impl Game {
@@ -28,37 +29,24 @@ impl Game {
         )(input)
     }
 
-    pub fn is_possible(&self) -> bool {
-        // "limit: 12 red cubes, 13 green cubes, and 14 blue cubes"
-
-        let set = self.max_set();
-
-        if set.red > 12 {
-            return false;
-        }
-
-        if set.green > 13 {
-            return false;
-        }
-
-        if set.blue > 14 {
-            return false;
-        }
-
-        true
+    /// Whether the bag could have held only the cubes of `limit`, such as
+    /// 12 red, 13 green and 14 blue cubes.
+    pub fn is_possible(&self, limit: &Set) -> bool {
+        self.max_set().fits(limit)
     }
 
-    pub fn power(&self) -> u32 {
+    /// Product of the fewest cubes of each colour of `palette` that make the
+    /// game possible, `None` when too large.
+    pub fn power<'a>(&self, palette: impl IntoIterator<Item = &'a Color>) -> Option<u64> {
         let set = self.max_set();
 
-        set.red * set.green * set.blue
+        palette.into_iter().try_fold(1u64, |power, color| {
+            power.checked_mul(set.count(color).into())
+        })
     }
 
-    fn max_set(&self) -> Set {
-        Set {
-            red: self.sets.iter().map(|c| c.red).max().unwrap_or(0),
-            green: self.sets.iter().map(|c| c.green).max().unwrap_or(0),
-            blue: self.sets.iter().map(|c| c.blue).max().unwrap_or(0),
-        }
+    /// Fewest cubes of each colour that make the game possible.
+    pub fn max_set(&self) -> Set {
+        self.sets.iter().fold(Set::default(), Set::union_max)
     }
 }
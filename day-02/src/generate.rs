@@ -0,0 +1,56 @@
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day02;
+
+impl Generate for Day02 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("games", 100, "Number of games"),
+        Param::new("sets", 6, "Maximum number of sets per game"),
+        Param::new("cubes", 20, "Maximum number of cubes of a colour per set"),
+    ];
+
+    /// Sets reveal one to three colours in random order.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let max_sets = params.size("sets", 1);
+        let cubes = params.get("cubes").max(1);
+        let mut input = String::new();
+
+        for id in 1..=params.get("games") {
+            let sets = (0..rng.gen_range(1..=max_sets))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    colors.shuffle(rng);
+
+                    colors[..rng.gen_range(1..=3)]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.gen_range(1..=cubes)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>();
+
+            input.push_str(&format!("Game {id}: {}\n", sets.join("; ")));
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day02>(seed, &"games=20".parse().unwrap()).unwrap();
+            let day = Day02::parse(&input).unwrap();
+
+            assert_eq!(day.puzzle().games.len(), 20);
+            assert!(day.part2().is_ok());
+        }
+    }
+}
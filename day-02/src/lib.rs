@@ -0,0 +1,68 @@
+//! Day 2: Cube Conundrum
+//!
+//! Games where cubes are drawn from a bag, see [`Puzzle`]. Cubes can be of
+//! any colour, and the bag of part 1 holds 12 red, 13 green and 14 blue
+//! cubes unless `--limit` says otherwise.
+
+mod color;
+mod game;
+mod generate;
+mod puzzle;
+mod set;
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Configure, Setting, Settings, Solution};
+
+pub use crate::{color::Color, game::Game, puzzle::Puzzle, set::Set};
+
+/// Cubes in the bag of part 1 as told by the puzzle.
+pub fn default_limit() -> Set {
+    Set::from([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+pub struct Day02 {
+    puzzle: Puzzle,
+    /// Cubes in the bag of part 1, [`default_limit`] by default.
+    limit: Set,
+}
+
+impl Day02 {
+    pub fn puzzle(&self) -> &Puzzle {
+        &self.puzzle
+    }
+}
+
+impl Solution for Day02 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, &Settings::default())
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.puzzle.part1(&self.limit)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.puzzle.part2()?.into())
+    }
+}
+
+impl Configure for Day02 {
+    const SETTINGS: &'static [Setting] = &[Setting::value(
+        "limit",
+        "LIST",
+        "Cubes of each colour in the bag of part 1, such as red=12,green=13,blue=14,yellow=3",
+    )];
+
+    fn parse_with(input: &str, settings: &Settings) -> Result<Self> {
+        let puzzle = parse_all(input, Puzzle::parse)?;
+        let limit = match settings.get("limit") {
+            Some(limit) => Set::parse_limit(limit)?,
+            None => default_limit(),
+        };
+
+        Ok(Self { puzzle, limit })
+    }
+}
@@ -1,9 +1,13 @@
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Result};
 use nom::{character::complete::multispace0, multi::many0, sequence::delimited, IResult, Parser};
 
-use crate::game::Game;
+use crate::{color::Color, game::Game, set::Set};
 
+/// All the games played.
 pub struct Puzzle {
-    games: Vec<Game>,
+    pub games: Vec<Game>,
 }
 
 impl Puzzle {
@@ -14,15 +18,34 @@ impl Puzzle {
         Ok((input, Self { games }))
     }
 
-    pub fn part1(&self) -> u32 {
+    /// Every colour of the games.
+    pub fn palette(&self) -> BTreeSet<&Color> {
+        self.games
+            .iter()
+            .flat_map(|g| &g.sets)
+            .flat_map(Set::colors)
+            .collect()
+    }
+
+    /// Sum of the ids of the games possible with the cubes of `limit`.
+    pub fn part1(&self, limit: &Set) -> Result<u32> {
         self.games
             .iter()
-            .filter(|g| g.is_possible())
-            .map(|g| g.id)
-            .sum()
+            .filter(|g| g.is_possible(limit))
+            .try_fold(0u32, |sum, game| {
+                sum.checked_add(game.id)
+                    .ok_or_else(|| anyhow!("Sum of the ids too large at game {}", game.id))
+            })
     }
 
-    pub fn part2(&self) -> u32 {
-        self.games.iter().map(|g| g.power()).sum()
+    /// Sum of the powers of all games, over the colours of all games.
+    pub fn part2(&self) -> Result<u64> {
+        let palette = self.palette();
+
+        self.games.iter().try_fold(0u64, |sum, game| {
+            game.power(palette.iter().copied())
+                .and_then(|power| sum.checked_add(power))
+                .ok_or_else(|| anyhow!("Power too large in game {}", game.id))
+        })
     }
 }
@@ -1,44 +1,44 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{anyhow, Result};
+use aoc_core::parse_all;
 use nom::{
     bytes::complete::tag,
     character::complete::{space0, space1, u32},
-    combinator::map,
-    multi::separated_list0,
+    combinator::{cut, map, map_opt},
+    multi::{separated_list0, separated_list1},
     sequence::{separated_pair, tuple},
     IResult,
 };
 
 use crate::color::Color;
 
-#[derive(Debug, Clone)]
+/// Cubes of each colour revealed at once, or held by the bag.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Set {
-    pub red: u32,
-    pub green: u32,
-    pub blue: u32,
+    cubes: BTreeMap<Color, u32>,
 }
 
 impl Set {
     pub fn parse(input: &str) -> IResult<&str, Self> {
         // "3 blue, 4 red"
-        map(
+        cut(map_opt(
             separated_list0(tuple((tag(","), space0)), Self::parse_cubes),
-            |colors| {
-                let mut set = Self {
-                    red: 0,
-                    green: 0,
-                    blue: 0,
-                };
-
-                for (color, count) in colors {
-                    match color {
-                        Color::Red => set.red += count,
-                        Color::Green => set.green += count,
-                        Color::Blue => set.blue += count,
-                    }
-                }
-
-                set
-            },
-        )(input)
+            Self::from_cubes,
+        ))(input)
+    }
+
+    /// Set of `cubes`, those of the same colour adding up as in `1 red, 2
+    /// red`, `None` when too many.
+    pub fn from_cubes(cubes: impl IntoIterator<Item = (Color, u32)>) -> Option<Self> {
+        let mut set = Self::default();
+
+        for (color, count) in cubes {
+            let total = set.cubes.entry(color).or_default();
+            *total = total.checked_add(count)?;
+        }
+
+        Some(set)
     }
 
     fn parse_cubes(input: &str) -> IResult<&str, (Color, u32)> {
@@ -48,4 +48,69 @@ impl Set {
             |(count, color)| (color, count),
         )(input)
     }
+
+    /// Set of a limit such as `red=12,green=13,blue=14`, each colour once.
+    pub fn parse_limit(s: &str) -> Result<Self> {
+        let cubes = parse_all(
+            s,
+            separated_list1(
+                tuple((tag(","), space0)),
+                separated_pair(Color::parse, tag("="), u32),
+            ),
+        )?;
+
+        let mut set = Self::default();
+
+        for (color, count) in cubes {
+            if set.cubes.insert(color.clone(), count).is_some() {
+                return Err(anyhow!("Colour {color} given twice in the limit"));
+            }
+        }
+
+        Ok(set)
+    }
+
+    /// Cubes of `color`, none when it was not revealed.
+    pub fn count(&self, color: &Color) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    /// Colours with their cubes, by name.
+    pub fn cubes(&self) -> impl Iterator<Item = (&Color, u32)> {
+        self.cubes.iter().map(|(color, &count)| (color, count))
+    }
+
+    /// Colours with cubes, even none, by name.
+    pub fn colors(&self) -> BTreeSet<&Color> {
+        self.cubes.keys().collect()
+    }
+
+    /// Whether every colour has at most the cubes of `limit`, which has none
+    /// of the colours it does not name.
+    pub fn fits(&self, limit: &Set) -> bool {
+        self.cubes()
+            .all(|(color, count)| count <= limit.count(color))
+    }
+
+    /// Colours of `self` and `other`, each with the most cubes of the two.
+    pub fn union_max(mut self, other: &Set) -> Self {
+        for (color, count) in other.cubes() {
+            let max = self.cubes.entry(color.clone()).or_default();
+            *max = (*max).max(count);
+        }
+
+        self
+    }
+}
+
+/// Set of each colour once, the last count of a colour given twice.
+impl<const N: usize> From<[(&str, u32); N]> for Set {
+    fn from(cubes: [(&str, u32); N]) -> Self {
+        Self {
+            cubes: cubes
+                .into_iter()
+                .map(|(name, count)| (Color::new(name), count))
+                .collect(),
+        }
+    }
 }
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Configure, Settings, Solution};
+
+use day_02::{default_limit, Color, Day02, Game, Puzzle, Set};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let sample_puzzle = parse_all(SAMPLE, Puzzle::parse)?;
+    assert_eq!(sample_puzzle.part1(&default_limit())?, 8);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let sample_puzzle = parse_all(SAMPLE, Puzzle::parse)?;
+    assert_eq!(sample_puzzle.part2()?, 2286);
+
+    Ok(())
+}
+
+#[test]
+fn game() -> Result<()> {
+    let game = parse_all(
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red",
+        Game::parse,
+    )?;
+
+    assert_eq!(game.id, 3);
+    assert_eq!(game.sets.len(), 2);
+    assert_eq!(
+        game.max_set(),
+        Set::from([("red", 20), ("green", 8), ("blue", 6)])
+    );
+    assert!(!game.is_possible(&default_limit()));
+    assert!(game.is_possible(&Set::from([("red", 20), ("green", 8), ("blue", 6)])));
+    assert_eq!(parse_all("green", Color::parse)?, Color::new("green"));
+
+    Ok(())
+}
+
+#[test]
+fn palette() -> Result<()> {
+    let input = "\
+Game 1: 2 yellow, 1 red; 4 yellow, 1 violet
+Game 2: 3 red, 2 violet
+Game 3: 1 yellow, 1 red, 1 red, 1 violet
+";
+    let mut settings = Settings::default();
+    settings.set("limit", "red=3, violet=1,yellow=4");
+
+    let day = Day02::parse_with(input, &settings)?;
+    assert_eq!(day.puzzle().palette().len(), 3);
+    assert_eq!(day.part1()?, Answer::from(1 + 3u32));
+    // Game 2 has no yellow cubes, so its power is 0, and game 3 has 2 red.
+    assert_eq!(day.part2()?, Answer::from(4 + 2u64));
+
+    // Colours missing from the limit are not in the bag.
+    settings.set("limit", "red=3,yellow=4");
+    let day = Day02::parse_with(input, &settings)?;
+    assert_eq!(day.part1()?, Answer::from(0u32));
+
+    for invalid in ["red", "red=1,red=2", "red=-1", ""] {
+        settings.set("limit", invalid);
+        assert!(Day02::parse_with(input, &settings).is_err(), "{invalid}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day02::parse(SAMPLE)?;
+
+    assert_eq!(day.puzzle().games.len(), 5);
+    assert_eq!(day.part1()?, Answer::from(8u32));
+
+    Ok(())
+}
+
+#[test]
+fn large_values() -> Result<()> {
+    assert!(Day02::parse("Game 1: 4294967295 red, 1 red\n").is_err());
+    assert!(Day02::parse("Game 1: 4294967295 red; 1 red\n").is_ok());
+
+    let day = Day02::parse("Game 4294967295: 1 red\nGame 4294967295: 1 red\n")?;
+    assert!(day.part1().is_err());
+
+    Ok(())
+}
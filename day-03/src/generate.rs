@@ -0,0 +1,69 @@
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day03;
+
+const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+
+impl Generate for Day03 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", 140, "Number of columns"),
+        Param::new("height", 140, "Number of rows"),
+        Param::new("numbers", 20, "Percentage of cells starting a number"),
+        Param::new("symbols", 10, "Percentage of cells holding a symbol"),
+    ];
+
+    /// Numbers of one to three digits, always followed by a dot or symbol.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let width = params.size("width", 1);
+        let numbers = params.get("numbers");
+        let symbols = params.get("symbols");
+        let mut input = String::new();
+
+        for _ in 0..params.size("height", 1) {
+            let mut row = Vec::with_capacity(width);
+
+            while row.len() < width {
+                let roll = rng.gen_range(0..100);
+
+                if roll < numbers {
+                    let digits = rng.gen_range(1..=3).min(width - row.len());
+                    let number = rng.gen_range(1..10u32.pow(digits as u32));
+                    row.extend(format!("{number:0digits$}").bytes());
+                } else if roll < numbers + symbols {
+                    row.push(*SYMBOLS.choose(rng).unwrap());
+                    continue;
+                }
+
+                if row.len() < width {
+                    row.push(b'.');
+                }
+            }
+
+            input.push_str(std::str::from_utf8(&row).unwrap());
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let params = "width=30,height=20".parse().unwrap();
+            let input = generate::<Day03>(seed, &params).unwrap();
+            let day = Day03::parse(&input).unwrap();
+
+            assert!(input.lines().all(|l| l.len() == 30));
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
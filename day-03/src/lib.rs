@@ -0,0 +1,243 @@
+//! Day 3: Gear Ratios
+//!
+//! An engine schematic of numbers and symbols, see [`Game`].
+
+mod generate;
+
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+
+/// The engine schematic, without its empty cells.
+#[derive(Debug)]
+pub struct Game {
+    /// Non-empty cells by `(x, y)` position.
+    pub map: HashMap<(usize, usize), Cell>,
+    /// Horizontal and vertical bounds of the schematic.
+    pub ranges: (RangeInclusive<usize>, RangeInclusive<usize>),
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Result<Game> {
+        let mut map = HashMap::new();
+
+        let mut max_x = 0;
+        let mut max_y = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, cell) in line.chars().enumerate() {
+                let cell = Cell::parse(cell)?;
+                if let Some(cell) = cell {
+                    map.insert((x, y), cell);
+                }
+
+                if x > max_x {
+                    max_x = x;
+                }
+
+                if y > max_y {
+                    max_y = y;
+                }
+            }
+        }
+
+        let x_range = 0..=max_x;
+        let y_range = 0..=max_y;
+
+        Ok(Game {
+            map,
+            ranges: (x_range, y_range),
+        })
+    }
+
+    /// Sum of the numbers adjacent to a symbol.
+    pub fn part1(&self) -> Result<u64> {
+        Ok(self
+            .find_numbers()
+            .into_iter()
+            .filter(|n| self.is_part_number(n))
+            .map(|n| n.value())
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .map(u64::from)
+            .sum())
+    }
+
+    /// Sum of the products of the two numbers next to each gear.
+    pub fn part2(&self) -> Result<u64> {
+        let gears = self.find_gears();
+        let numbers = self.find_numbers();
+
+        gears
+            .iter()
+            .filter_map(|(gx, gy)| {
+                let adjacent_numbers: Vec<_> = numbers
+                    .iter()
+                    .filter(|n| {
+                        let (x, y) = n.surrounding_bounds();
+                        x.contains(gx) && y.contains(gy)
+                    })
+                    .collect();
+
+                if let [a, b] = adjacent_numbers[..] {
+                    Some([a, b])
+                } else {
+                    None
+                }
+            })
+            .map(|numbers| -> Result<u64> {
+                Ok(numbers
+                    .into_iter()
+                    .map(|n| n.value())
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .map(u64::from)
+                    .product::<u64>())
+            })
+            .sum()
+    }
+
+    /// Every number, in reading order.
+    pub fn find_numbers(&self) -> Vec<Number> {
+        let mut numbers = vec![];
+        let mut current_number: Option<Number> = None;
+
+        for y in self.ranges.1.clone() {
+            for x in self.ranges.0.clone() {
+                let cell = self.map.get(&(x, y));
+
+                if let Some(Cell::Number(n)) = cell {
+                    match current_number {
+                        Some(ref mut number) => number.add_part(x, *n),
+                        None => current_number = Some(Number::new(y, x, *n)),
+                    }
+                } else if let Some(number) = current_number.take() {
+                    numbers.push(number);
+                }
+            }
+
+            // Numbers end with their row.
+            numbers.extend(current_number.take());
+        }
+
+        numbers
+    }
+
+    /// Positions of all `*` symbols, whether or not they are gears.
+    pub fn find_gears(&self) -> Vec<(usize, usize)> {
+        let mut gears = vec![];
+
+        for (position, cell) in &self.map {
+            if let Cell::Symbol('*') = cell {
+                gears.push(*position);
+            }
+        }
+
+        gears
+    }
+
+    /// Whether a symbol is adjacent to `number`.
+    pub fn is_part_number(&self, number: &Number) -> bool {
+        let (x_range, y_range) = number.surrounding_bounds();
+
+        for x in x_range {
+            for y in y_range.clone() {
+                if let Some(Cell::Symbol(_)) = self.map.get(&(x, y)) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Number(u32),
+    Symbol(char),
+}
+
+impl Cell {
+    pub fn parse(input: char) -> Result<Option<Self>> {
+        match input {
+            '0'..='9' => Ok(Some(Cell::Number(input.to_string().parse()?))),
+            '.' => Ok(None),
+            _ => Ok(Some(Self::Symbol(input))),
+        }
+    }
+}
+
+/// Horizontal run of digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub number: String,
+    pub y: usize,
+    pub x_start: usize,
+    pub x_end: usize,
+}
+
+impl Number {
+    fn new(y: usize, x: usize, value: u32) -> Self {
+        Number {
+            number: value.to_string(),
+            y,
+            x_start: x,
+            x_end: x,
+        }
+    }
+
+    fn add_part(&mut self, x: usize, value: u32) {
+        self.x_end = x;
+        self.number += &value.to_string();
+    }
+
+    /// Positions next to the number, the number itself included.
+    pub fn surrounding_bounds(&self) -> (RangeInclusive<usize>, RangeInclusive<usize>) {
+        let x_start = if self.x_start == 0 {
+            0
+        } else {
+            self.x_start - 1
+        };
+        let x_end = self.x_end + 1;
+
+        let y_start = if self.y == 0 { 0 } else { self.y - 1 };
+        let y_end = self.y + 1;
+
+        (x_start..=x_end, y_start..=y_end)
+    }
+
+    pub fn value(&self) -> Result<u32> {
+        Ok(self.number.parse::<u32>()?)
+    }
+}
+
+pub struct Day03 {
+    game: Game,
+}
+
+impl Day03 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day03 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            game: Game::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.part1()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+
+use day_03::{Cell, Day03, Game};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let sample_game = Game::parse(SAMPLE)?;
+    assert_eq!(sample_game.part1()?, 4361);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let sample_game = Game::parse(SAMPLE)?;
+    assert_eq!(sample_game.part2()?, 467835);
+
+    Ok(())
+}
+
+#[test]
+fn numbers() -> Result<()> {
+    let game = Game::parse(SAMPLE)?;
+    let numbers = game.find_numbers();
+
+    assert_eq!(numbers.len(), 10);
+    assert_eq!(numbers[0].value()?, 467);
+    assert!(game.is_part_number(&numbers[0]));
+    assert!(!game.is_part_number(&numbers[1]));
+    assert_eq!(game.map.get(&(3, 1)), Some(&Cell::Symbol('*')));
+    assert_eq!(game.find_gears().len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day03::parse(SAMPLE)?;
+
+    assert_eq!(day.game().ranges, (0..=9, 0..=9));
+    assert_eq!(day.part2()?, Answer::from(467835u32));
+
+    Ok(())
+}
+
+#[test]
+fn large_numbers() -> Result<()> {
+    let day = Day03::parse("4000000000*4000000000\n")?;
+
+    assert_eq!(day.part1()?, Answer::from(8000000000u64));
+    assert_eq!(day.part2()?, Answer::from(16000000000000000000u64));
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use aoc_core::{Generate, Param, Params};
+use rand::{
+    seq::{index::sample, SliceRandom},
+    Rng,
+};
+
+use crate::Day04;
+
+/// The total number of cards stays below this so that part 2 doesn't
+/// overflow.
+const MAX_TOTAL: u64 = u32::MAX as u64 / 2;
+
+impl Generate for Day04 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("cards", 200, "Number of cards"),
+        Param::new("winning", 10, "Winning numbers per card, at most 20"),
+        Param::new("numbers", 25, "Numbers per card, at most 49"),
+    ];
+
+    /// Numbers are 1 to 99 and distinct on each side of a card.
+    ///
+    /// Wins never go past the last card, and are limited so that there are
+    /// no more than [`MAX_TOTAL`] cards in the end.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let cards = params.size("cards", 1);
+        let winning = params.size("winning", 1).min(20);
+        let numbers = params.size("numbers", 1).min(49);
+
+        let mut copies = vec![1u64; cards];
+        let mut total = cards as u64;
+        let mut input = String::new();
+
+        for index in 0..cards {
+            let budget = (MAX_TOTAL.saturating_sub(total) / copies[index]) as usize;
+            let matches = rng
+                .gen_range(0..=winning.min(numbers))
+                .min(cards - index - 1)
+                .min(budget);
+
+            let won = copies[index];
+            total += won * matches as u64;
+
+            for copy in &mut copies[index + 1..=index + matches] {
+                *copy += won;
+            }
+
+            // The first `winning` values are the winning numbers, the card's
+            // numbers are `matches` of them and the rest from the others.
+            let values = sample(rng, 99, winning + numbers - matches)
+                .into_iter()
+                .map(|v| v + 1)
+                .collect::<Vec<_>>();
+
+            let mut own = values[..matches].to_vec();
+            own.extend(&values[winning..]);
+            own.shuffle(rng);
+
+            let format = |values: &[usize]| {
+                values
+                    .iter()
+                    .map(|v| format!("{v:>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+
+            input.push_str(&format!(
+                "Card {:>3}: {} | {}\n",
+                index + 1,
+                format(&values[..winning]),
+                format(&own)
+            ));
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day04>(seed, &Default::default()).unwrap();
+            let day = Day04::parse(&input).unwrap();
+
+            assert_eq!(day.game().cards.len(), 200);
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Day 4: Scratchcards
+//!
+//! Cards with winning numbers, see [`Game`].
+
+mod generate;
+
+use std::collections::HashSet;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{multispace0, space0, space1, u32},
+    combinator::map,
+    multi::{many0, separated_list0},
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+use anyhow::{anyhow, Result};
+use aoc_core::{parse_all, trace, Answer, Solution};
+
+/// The pile of scratchcards, in order.
+#[derive(Debug)]
+pub struct Game {
+    pub cards: Vec<Card>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            many0(delimited(multispace0, Card::parse, multispace0)),
+            |cards| Self { cards },
+        )(input)
+    }
+
+    /// Total score of the cards.
+    pub fn part1(&self) -> Result<u32> {
+        self.cards.iter().try_fold(0u32, |total, card| {
+            total
+                .checked_add(card.get_score()?)
+                .ok_or_else(|| anyhow!("Total score too large"))
+        })
+    }
+
+    /// Number of cards held once all copies are won.
+    pub fn part2(&self) -> Result<u32> {
+        self.card_counts()?.iter().try_fold(0u32, |total, &count| {
+            total
+                .checked_add(count)
+                .ok_or_else(|| anyhow!("Number of cards too large"))
+        })
+    }
+
+    /// Number of copies held of each card, the original included.
+    pub fn card_counts(&self) -> Result<Vec<u32>> {
+        let mut card_counts: Vec<u32> = self.cards.iter().map(|_| 1).collect();
+
+        for (index, card) in self.cards.iter().enumerate() {
+            let winning_card_count = card.get_matching_count();
+
+            let card_count = *card_counts
+                .get(index)
+                .ok_or_else(|| anyhow!("Card count not found: {}", card.id))?;
+
+            trace::emit("card", |e| {
+                e.field("id", card.id)
+                    .field("matches", winning_card_count)
+                    .field("copies", card_count)
+            });
+
+            let winning_range = index + 1..=index + winning_card_count as usize;
+
+            let winning_card_counts = card_counts
+                .get_mut(winning_range.clone())
+                .ok_or_else(|| anyhow!("Winning cards not available: Range {:?}", winning_range))?
+                .iter_mut();
+
+            for winning_card_count in winning_card_counts {
+                *winning_card_count = (*winning_card_count)
+                    .checked_add(card_count)
+                    .ok_or_else(|| anyhow!("Too many copies won by card {}", card.id))?;
+            }
+        }
+
+        Ok(card_counts)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winning_numbers: HashSet<u32>,
+    pub numbers: HashSet<u32>,
+}
+
+impl Card {
+    fn parse_header(input: &str) -> IResult<&str, u32> {
+        delimited(tuple((tag("Card"), space1)), u32, tuple((tag(":"), space0)))(input)
+    }
+
+    fn parse_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list0(space1, u32)(input)
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+
+        map(
+            tuple((
+                Self::parse_header,
+                Self::parse_numbers,
+                tuple((space0, tag("|"), space0)),
+                Self::parse_numbers,
+            )),
+            |(id, winning_numbers, _, numbers)| Card {
+                id,
+                winning_numbers: HashSet::from_iter(winning_numbers),
+                numbers: HashSet::from_iter(numbers),
+            },
+        )(input)
+    }
+
+    /// How many of the numbers are winning numbers.
+    pub fn get_matching_count(&self) -> u32 {
+        self.numbers.intersection(&self.winning_numbers).count() as u32
+    }
+
+    /// One point for the first match, doubled for every other one.
+    pub fn get_score(&self) -> Result<u32> {
+        let matching_count = self.get_matching_count();
+
+        match matching_count {
+            0 => Ok(0),
+            _ => 2u32
+                .checked_pow(matching_count - 1)
+                .ok_or_else(|| anyhow!("Score of card {} too large", self.id)),
+        }
+    }
+}
+
+pub struct Day04 {
+    game: Game,
+}
+
+impl Day04 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day04 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game = parse_all(input, Game::parse)?;
+
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.part1()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::Result;
+use aoc_core::{parse_all, trace, Answer, Solution};
+
+use day_04::{Card, Day04, Game};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let sample_game = parse_all(SAMPLE, Game::parse)?;
+
+    assert_eq!(sample_game.part1()?, 13);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let sample_game = parse_all(SAMPLE, Game::parse)?;
+
+    assert_eq!(sample_game.part2()?, 30);
+    assert_eq!(sample_game.card_counts()?, [1, 2, 4, 8, 14, 1]);
+
+    Ok(())
+}
+
+#[test]
+fn part2_trace() -> Result<()> {
+    let sample_game = parse_all(SAMPLE, Game::parse)?;
+
+    let (total, events) = trace::record("card".parse().unwrap(), || sample_game.part2());
+    let events = events.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(total?, 30);
+    assert_eq!(events.len(), 6);
+    assert_eq!(events[0], "card copies=1 id=1 matches=4");
+    assert_eq!(events[4], "card copies=14 id=5 matches=0");
+
+    Ok(())
+}
+
+#[test]
+fn card() -> Result<()> {
+    let card = parse_all(
+        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+        Card::parse,
+    )?;
+
+    assert_eq!(card.id, 1);
+    assert_eq!(card.get_matching_count(), 4);
+    assert_eq!(card.get_score()?, 8);
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day04::parse(SAMPLE)?;
+
+    assert_eq!(day.game().cards.len(), 6);
+    assert_eq!(day.part2()?, Answer::from(30u32));
+
+    Ok(())
+}
+
+#[test]
+fn large_values() -> Result<()> {
+    let numbers = (1..=70)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let day = Day04::parse(&format!("Card 1: {numbers} | {numbers}\n"))?;
+
+    assert!(day.part1().is_err());
+
+    // Each card wins a copy of every later one, doubling their copies.
+    let cards = (1..=34)
+        .map(|id| {
+            let numbers = (1..=34 - id).map(|n| n.to_string()).collect::<Vec<_>>();
+            let numbers = numbers.join(" ");
+
+            format!("Card {id}: {numbers} | {numbers}\n")
+        })
+        .collect::<String>();
+    let day = Day04::parse(&cards)?;
+
+    assert!(day.part2().is_err());
+
+    Ok(())
+}
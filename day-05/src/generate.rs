@@ -0,0 +1,99 @@
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day05;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+impl Generate for Day05 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("seeds", 10, "Number of seed ranges"),
+        Param::new("mappings", 30, "Number of mappings per map"),
+        Param::new("max", 4_294_967_296, "Upper bound of all values"),
+    ];
+
+    /// Each map cuts `0..max` into pieces and moves them around, like the
+    /// real almanac, so that its source ranges never overlap.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let mappings = params.size("mappings", 1) as u64;
+        let max = params.get("max").max(mappings * 2);
+
+        let seeds = (0..params.get("seeds"))
+            .map(|_| {
+                let start = rng.gen_range(0..max - 1);
+                let length = rng.gen_range(1..=(max - start).min(max / 50).max(1));
+
+                format!("{start} {length}")
+            })
+            .collect::<Vec<_>>();
+
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        for pair in CATEGORIES.windows(2) {
+            let mut cuts = (1..mappings)
+                .map(|_| rng.gen_range(1..max))
+                .collect::<Vec<_>>();
+            cuts.extend([0, max]);
+            cuts.sort_unstable();
+            cuts.dedup();
+
+            let pieces = cuts.windows(2).map(|w| (w[0], w[1] - w[0]));
+            let mut lengths = pieces.clone().map(|(_, length)| length).collect::<Vec<_>>();
+            lengths.shuffle(rng);
+
+            // Destinations of the shuffled pieces, laid out one after another.
+            let mut destinations = lengths
+                .iter()
+                .scan(0, |start, &length| {
+                    *start += length;
+                    Some((*start - length, length))
+                })
+                .collect::<Vec<_>>();
+
+            let mut lines = pieces
+                .map(|(source, length)| {
+                    let i = destinations.iter().position(|d| d.1 == length).unwrap();
+                    let (destination, _) = destinations.swap_remove(i);
+
+                    format!("{destination} {source} {length}")
+                })
+                .collect::<Vec<_>>();
+            lines.shuffle(rng);
+
+            input.push_str(&format!("\n{}-to-{} map:\n", pair[0], pair[1]));
+            input.push_str(&lines.join("\n"));
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day05>(seed, &Default::default()).unwrap();
+            let day = Day05::parse(&input).unwrap();
+
+            assert_eq!(day.game().maps.len(), 7);
+            assert!(day.game().maps.values().all(|m| m.mappings.len() == 30));
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
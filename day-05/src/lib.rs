@@ -0,0 +1,352 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! The almanac maps seeds through a chain of categories down to locations,
+//! see [`Game`]. Values are handled as [`Range`]s so that part 2 never has
+//! to enumerate its seeds.
+
+mod generate;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha0, multispace0, newline, space0, space1, u64},
+    combinator::map_opt,
+    multi::separated_list0,
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+use anyhow::{anyhow, Result};
+use aoc_core::{parse_all, trace, Answer, Solution};
+
+/// Half-open range of values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Range {
+    pub start: u64, // inclusive
+    pub end: u64,   // exclusive
+}
+
+impl Range {
+    pub fn new(start: u64, end: u64) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn intersect(self, other: Range) -> Option<Range> {
+        let max_start = std::cmp::max(self.start, other.start);
+        let min_end = std::cmp::min(self.end, other.end);
+
+        if max_start < min_end {
+            Some(Range::new(max_start, min_end))
+        } else {
+            None
+        }
+    }
+
+    /// Parts of `self` that are not in `other`.
+    pub fn subtract(self, other: Range) -> HashSet<Range> {
+        if self.is_empty() {
+            return HashSet::new();
+        }
+
+        // No overlap
+        if self.start >= other.end || self.end <= other.start {
+            return [self].into();
+        }
+
+        let mut result = HashSet::new();
+
+        // Partial overlap at the start of "self"
+        if other.start > self.start {
+            result.insert(Range::new(self.start, other.start));
+        }
+
+        // Partial overlap at the end of "self"
+        if other.end < self.end {
+            result.insert(Range::new(other.end, self.end));
+        }
+
+        result
+    }
+
+    /// Parts of `self` that are in none of `ranges_to_subtract`.
+    pub fn subtract_ranges(self, ranges_to_subtract: &[Range]) -> HashSet<Range> {
+        let mut current_ranges: HashSet<_> = [self].into_iter().filter(|r| !r.is_empty()).collect();
+
+        for &range_to_subtract in ranges_to_subtract {
+            current_ranges = current_ranges
+                .iter()
+                .flat_map(|r| r.subtract(range_to_subtract).into_iter())
+                .collect();
+        }
+
+        current_ranges
+    }
+}
+
+/// One line of a map, moving `source_range` to `destination_range_start`.
+/// `start..end`.
+impl Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct Mapping {
+    pub source_range: Range,
+    pub destination_range_start: u64,
+}
+
+impl Mapping {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // 50 98 2
+        map_opt(
+            tuple((u64, space1, u64, space1, u64)),
+            |(destination_range_start, _, source_range_start, _, range_length)| {
+                // Both ends must be representable.
+                destination_range_start.checked_add(range_length)?;
+
+                Some(Self {
+                    source_range: Range::new(
+                        source_range_start,
+                        source_range_start.checked_add(range_length)?,
+                    ),
+                    destination_range_start,
+                })
+            },
+        )(input)
+    }
+
+    /// Destination of the part of `range` covered by this mapping.
+    pub fn map(&self, range: Range) -> Option<Range> {
+        range.intersect(self.source_range).map(|intersect| {
+            Range::new(
+                intersect.start - self.source_range.start + self.destination_range_start,
+                intersect.end - self.source_range.start + self.destination_range_start,
+            )
+        })
+    }
+}
+
+/// Conversion from one category to the next.
+#[derive(Debug)]
+pub struct Map {
+    pub source_category: String,
+    pub destination_category: String,
+    pub mappings: HashSet<Mapping>,
+}
+
+impl Map {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // seed-to-soil map:
+
+        let (input, (source_category, _, destination_category, _, _, _)) =
+            tuple((alpha0, tag("-to-"), alpha0, space1, tag("map:"), newline))(input)?;
+
+        let (input, mappings) = separated_list0(newline, Mapping::parse)(input)?;
+
+        Ok((
+            input,
+            Self {
+                source_category: source_category.to_string(),
+                destination_category: destination_category.to_string(),
+                mappings: HashSet::from_iter(mappings),
+            },
+        ))
+    }
+
+    /// Destinations of `range`, values outside every mapping are kept as
+    /// they are.
+    pub fn map(&self, range: Range) -> Vec<Range> {
+        let ranges: Vec<_> = self.mappings.iter().map(|m| m.source_range).collect();
+
+        let other_ranges = range.subtract_ranges(&ranges);
+
+        let other_mappings: Vec<_> = other_ranges
+            .iter()
+            .map(|&r| Mapping {
+                source_range: r,
+                destination_range_start: r.start,
+            })
+            .collect();
+
+        self.mappings
+            .iter()
+            .chain(other_mappings.iter())
+            .flat_map(|m| m.map(range))
+            .collect()
+    }
+}
+
+/// The almanac.
+#[derive(Debug)]
+pub struct Game {
+    pub seeds: Vec<u64>,
+    /// Maps by source category.
+    pub maps: HashMap<String, Map>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // seeds: 79 14 55 13
+        let (input, seeds) = nom::sequence::preceded(
+            tuple((tag("seeds:"), space0)),
+            separated_list0(space1, u64),
+        )(input)?;
+
+        let (input, maps_vec) = delimited(
+            multispace0,
+            separated_list0(multispace0, Map::parse),
+            multispace0,
+        )(input)?;
+
+        let mut maps = HashMap::new();
+
+        for map in maps_vec {
+            maps.insert(map.source_category.clone(), map);
+        }
+
+        Ok((input, Game { seeds, maps }))
+    }
+
+    /// Lowest location of the seeds.
+    pub fn part1(&self) -> Result<u64> {
+        let ranges = self
+            .seeds
+            .iter()
+            .map(|&s| {
+                s.checked_add(1)
+                    .map(|end| Range::new(s, end))
+                    .ok_or(anyhow!("Seed too large: {s}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let min_value = self
+            .find_category_ranges("seed", &ranges, "location")?
+            .into_iter()
+            .map(|r| r.start)
+            .min()
+            .ok_or(anyhow!("No minimal value"))?;
+
+        Ok(min_value)
+    }
+
+    /// Lowest location of the seeds, read as ranges of start and length.
+    pub fn part2(&self) -> Result<u64> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(anyhow!("Odd number of seed values"));
+        }
+
+        let ranges = self
+            .seeds
+            .chunks(2)
+            .map(|chunk| {
+                let start = chunk[0];
+                let length = chunk[1];
+
+                start
+                    .checked_add(length)
+                    .map(|end| Range::new(start, end))
+                    .ok_or(anyhow!("Seed range too large: {start} {length}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.find_minimal_value(&ranges)
+    }
+
+    /// Lowest location of all values in `ranges`.
+    pub fn find_minimal_value(&self, ranges: &[Range]) -> Result<u64> {
+        let min_value = self
+            .find_category_ranges("seed", ranges, "location")?
+            .into_iter()
+            .map(|r| r.start)
+            .min()
+            .ok_or(anyhow!("No minimal value"))?;
+
+        Ok(min_value)
+    }
+
+    /// Follows the maps from `source_category` until
+    /// `destination_category`.
+    pub fn find_category_ranges(
+        &self,
+        source_category: &str,
+        source_ranges: &[Range],
+        destination_category: &str,
+    ) -> Result<Vec<Range>> {
+        let mut category = source_category.to_string();
+        let mut ranges = Vec::from(source_ranges);
+
+        // Each map is used at most once, unless they form a cycle.
+        for _ in 0..=self.maps.len() {
+            if category == destination_category {
+                return Ok(ranges);
+            }
+
+            let map = self
+                .maps
+                .get(&category)
+                .ok_or(anyhow!("Category not found: {category}"))?;
+
+            category = map.destination_category.clone();
+
+            ranges = ranges
+                .iter()
+                .flat_map(|&r| map.map(r).into_iter())
+                .collect();
+
+            trace::emit("map", |e| {
+                let mut sorted = ranges.clone();
+                sorted.sort_by_key(|r| (r.start, r.end));
+
+                e.field("from", map.source_category.as_str())
+                    .field("to", category.as_str())
+                    .field(
+                        "ranges",
+                        sorted.iter().map(Range::to_string).collect::<Vec<_>>(),
+                    )
+            });
+        }
+
+        Err(anyhow!(
+            "Maps from {source_category} never reach {destination_category}"
+        ))
+    }
+}
+
+pub struct Day05 {
+    game: Game,
+}
+
+impl Day05 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day05 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game = parse_all(input, Game::parse)?;
+
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.part1()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
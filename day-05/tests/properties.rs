@@ -0,0 +1,50 @@
+use std::collections::BTreeSet;
+
+use proptest::prelude::*;
+
+use day_05::Range;
+
+/// Reference for [`Range::subtract_ranges`], as the set of values left.
+fn naive_subtract_ranges(range: Range, ranges_to_subtract: &[Range]) -> BTreeSet<u64> {
+    (range.start..range.end)
+        .filter(|v| {
+            !ranges_to_subtract
+                .iter()
+                .any(|r| (r.start..r.end).contains(v))
+        })
+        .collect()
+}
+
+fn range() -> impl Strategy<Value = Range> {
+    (0..40u64, 0..20u64).prop_map(|(start, length)| Range::new(start, start + length))
+}
+
+proptest! {
+    #[test]
+    fn subtract_ranges_matches_naive(
+        range in range(),
+        ranges_to_subtract in prop::collection::vec(range(), 0..6),
+    ) {
+        let mut result = range
+            .subtract_ranges(&ranges_to_subtract)
+            .into_iter()
+            .collect::<Vec<_>>();
+        result.sort_by_key(|r| r.start);
+
+        // The parts left are disjoint and never empty.
+        for r in &result {
+            prop_assert!(r.start < r.end, "empty range {:?}", r);
+        }
+
+        for pair in result.windows(2) {
+            prop_assert!(pair[0].end <= pair[1].start, "overlap {:?}", pair);
+        }
+
+        let values = result
+            .iter()
+            .flat_map(|r| r.start..r.end)
+            .collect::<BTreeSet<_>>();
+
+        prop_assert_eq!(values, naive_subtract_ranges(range, &ranges_to_subtract));
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use aoc_core::{parse_all, trace, Answer, Solution};
+
+use day_05::{Day05, Game, Range};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn test_part1() -> Result<()> {
+    let sample_game = parse_all(SAMPLE, Game::parse).unwrap();
+    assert_eq!(sample_game.part1()?, 35);
+
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let sample_game = parse_all(SAMPLE, Game::parse).unwrap();
+    assert_eq!(sample_game.part2()?, 46);
+
+    Ok(())
+}
+
+#[test]
+fn test_ranges() {
+    let range = Range::new(10, 20);
+
+    assert_eq!(
+        range.intersect(Range::new(15, 30)),
+        Some(Range::new(15, 20))
+    );
+    assert_eq!(range.intersect(Range::new(20, 30)), None);
+    assert_eq!(
+        range.subtract_ranges(&[Range::new(12, 14), Range::new(18, 25)]),
+        HashSet::from([Range::new(10, 12), Range::new(14, 18)])
+    );
+}
+
+#[test]
+fn test_maps() -> Result<()> {
+    let game = parse_all(SAMPLE, Game::parse)?;
+    let soil = game.find_category_ranges("seed", &[Range::new(79, 80)], "soil")?;
+
+    assert_eq!(soil, [Range::new(81, 82)]);
+    assert_eq!(game.maps["seed"].destination_category, "soil");
+    assert_eq!(game.maps.len(), 7);
+
+    Ok(())
+}
+
+#[test]
+fn test_solution() -> Result<()> {
+    let day = Day05::parse(SAMPLE)?;
+
+    assert_eq!(day.game().seeds, [79, 14, 55, 13]);
+    assert_eq!(day.part2()?, Answer::from(46u64));
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid() -> Result<()> {
+    // An odd number of seed values for part 2.
+    let day = Day05::parse(&SAMPLE.replacen("79 14", "79", 1))?;
+    assert!(day.part1().is_ok());
+    assert!(day.part2().is_err());
+
+    // Maps looping back without reaching a location.
+    let day = Day05::parse("seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3\n")?;
+    assert!(day.part1().is_err());
+
+    // Ranges beyond the largest value.
+    assert!(Day05::parse(&SAMPLE.replacen("50 98 2", "50 98 18446744073709551615", 1)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_trace() -> Result<()> {
+    let sample_game = parse_all(SAMPLE, Game::parse)?;
+    let (location, events) = trace::record(trace::Filter::all(), || {
+        sample_game.find_minimal_value(&[Range::new(79, 80)])
+    });
+
+    assert_eq!(location?, 82);
+    assert_eq!(events.len(), 7);
+    assert_eq!(
+        events[0].to_string(),
+        "map from=seed ranges=[81..82] to=soil"
+    );
+    assert_eq!(
+        events[6].to_string(),
+        "map from=humidity ranges=[82..83] to=location"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day06;
+
+impl Generate for Day06 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("races", 4, "Number of races"),
+        Param::new("digits", 2, "Digits of each race time"),
+    ];
+
+    /// Every race can be won. Sizes are limited so that the numbers of part 2,
+    /// where the digits of all races are joined, still fit into a `u64`.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let races = params.size("races", 1).min(9);
+        let digits = params.size("digits", 1).min(9 / races).max(1) as u32;
+
+        let (times, distances): (Vec<_>, Vec<_>) = (0..races)
+            .map(|_| {
+                let time = rng.gen_range(10u64.pow(digits - 1).max(2)..10u64.pow(digits));
+                let best = (time / 2) * (time - time / 2);
+
+                (time, rng.gen_range(0..best))
+            })
+            .unzip();
+
+        let columns = |values: &[u64]| {
+            values
+                .iter()
+                .zip(&distances)
+                .map(|(value, distance)| {
+                    format!("{value:>width$}", width = distance.to_string().len())
+                })
+                .collect::<Vec<_>>()
+                .join("   ")
+        };
+
+        format!(
+            "Time:      {}\nDistance:  {}\n",
+            columns(&times),
+            columns(&distances)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day06>(seed, &Default::default()).unwrap();
+            let day = Day06::parse(&input).unwrap();
+
+            assert_eq!(day.game1().races.len(), 4);
+            assert!(day.game1().races.iter().all(|r| r.record_count() > 0));
+            assert!(day.part2().is_ok());
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Day 6: Wait For It
+//!
+//! Boat races, see [`Game`]. Part 1 reads each column as a race, part 2
+//! ignores the spaces and reads a single race.
+
+mod generate;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{newline, space0, space1, u64},
+    combinator::{map, map_res, recognize},
+    multi::separated_list0,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+use anyhow::{anyhow, Result};
+use aoc_core::{parse_all, Answer, Solution};
+
+/// The races to win.
+pub struct Game {
+    pub races: Vec<Race>,
+}
+
+impl Game {
+    /// Parses one race per column.
+    pub fn parse1(input: &str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                // Time:      7  15   30
+                delimited(tuple((tag("Time:"), space0)), Self::parse_list, newline),
+                // Distance:  9  40  200
+                preceded(tuple((tag("Distance:"), space0)), Self::parse_list),
+            )),
+            |(time_list, distance_list)| {
+                let races = time_list
+                    .iter()
+                    .zip(distance_list.iter())
+                    .map(|(&time, &distance)| Race::new(time, distance))
+                    .collect();
+
+                Self { races }
+            },
+        )(input)
+    }
+
+    /// Parses a single race, ignoring the spaces between digits.
+    pub fn parse2(input: &str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                // Time:      7  15   30
+                delimited(
+                    tuple((tag("Time:"), space0)),
+                    Self::parse_list_as_number,
+                    newline,
+                ),
+                // Distance:  9  40  200
+                preceded(
+                    tuple((tag("Distance:"), space0)),
+                    Self::parse_list_as_number,
+                ),
+            )),
+            |(time, distance)| {
+                let races = vec![Race::new(time, distance)];
+                Self { races }
+            },
+        )(input)
+    }
+
+    fn parse_list(input: &str) -> IResult<&str, Vec<u64>> {
+        separated_list0(space1, u64)(input)
+    }
+
+    fn parse_list_as_number(input: &str) -> IResult<&str, u64> {
+        map_res(recognize(Self::parse_list), |s: &str| {
+            s.chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .parse::<u64>()
+        })(input)
+    }
+
+    /// Product of the number of ways to beat each record.
+    pub fn puzzle(&self) -> Result<u64> {
+        self.races
+            .iter()
+            .try_fold(1u64, |product, race| {
+                product.checked_mul(race.record_count())
+            })
+            .ok_or(anyhow!("Product of the record counts is too large"))
+    }
+}
+
+/// Duration of a race and the record distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    pub fn new(time: u64, distance: u64) -> Self {
+        Self { time, distance }
+    }
+
+    /// Number of ways to hold the button that beat the record.
+    pub fn record_count(&self) -> u64 {
+        (0..self.time)
+            .filter(|time_charging| {
+                let time_remaining = self.time - time_charging;
+                let speed = *time_charging;
+                let distance = u128::from(speed) * u128::from(time_remaining);
+                distance > u128::from(self.distance)
+            })
+            .count() as u64
+    }
+}
+
+pub struct Day06 {
+    game1: Game,
+    game2: Game,
+}
+
+impl Day06 {
+    /// Races as read for part 1.
+    pub fn game1(&self) -> &Game {
+        &self.game1
+    }
+
+    /// Race as read for part 2.
+    pub fn game2(&self) -> &Game {
+        &self.game2
+    }
+}
+
+impl Solution for Day06 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game1 = parse_all(input, Game::parse1)?;
+        let game2 = parse_all(input, Game::parse2)?;
+
+        Ok(Self { game1, game2 })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game1.puzzle()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game2.puzzle()?.into())
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+
+use day_06::{Day06, Game, Race};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let (_, game) = Game::parse1(SAMPLE)?;
+    assert_eq!(game.puzzle()?, 288);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let (_, game) = Game::parse2(SAMPLE)?;
+    assert_eq!(game.puzzle()?, 71503);
+
+    Ok(())
+}
+
+#[test]
+fn race() {
+    assert_eq!(Race::new(7, 9).record_count(), 4);
+    assert_eq!(Race::new(30, 200).record_count(), 9);
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day06::parse(SAMPLE)?;
+
+    assert_eq!(day.game1().races.len(), 3);
+    assert_eq!(day.game2().races, [Race::new(71530, 940200)]);
+    assert_eq!(day.part1()?, Answer::from(288u64));
+
+    Ok(())
+}
+
+#[test]
+fn large_product() -> Result<()> {
+    let input = format!(
+        "Time: {}\nDistance: {}",
+        ["200"; 10].join(" "),
+        ["0"; 10].join(" ")
+    );
+    let game = parse_all(&input, Game::parse1)?;
+
+    assert!(game.puzzle().is_err());
+
+    Ok(())
+}
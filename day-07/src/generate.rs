@@ -0,0 +1,54 @@
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day07;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+impl Generate for Day07 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("hands", 1000, "Number of hands"),
+        Param::new("bid", 1000, "Highest bid"),
+    ];
+
+    /// Each hand draws from a few card values only, so that every type of
+    /// hand shows up.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let bid = params.get("bid").max(1);
+        let mut input = String::new();
+
+        for _ in 0..params.get("hands") {
+            let kinds = rng.gen_range(1..=5);
+            let values = CARDS
+                .choose_multiple(rng, kinds)
+                .copied()
+                .collect::<Vec<_>>();
+
+            let hand = (0..5)
+                .map(|_| char::from(*values.choose(rng).unwrap()))
+                .collect::<String>();
+
+            input.push_str(&format!("{hand} {}\n", rng.gen_range(1..=bid)));
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day07>(seed, &"hands=100".parse().unwrap()).unwrap();
+            let day = Day07::parse(&input).unwrap();
+
+            assert_eq!(day.game1().rounds.len(), 100);
+            assert_eq!(day.game2().rounds.len(), 100);
+        }
+    }
+}
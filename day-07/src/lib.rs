@@ -0,0 +1,229 @@
+//! Day 7: Camel Cards
+//!
+//! Hands of cards ranked by strength, see [`Game`]. Part 2 reads `J` as a
+//! joker, see [`ParserOptions`].
+
+mod generate;
+
+use std::collections::HashMap;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::{
+        complete::newline,
+        complete::{space1, u64},
+    },
+    combinator::{map, value},
+    multi::{many0, many_m_n, separated_list0},
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_core::{parse_all, Answer, Solution};
+
+/// The hands played and their bids.
+pub struct Game {
+    pub rounds: Vec<Round>,
+}
+
+/// Meaning of the `J` card.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JParse {
+    #[default]
+    Jack,
+    Joker,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParserOptions {
+    pub j_parse: JParse,
+}
+
+impl Game {
+    pub fn parse(options: ParserOptions) -> impl Fn(&str) -> IResult<&str, Self> {
+        move |input: &str| {
+            delimited(
+                many0(newline),
+                map(separated_list0(newline, Round::parse(options)), |rounds| {
+                    Self { rounds }
+                }),
+                many0(newline),
+            )(input)
+        }
+    }
+
+    /// Total winnings, the bid of each hand multiplied by its rank.
+    pub fn puzzle(&self) -> Result<u64> {
+        let mut rounds: Vec<_> = self.rounds.iter().collect();
+
+        rounds.sort_by_key(|a| &a.hand);
+
+        rounds
+            .iter()
+            .enumerate()
+            .try_fold(0u64, |total, (index, round)| {
+                let rank = index as u64 + 1;
+                total.checked_add(rank.checked_mul(round.bid)?)
+            })
+            .ok_or(anyhow!("Total winnings are too large"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Round {
+    pub hand: Hand,
+    pub bid: u64,
+}
+
+impl Round {
+    pub fn parse(options: ParserOptions) -> impl Fn(&str) -> IResult<&str, Self> {
+        // Example: "32T3K 765"
+        move |input: &str| {
+            map(
+                tuple((Hand::parse(options), space1, u64)),
+                |(hand, _, bid)| Self { hand, bid },
+            )(input)
+        }
+    }
+}
+
+/// Five cards, ordered by type and then card by card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+}
+
+impl Hand {
+    pub fn parse(options: ParserOptions) -> impl Fn(&str) -> IResult<&str, Self> {
+        // Example: "32T3K"
+        move |input: &str| map(many_m_n(5, 5, Card::parse(options)), |cards| Self { cards })(input)
+    }
+}
+
+impl Hand {
+    /// Number of five, four, three, two and single cards of a kind, jokers
+    /// joining the largest set. Comparing these orders hands by type.
+    pub fn get_set_counts(&self) -> Vec<u64> {
+        let mut card_map: HashMap<Card, u64> = HashMap::new();
+
+        for &c in &self.cards {
+            *card_map.entry(c).or_insert(0) += 1;
+        }
+
+        if let Some(joker_count) = card_map.remove(&Card::Joker) {
+            if let Some((&card, &count)) = card_map.iter().max_by_key(|(_, &count)| count) {
+                card_map.insert(card, count + joker_count);
+            }
+        }
+
+        (1..=5)
+            .rev()
+            .map(|count| card_map.values().filter(|&&c| c == count).count() as u64)
+            .collect()
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.get_set_counts(), &self.cards).cmp(&(other.get_set_counts(), &other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    pub fn parse(options: ParserOptions) -> impl Fn(&str) -> IResult<&str, Self> {
+        move |input: &str| {
+            alt((
+                value(Card::Two, tag("2")),
+                value(Card::Three, tag("3")),
+                value(Card::Four, tag("4")),
+                value(Card::Five, tag("5")),
+                value(Card::Six, tag("6")),
+                value(Card::Seven, tag("7")),
+                value(Card::Eight, tag("8")),
+                value(Card::Nine, tag("9")),
+                value(Card::Ten, tag("T")),
+                value(
+                    match options.j_parse {
+                        JParse::Jack => Card::Jack,
+                        JParse::Joker => Card::Joker,
+                    },
+                    tag("J"),
+                ),
+                value(Card::Queen, tag("Q")),
+                value(Card::King, tag("K")),
+                value(Card::Ace, tag("A")),
+            ))(input)
+        }
+    }
+}
+
+pub struct Day07 {
+    game1: Game,
+    game2: Game,
+}
+
+impl Day07 {
+    /// Hands as read for part 1, with jacks.
+    pub fn game1(&self) -> &Game {
+        &self.game1
+    }
+
+    /// Hands as read for part 2, with jokers.
+    pub fn game2(&self) -> &Game {
+        &self.game2
+    }
+}
+
+impl Solution for Day07 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 7;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game1 = parse_all(input, Game::parse(ParserOptions::default()))
+            .context("Error parsing input")?;
+
+        let game2 = parse_all(
+            input,
+            Game::parse(ParserOptions {
+                j_parse: JParse::Joker,
+            }),
+        )
+        .context("Error parsing input")?;
+
+        Ok(Self { game1, game2 })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game1.puzzle()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game2.puzzle()?.into())
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+
+use day_07::{Card, Day07, Game, Hand, JParse, ParserOptions};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let (_, game) = Game::parse(ParserOptions::default())(SAMPLE)?;
+
+    assert_eq!(game.puzzle()?, 6440);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let (_, game) = Game::parse(ParserOptions {
+        j_parse: JParse::Joker,
+    })(SAMPLE)?;
+
+    assert_eq!(game.puzzle()?, 5905);
+
+    Ok(())
+}
+
+#[test]
+fn parse_v1() -> Result<()> {
+    let (_, card) = Card::parse(ParserOptions::default())("J")?;
+
+    assert_eq!(card, Card::Jack);
+
+    Ok(())
+}
+
+#[test]
+fn parse_v2() -> Result<()> {
+    let (_, card) = Card::parse(ParserOptions {
+        j_parse: JParse::Joker,
+    })("J")?;
+
+    assert_eq!(card, Card::Joker);
+
+    Ok(())
+}
+
+#[test]
+fn hands() -> Result<()> {
+    let joker = ParserOptions {
+        j_parse: JParse::Joker,
+    };
+
+    let (_, two_pair) = Hand::parse(ParserOptions::default())("KTJJT")?;
+    let (_, four) = Hand::parse(joker)("KTJJT")?;
+
+    assert_eq!(two_pair.get_set_counts(), [0, 0, 0, 2, 1]);
+    assert_eq!(four.get_set_counts(), [0, 1, 0, 0, 1]);
+    assert!(two_pair > Hand::parse(ParserOptions::default())("32T3K")?.1);
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day07::parse(SAMPLE)?;
+
+    assert_eq!(day.game1().rounds[0].bid, 765);
+    assert_eq!(day.game2().rounds[3].hand.cards[2], Card::Joker);
+    assert_eq!(day.part2()?, Answer::from(5905u64));
+
+    Ok(())
+}
+
+#[test]
+fn large_bids() -> Result<()> {
+    let day = Day07::parse("32T3K 18446744073709551615\nT55J5 2\n")?;
+
+    assert!(day.part1().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day08;
+
+const PRIMES: [usize; 12] = [37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83];
+
+const CHARS: &[u8] = b"0123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+
+/// Id of a node, never ending with `A` or `Z`.
+fn name(mut index: usize) -> String {
+    let mut name = vec![CHARS[index % CHARS.len()]];
+    index /= CHARS.len();
+
+    while name.len() < 3 || index > 0 {
+        name.push(CHARS[index % CHARS.len()]);
+        index /= CHARS.len();
+    }
+
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+impl Generate for Day08 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("instructions", 13, "Number of instructions"),
+        Param::new("ghosts", 6, "Number of start nodes, at most 12"),
+    ];
+
+    /// Every ghost walks a loop of two nodes wide layers, each node leading
+    /// to both nodes of the next layer. The loop runs from its `Z` node back
+    /// to it, and its length is the number of instructions times a prime.
+    ///
+    /// `AAA` starts the first loop.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let instructions = params.size("instructions", 1);
+        let ghosts = params.size("ghosts", 1).min(PRIMES.len());
+
+        let mut names = (0..).map(name);
+        let mut nodes = Vec::new();
+
+        for (ghost, &prime) in PRIMES.choose_multiple(rng, ghosts).enumerate() {
+            let [start, end] = match ghost {
+                0 => ["AAA".to_string(), "ZZZ".to_string()],
+                _ => ["A", "Z"].map(|suffix| format!("{}{suffix}", &name(ghost)[1..])),
+            };
+
+            let layers = (1..instructions * prime)
+                .map(|_| [names.next().unwrap(), names.next().unwrap()])
+                .collect::<Vec<_>>();
+
+            let first = &layers[0];
+            nodes.push(format!("{start} = ({}, {})", first[0], first[1]));
+            nodes.push(format!("{end} = ({}, {})", first[0], first[1]));
+
+            for pair in layers.windows(2) {
+                for node in &pair[0] {
+                    let mut next = pair[1].clone();
+                    next.shuffle(rng);
+
+                    nodes.push(format!("{node} = ({}, {})", next[0], next[1]));
+                }
+            }
+
+            for node in layers.last().unwrap() {
+                nodes.push(format!("{node} = ({end}, {end})"));
+            }
+        }
+
+        nodes.shuffle(rng);
+
+        let instructions = (0..instructions)
+            .map(|_| if rng.gen() { 'L' } else { 'R' })
+            .collect::<String>();
+
+        format!("{instructions}\n\n{}\n", nodes.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Answer, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_name() {
+        assert_eq!(name(0), "000");
+        assert_eq!(name(35), "011");
+        assert_eq!(name(34 * 34 * 34), "1000");
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let params = "instructions=3,ghosts=3".parse().unwrap();
+            let input = generate::<Day08>(seed, &params).unwrap();
+            let day = Day08::parse(&input).unwrap();
+
+            let Answer::Number(steps) = day.part1().unwrap() else {
+                panic!("Expected a number");
+            };
+
+            assert_eq!(steps % 3, 0);
+            assert!(day.part2().is_ok());
+        }
+    }
+}
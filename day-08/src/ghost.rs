@@ -0,0 +1,110 @@
+use aoc_core::math::{crt, Congruence};
+
+/// Steps at which one ghost stands on a `Z` node.
+///
+/// A ghost's state is its node and its position in the instructions, so it
+/// eventually loops: after `cycle_start` steps, the next `cycle_length` steps
+/// repeat forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ghost {
+    pub cycle_start: u64,
+    pub cycle_length: u64,
+    /// Sorted hits up to the end of the first loop.
+    pub hits: Vec<u64>,
+}
+
+impl Ghost {
+    /// Whether the ghost is on a `Z` node after `step` steps.
+    pub fn is_hit(&self, step: u64) -> bool {
+        if step < self.cycle_start + self.cycle_length {
+            return self.hits.binary_search(&step).is_ok();
+        }
+
+        self.hits
+            .iter()
+            .any(|&h| h >= self.cycle_start && (step - h).is_multiple_of(self.cycle_length))
+    }
+
+    /// One congruence per hit inside the loop.
+    fn congruences(&self) -> impl Iterator<Item = Congruence> + '_ {
+        self.hits
+            .iter()
+            .filter(|&&h| h >= self.cycle_start)
+            .filter_map(|&h| Congruence::new(h.into(), self.cycle_length.into()))
+    }
+}
+
+/// First step at which all `ghosts` are on a `Z` node at the same time.
+///
+/// Steps before every ghost entered its loop are checked one by one, the
+/// rest is solved with the Chinese Remainder Theorem for every combination
+/// of hits, so loops may have offsets and several hits.
+pub fn first_common_hit(ghosts: &[Ghost]) -> Option<i128> {
+    let (first, others) = ghosts.split_first()?;
+    let looping = ghosts.iter().map(|g| g.cycle_start).max()?;
+
+    let early = first
+        .hits
+        .iter()
+        .take_while(|&&h| h < looping)
+        .find(|&&h| others.iter().all(|g| g.is_hit(h)));
+
+    if let Some(&step) = early {
+        return Some(step.into());
+    }
+
+    let combinations = ghosts.iter().fold(vec![crt([])?], |combinations, ghost| {
+        combinations
+            .iter()
+            .flat_map(|c| ghost.congruences().filter_map(|g| c.combine(g)))
+            .collect()
+    });
+
+    combinations
+        .iter()
+        .filter_map(|c| c.first_from(looping.into()))
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ghost(cycle_start: u64, cycle_length: u64, hits: &[u64]) -> Ghost {
+        Ghost {
+            cycle_start,
+            cycle_length,
+            hits: hits.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_pure_cycles() {
+        let ghosts = [ghost(1, 2, &[2]), ghost(1, 3, &[3])];
+
+        assert_eq!(first_common_hit(&ghosts), Some(6));
+    }
+
+    #[test]
+    fn test_offsets() {
+        // Hits at 3, 8, 13, 18, ... and 4, 11, 18, ...
+        let ghosts = [ghost(2, 5, &[3]), ghost(0, 7, &[4])];
+
+        assert_eq!(first_common_hit(&ghosts), Some(18));
+    }
+
+    #[test]
+    fn test_hit_before_cycle() {
+        // The first ghost only passes 1 once, before it loops on 5..8.
+        let ghosts = [ghost(5, 3, &[1, 6]), ghost(0, 1, &[0])];
+
+        assert_eq!(first_common_hit(&ghosts), Some(1));
+    }
+
+    #[test]
+    fn test_never() {
+        let ghosts = [ghost(0, 2, &[0]), ghost(0, 2, &[1])];
+
+        assert_eq!(first_common_hit(&ghosts), None);
+    }
+}
@@ -0,0 +1,201 @@
+//! Day 8: Haunted Wasteland
+//!
+//! A network of nodes walked by following left/right instructions, see
+//! [`Graph`]. Part 2 walks all `A` nodes at once as [`Ghost`]s.
+
+mod generate;
+mod ghost;
+mod node;
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{parse_all, Answer, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::newline,
+    combinator::map,
+    multi::{many0, separated_list0},
+    sequence::terminated,
+    IResult,
+};
+
+pub use ghost::{first_common_hit, Ghost};
+pub use node::Node;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// A node is named as a successor but never defined.
+    NodeNotFound(String),
+    NoInstructions,
+    /// The walk from this node loops without reaching a `Z` node.
+    NoZNode(String),
+    GhostsNeverMeet,
+}
+
+type Result<T, E = GraphError> = std::result::Result<T, E>;
+
+impl std::error::Error for GraphError {}
+
+impl std::fmt::Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NodeNotFound(id) => write!(f, "node {id} not found"),
+            Self::NoInstructions => write!(f, "no instructions"),
+            Self::NoZNode(start) => write!(f, "no Z node reached from {start}"),
+            Self::GhostsNeverMeet => write!(f, "ghosts never reach Z nodes together"),
+        }
+    }
+}
+
+/// The instructions and the network they are followed in.
+#[derive(Debug)]
+pub struct Graph {
+    pub instructions: Vec<Instruction>,
+    pub nodes: Vec<Node>,
+}
+
+impl Graph {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, instructions) = many0(alt((
+            map(tag("L"), |_| Instruction::Left),
+            map(tag("R"), |_| Instruction::Right),
+        )))(input)?;
+
+        let (input, _) = many0(newline)(input)?;
+
+        let (input, nodes) =
+            terminated(separated_list0(newline, Node::parse), many0(newline))(input)?;
+
+        Ok((
+            input,
+            Self {
+                instructions,
+                nodes,
+            },
+        ))
+    }
+
+    /// Steps from `AAA` to a `Z` node.
+    pub fn part1(&self) -> Result<u64> {
+        self.find_steps("AAA")
+    }
+
+    /// Steps until all ghosts are on `Z` nodes at the same time.
+    pub fn part2(&self) -> Result<i128> {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|n| (n.id.as_str(), n))
+            .collect::<HashMap<_, _>>();
+
+        let ghosts = self
+            .nodes
+            .iter()
+            .filter(|n| n.id.ends_with('A'))
+            .map(|n| self.follow_ghost(&nodes, &n.id))
+            .collect::<Result<Vec<_>>>()?;
+
+        first_common_hit(&ghosts).ok_or(GraphError::GhostsNeverMeet)
+    }
+
+    /// Walks from `start` until the ghost loops.
+    pub fn follow_ghost(&self, nodes: &HashMap<&str, &Node>, start: &str) -> Result<Ghost> {
+        let mut seen = HashMap::new();
+        let mut hits = Vec::new();
+        let mut node_id = start;
+
+        for (step, (i, instruction)) in self.instructions.iter().enumerate().cycle().enumerate() {
+            let step = step as u64;
+
+            if let Some(cycle_start) = seen.insert((node_id, i), step) {
+                return Ok(Ghost {
+                    cycle_start,
+                    cycle_length: step - cycle_start,
+                    hits,
+                });
+            }
+
+            if node_id.ends_with('Z') {
+                hits.push(step);
+            }
+
+            let node = nodes
+                .get(node_id)
+                .ok_or_else(|| GraphError::NodeNotFound(node_id.to_string()))?;
+
+            node_id = match instruction {
+                Instruction::Left => &node.left,
+                Instruction::Right => &node.right,
+            };
+        }
+
+        Err(GraphError::NoInstructions)
+    }
+
+    /// Steps from `starting_node_id` to the first node ending with `Z`.
+    pub fn find_steps(&self, starting_node_id: &str) -> Result<u64> {
+        let mut node_id = starting_node_id.to_string();
+        let mut seen = HashSet::new();
+
+        for (step, (i, instruction)) in self.instructions.iter().enumerate().cycle().enumerate() {
+            if node_id.ends_with("Z") {
+                return Ok(step as u64);
+            }
+
+            if !seen.insert((node_id.clone(), i)) {
+                return Err(GraphError::NoZNode(starting_node_id.to_string()));
+            }
+
+            let node = self
+                .nodes
+                .iter()
+                .find(|n| n.id == node_id)
+                .ok_or_else(|| GraphError::NodeNotFound(node_id.clone()))?;
+
+            node_id = {
+                match instruction {
+                    Instruction::Left => node.left.clone(),
+                    Instruction::Right => node.right.clone(),
+                }
+            };
+        }
+
+        Err(GraphError::NoInstructions)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+pub struct Day08 {
+    graph: Graph,
+}
+
+impl Day08 {
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+}
+
+impl Solution for Day08 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let graph = parse_all(input, Graph::parse)?;
+
+        Ok(Self { graph })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        Ok(self.graph.part1()?.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        Ok(self.graph.part2()?.into())
+    }
+}
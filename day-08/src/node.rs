@@ -5,6 +5,7 @@ use nom::{
     IResult,
 };
 
+/// A node and the nodes to its left and right.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Node {
     pub id: String,
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+
+use day_08::{Day08, Graph, GraphError, Instruction};
+
+#[test]
+fn part1_sample1() {
+    let graph = parse_all(include_str!("../src/sample-input.txt"), Graph::parse).unwrap();
+
+    assert_eq!(graph.part1().unwrap(), 2);
+}
+
+#[test]
+fn part1_sample2() {
+    let graph = parse_all(include_str!("../src/sample-input-2.txt"), Graph::parse).unwrap();
+
+    assert_eq!(graph.part1().unwrap(), 6);
+}
+
+#[test]
+fn part2() {
+    let graph = parse_all(include_str!("../src/sample-input-3.txt"), Graph::parse).unwrap();
+
+    assert_eq!(graph.part2().unwrap(), 6);
+}
+
+#[test]
+fn ghost() -> Result<()> {
+    let graph = parse_all(include_str!("../src/sample-input-3.txt"), Graph::parse)?;
+    let nodes = graph
+        .nodes
+        .iter()
+        .map(|n| (n.id.as_str(), n))
+        .collect::<HashMap<_, _>>();
+
+    let ghost = graph.follow_ghost(&nodes, "11A")?;
+
+    assert_eq!(ghost.cycle_length, 2);
+    assert!(ghost.is_hit(2));
+    assert!(ghost.is_hit(10));
+    assert!(!ghost.is_hit(3));
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day08::parse(include_str!("../src/sample-input-2.txt"))?;
+
+    assert_eq!(
+        day.graph().instructions,
+        [Instruction::Left, Instruction::Left, Instruction::Right]
+    );
+    assert_eq!(day.part1()?, Answer::from(6u64));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> Result<()> {
+    // A missing node, a loop without `Z` node and no instructions at all.
+    for (input, error) in [
+        (
+            "L\n\nAAA = (BBB, BBB)\n",
+            GraphError::NodeNotFound("BBB".to_string()),
+        ),
+        (
+            "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n",
+            GraphError::NoZNode("AAA".to_string()),
+        ),
+        (
+            "\nAAA = (BBB, BBB)\nBBB = (ZZZ, ZZZ)\n",
+            GraphError::NoInstructions,
+        ),
+    ] {
+        assert!(Day08::parse(input)?.part1().is_err());
+        assert_eq!(parse_all(input, Graph::parse)?.part1(), Err(error));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day09;
+
+impl Generate for Day09 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("lines", 200, "Number of sequences"),
+        Param::new("length", 21, "Values per sequence"),
+        Param::new("degree", 6, "Highest degree of the polynomials"),
+    ];
+
+    /// Values of random polynomials with small coefficients, so that the
+    /// differences reach zero before the sequence runs out.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let length = params.size("length", 2);
+        let max_degree = params.size("degree", 0).min(length - 2);
+        let mut input = String::new();
+
+        for _ in 0..params.get("lines") {
+            let degree = rng.gen_range(0..=max_degree);
+            let coefficients = (0..=degree)
+                .map(|i| rng.gen_range(-30..=30) / (i as i64 + 1))
+                .collect::<Vec<_>>();
+
+            let values = (0..length as i64)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, c| value * x + c)
+                        .to_string()
+                })
+                .collect::<Vec<_>>();
+
+            input.push_str(&values.join(" "));
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day09>(seed, &"lines=50".parse().unwrap()).unwrap();
+            let day = Day09::parse(&input).unwrap();
+
+            assert_eq!(day.game().inputs.len(), 50);
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Day 9: Mirage Maintenance
+//!
+//! Sequences extrapolated from their repeated differences, see [`Game`].
+
+mod generate;
+
+use anyhow::{anyhow, Result};
+use aoc_core::{Answer, ParseError, Solution};
+
+/// The sequences of the report.
+#[derive(Debug)]
+pub struct Game {
+    pub inputs: Vec<Vec<i64>>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Result<Self> {
+        let inputs = input
+            .lines()
+            .map(|l| {
+                l.split(' ')
+                    .map(|v| v.parse().map_err(|_| ParseError::at(input, v, "a number")))
+                    .collect()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { inputs })
+    }
+
+    /// Differences between consecutive values.
+    pub fn diff(sequence: &[i64]) -> Result<Vec<i64>> {
+        sequence
+            .windows(2)
+            .map(|window| {
+                let [a, b] = window else {
+                    unreachable!("windows of two values")
+                };
+
+                b.checked_sub(*a)
+                    .ok_or_else(|| anyhow!("Difference of {a} and {b} is too large"))
+            })
+            .collect()
+    }
+
+    /// Value following `sequence`.
+    pub fn next_prediction(sequence: &[i64]) -> Result<i64> {
+        if sequence.iter().all(|&i| i == 0) {
+            return Ok(0);
+        }
+
+        let last = sequence.last().ok_or_else(|| anyhow!("Empty sequence"))?;
+
+        last.checked_add(Self::next_prediction(&Self::diff(sequence)?)?)
+            .ok_or_else(|| anyhow!("Prediction is too large"))
+    }
+
+    /// Value preceding `sequence`.
+    pub fn prev_prediction(sequence: &[i64]) -> Result<i64> {
+        if sequence.iter().all(|&i| i == 0) {
+            return Ok(0);
+        }
+
+        let first = sequence.first().ok_or_else(|| anyhow!("Empty sequence"))?;
+
+        first
+            .checked_sub(Self::prev_prediction(&Self::diff(sequence)?)?)
+            .ok_or_else(|| anyhow!("Prediction is too large"))
+    }
+
+    /// Sum of the next values.
+    pub fn part1(&self) -> Result<i64> {
+        Self::sum(self.inputs.iter().map(|i| Self::next_prediction(i)))
+    }
+
+    /// Sum of the previous values.
+    pub fn part2(&self) -> Result<i64> {
+        Self::sum(self.inputs.iter().map(|i| Self::prev_prediction(i)))
+    }
+
+    fn sum(mut predictions: impl Iterator<Item = Result<i64>>) -> Result<i64> {
+        predictions.try_fold(0i64, |sum, prediction| {
+            sum.checked_add(prediction?)
+                .ok_or_else(|| anyhow!("Sum of the predictions is too large"))
+        })
+    }
+}
+
+pub struct Day09 {
+    game: Game,
+}
+
+impl Day09 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day09 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            game: Game::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.part1()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
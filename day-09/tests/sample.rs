@@ -0,0 +1,62 @@
+use anyhow::Result;
+use aoc_core::{Answer, ParseError, Solution};
+
+use day_09::{Day09, Game};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let game = Game::parse(SAMPLE)?;
+
+    assert_eq!(game.part1()?, 114);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let game = Game::parse(SAMPLE)?;
+
+    assert_eq!(game.part2()?, 2);
+
+    Ok(())
+}
+
+#[test]
+fn parse_error() {
+    let err = Game::parse("0 3 6\n1 three 5\n").unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap();
+
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.text, "1 three 5");
+}
+
+#[test]
+fn predictions() -> Result<()> {
+    assert_eq!(Game::diff(&[1, 3, 6, 10])?, [2, 3, 4]);
+    assert_eq!(Game::next_prediction(&[1, 3, 6, 10])?, 15);
+    assert_eq!(Game::prev_prediction(&[10, 13, 16, 21, 30, 45])?, 5);
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day09::parse(SAMPLE)?;
+
+    assert_eq!(day.game().inputs[0], [0, 3, 6, 9, 12, 15]);
+    assert_eq!(day.part1()?, Answer::from(114i64));
+
+    Ok(())
+}
+
+#[test]
+fn large_values() -> Result<()> {
+    let day = Day09::parse("0 9223372036854775807 0\n")?;
+
+    assert!(day.part1().is_err());
+    assert!(day.part2().is_err());
+
+    Ok(())
+}
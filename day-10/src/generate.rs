@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Param, Params};
+use grid::{Direction, Grid, Position};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day10;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+fn pipe(a: Direction, b: Direction) -> char {
+    use Direction::*;
+
+    match (a.min(b), a.max(b)) {
+        (Up, Down) => '|',
+        (Right, Left) => '-',
+        (Up, Right) => 'L',
+        (Up, Left) => 'J',
+        (Down, Left) => '7',
+        (Right, Down) => 'F',
+        _ => unreachable!("A pipe connects two different directions"),
+    }
+}
+
+/// Random tree grown from a random node of a `width` x `height` grid, until
+/// it holds `fill` percent of the nodes. Returns its nodes and edges.
+fn tree<R: Rng>(
+    rng: &mut R,
+    width: usize,
+    height: usize,
+    fill: u64,
+) -> (Grid<bool>, HashSet<(Position, Position)>) {
+    let mut nodes = Grid::new(width, height, false);
+    let mut edges = HashSet::new();
+
+    let start = Position(
+        rng.gen_range(0..width) as i64,
+        rng.gen_range(0..height) as i64,
+    );
+    nodes[start] = true;
+
+    let mut frontier = nodes
+        .neighbours4(start)
+        .map(|n| (start, n))
+        .collect::<Vec<_>>();
+    let mut count = 1;
+
+    while count * 100 < (width * height) as u64 * fill && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+
+        if nodes[to] {
+            continue;
+        }
+
+        nodes[to] = true;
+        edges.extend([(from, to), (to, from)]);
+        frontier.extend(nodes.neighbours4(to).map(|n| (to, n)));
+        count += 1;
+    }
+
+    (nodes, edges)
+}
+
+impl Generate for Day10 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", 140, "Number of columns"),
+        Param::new("height", 140, "Number of rows"),
+        Param::new("fill", 60, "Percentage of the field within the loop"),
+        Param::new("junk", 70, "Percentage of pipes among the other tiles"),
+    ];
+
+    /// The loop runs around a random tree drawn three times larger, whose
+    /// nodes and edges are the tiles inside.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let width = params.size("width", 3);
+        let height = params.size("height", 3);
+        let junk = params.get("junk");
+
+        let (nodes, edges) = tree(rng, width / 3, height / 3, params.get("fill"));
+        let block = |p: Position| Position(p.0 / 3, p.1 / 3);
+
+        // Tiles inside the loop, the centres of the nodes and their edges.
+        let mut inside = HashSet::new();
+
+        for (node, _) in nodes.iter().filter(|(_, &n)| n) {
+            let centre = Position(node.0 * 3 + 1, node.1 * 3 + 1);
+
+            inside.insert(centre);
+
+            for dir in Direction::ALL {
+                if edges.contains(&(node, node.move_dir(dir))) {
+                    inside.extend([centre.move_dir(dir), centre.move_by(dir, 2)]);
+                }
+            }
+        }
+
+        let on_loop = |p: Position| {
+            p.0 < nodes.width() as i64 * 3
+                && p.1 < nodes.height() as i64 * 3
+                && nodes.get(block(p)) == Some(&true)
+                && !inside.contains(&p)
+        };
+
+        let mut field = Grid::new(width, height, '.');
+        let mut tiles = Vec::new();
+
+        for pos in field.positions().collect::<Vec<_>>() {
+            if on_loop(pos) {
+                // Neighbours on the loop are only connected within a node or
+                // along an edge, otherwise they are squeezed between pipes.
+                let dirs = Direction::ALL
+                    .into_iter()
+                    .filter(|&d| {
+                        let next = pos.move_dir(d);
+                        let (a, b) = (block(pos), block(next));
+
+                        on_loop(next) && (a == b || edges.contains(&(a, b)))
+                    })
+                    .collect::<Vec<_>>();
+
+                field[pos] = pipe(dirs[0], dirs[1]);
+                tiles.push(pos);
+            } else if rng.gen_range(0..100) < junk {
+                field[pos] = *PIPES.choose(rng).unwrap();
+            }
+        }
+
+        let start = *tiles.choose(rng).unwrap();
+        field[start] = 'S';
+
+        for next in start.neighbours4() {
+            if !on_loop(next) {
+                if let Some(tile) = field.get_mut(next) {
+                    *tile = '.';
+                }
+            }
+        }
+
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let params = "width=30,height=20".parse().unwrap();
+            let input = generate::<Day10>(seed, &params).unwrap();
+            let day = Day10::parse(&input).unwrap();
+
+            assert_eq!(day.game().map.width(), 30);
+            assert_eq!(day.game().map.height(), 20);
+            assert!(day.game().find_loop().len() >= 8);
+            assert!(day.part2().is_ok());
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Day 10: Pipe Maze
+//!
+//! A field of pipes with a single loop through the start, see [`Game`].
+
+mod generate;
+mod render;
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use aoc_core::{Answer, Solution};
+use graph::bfs;
+use grid::{Direction, Grid, Position};
+
+/// Directions a pipe connects to, empty for the ground.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tile(pub HashSet<Direction>);
+
+impl Tile {
+    pub fn parse(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Self(HashSet::from_iter([Direction::Up, Direction::Down]))),
+            '-' => Some(Self(HashSet::from_iter([
+                Direction::Right,
+                Direction::Left,
+            ]))),
+            'L' => Some(Self(HashSet::from_iter([Direction::Up, Direction::Right]))),
+
+            'J' => Some(Self(HashSet::from_iter([Direction::Up, Direction::Left]))),
+
+            '7' => Some(Self(HashSet::from_iter([Direction::Down, Direction::Left]))),
+
+            'F' => Some(Self(HashSet::from_iter([
+                Direction::Down,
+                Direction::Right,
+            ]))),
+
+            _ => None,
+        }
+    }
+}
+
+/// The field, the start tile being replaced by the pipe it hides.
+#[derive(Debug)]
+pub struct Game {
+    pub map: Grid<Tile>,
+    pub start_pos: Position,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Result<Self> {
+        let chars = Grid::parse(input, Some)?;
+
+        let start_pos = chars
+            .find(|&c| c == 'S')
+            .ok_or(anyhow!("Start position not found"))?;
+
+        let mut map = chars.map(|&c| Tile::parse(c).unwrap_or(Tile(HashSet::new())));
+
+        let mut set = HashSet::new();
+
+        for dir in Direction::ALL {
+            if let Some(tile) = map.get(start_pos.move_dir(dir)) {
+                if tile.0.contains(&dir.inverse()) {
+                    set.insert(dir);
+                }
+            }
+        }
+
+        map[start_pos] = Tile(set);
+
+        Ok(Self { map, start_pos })
+    }
+
+    /// Steps from the start to every tile of the loop.
+    pub fn find_loop(&self) -> HashMap<Position, usize> {
+        bfs(self.start_pos, |&point| {
+            self.map
+                .get(point)
+                .map(|tile| {
+                    tile.0
+                        .iter()
+                        .map(|&dir| point.move_dir(dir))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        })
+    }
+
+    /// Steps to the tile of the loop farthest from the start.
+    pub fn find_farthest(&self) -> usize {
+        self.find_loop().into_values().max().unwrap_or(0)
+    }
+
+    /// Number of tiles enclosed by the loop.
+    pub fn find_inside_tiles(&self) -> i64 {
+        self.inside_tiles(&self.find_loop()).len() as i64
+    }
+
+    /// Tiles enclosed by `wall_tiles`, the loop found by [`Game::find_loop`].
+    pub fn inside_tiles(&self, wall_tiles: &HashMap<Position, usize>) -> HashSet<Position> {
+        let mut tiles = HashSet::new();
+        let mut inside = false;
+
+        for (point, tile) in self.map.iter() {
+            if wall_tiles.contains_key(&point) {
+                if tile.0.contains(&Direction::Up) {
+                    inside = !inside;
+                }
+            } else if inside {
+                tiles.insert(point);
+            }
+        }
+
+        tiles
+    }
+}
+
+pub struct Day10 {
+    game: Game,
+}
+
+impl Day10 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day10 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 10;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            game: Game::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.find_farthest().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.find_inside_tiles().into())
+    }
+}
@@ -0,0 +1,75 @@
+use aoc_core::{Part, Render};
+use grid::{Direction, Grid, Pixel, Rgb};
+
+use crate::{Day10, Tile};
+
+impl Tile {
+    /// Box drawing character of the pipe, a space for the ground.
+    pub fn glyph(&self) -> char {
+        let has = |dir| self.0.contains(&dir);
+
+        match (
+            has(Direction::Up),
+            has(Direction::Right),
+            has(Direction::Down),
+            has(Direction::Left),
+        ) {
+            (true, false, true, false) => '│',
+            (false, true, false, true) => '─',
+            (true, true, false, false) => '└',
+            (true, false, false, true) => '┘',
+            (false, false, true, true) => '┐',
+            (false, true, true, false) => '┌',
+            _ => ' ',
+        }
+    }
+}
+
+impl Render for Day10 {
+    /// The loop, from green at the start to red at the farthest tile for
+    /// part 1, with the tiles it encloses in yellow for part 2.
+    fn render(&self, part: Part) -> anyhow::Result<Grid<Pixel>> {
+        let wall_tiles = self.game.find_loop();
+        let farthest = wall_tiles.values().copied().max().unwrap_or(0).max(1);
+
+        let inside = match part {
+            Part::One => Default::default(),
+            Part::Two => self.game.inside_tiles(&wall_tiles),
+        };
+
+        Ok(self
+            .game
+            .map
+            .map_with_position(|pos, tile| match (wall_tiles.get(&pos), part) {
+                (Some(&steps), Part::One) => Pixel::new(
+                    tile.glyph(),
+                    Rgb::GREEN.mix(Rgb::RED, steps as f64 / farthest as f64),
+                ),
+                (Some(_), Part::Two) => Pixel::new(tile.glyph(), Rgb::BLUE),
+                (None, _) if inside.contains(&pos) => Pixel::new('I', Rgb::YELLOW),
+                (None, _) => Pixel::new('.', Rgb::DARK_GREY),
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let day = Day10::parse(include_str!("sample-input-3.txt")).unwrap();
+
+        let grid = day.render(Part::Two).unwrap();
+        let inside = grid.iter().filter(|(_, p)| p.glyph == 'I').count();
+
+        assert_eq!(inside, 4);
+        assert_eq!(grid.rows().nth(1).unwrap()[1].glyph, '┌');
+
+        let grid = day.render(Part::One).unwrap();
+        assert_eq!(grid[day.game().start_pos].colour, Rgb::GREEN);
+        assert!(!grid.to_string().contains('I'));
+    }
+}
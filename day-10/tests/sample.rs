@@ -0,0 +1,51 @@
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+use grid::{Direction, Position};
+
+use day_10::{Day10, Game, Tile};
+
+#[test]
+fn part1() -> Result<()> {
+    let game = Game::parse(include_str!("../src/sample-input.txt"))?;
+    assert_eq!(game.find_farthest(), 4);
+
+    let game = Game::parse(include_str!("../src/sample-input-2.txt"))?;
+    assert_eq!(game.find_farthest(), 8);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    for (input, inside) in [
+        (include_str!("../src/sample-input-3.txt"), 4),
+        (include_str!("../src/sample-input-4.txt"), 8),
+        (include_str!("../src/sample-input-5.txt"), 10),
+    ] {
+        assert_eq!(Game::parse(input)?.find_inside_tiles(), inside);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn start_tile() -> Result<()> {
+    let game = Game::parse(include_str!("../src/sample-input.txt"))?;
+
+    assert_eq!(game.start_pos, Position(1, 1));
+    assert_eq!(game.map[game.start_pos], Tile::parse('F').unwrap());
+    assert_eq!(game.find_loop().len(), 8);
+    assert!(Tile::parse('.').is_none());
+    assert!(Tile::parse('|').unwrap().0.contains(&Direction::Up));
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day10::parse(include_str!("../src/sample-input-5.txt"))?;
+
+    assert_eq!(day.part2()?, Answer::from(10i64));
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day11;
+
+impl Generate for Day11 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", 140, "Number of columns"),
+        Param::new("height", 140, "Number of rows"),
+        Param::new("galaxies", 2, "Percentage of galaxies among the tiles"),
+        Param::new("empty", 5, "Percentage of rows and columns left empty"),
+    ];
+
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let (width, height) = (params.size("width", 1), params.size("height", 1));
+        let empty = params.get("empty");
+
+        let empty_cols = (0..width)
+            .map(|_| rng.gen_range(0..100) < empty)
+            .collect::<Vec<_>>();
+        let empty_rows = (0..height)
+            .map(|_| rng.gen_range(0..100) < empty)
+            .collect::<Vec<_>>();
+
+        let mut image = String::new();
+
+        for &empty_row in &empty_rows {
+            for &empty_col in &empty_cols {
+                let is_empty = empty_row || empty_col;
+
+                if !is_empty && rng.gen_range(0..100) < params.get("galaxies") {
+                    image.push('#');
+                } else {
+                    image.push('.');
+                }
+            }
+
+            image.push('\n');
+        }
+
+        // The image needs a galaxy, even when small or sparse.
+        if !image.contains('#') {
+            let (x, y) = (rng.gen_range(0..width), rng.gen_range(0..height));
+            let index = y * (width + 1) + x;
+
+            image.replace_range(index..=index, "#");
+        }
+
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Answer, Solution};
+
+    use super::*;
+
+    /// Sum of the distances of `input`, crossing each empty row or column
+    /// one step at a time.
+    fn reference(input: &str, factor: u64) -> u64 {
+        let rows = input.lines().map(|l| l.as_bytes()).collect::<Vec<_>>();
+        let galaxies = (0..rows.len())
+            .flat_map(|y| (0..rows[y].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| rows[y][x] == b'#')
+            .collect::<Vec<_>>();
+
+        let steps = |from: usize, to: usize, empty: &dyn Fn(usize) -> bool| -> u64 {
+            (from.min(to)..from.max(to))
+                .map(|i| if empty(i) { factor } else { 1 })
+                .sum()
+        };
+        let empty_row = |y: usize| !rows[y].contains(&b'#');
+        let empty_col = |x: usize| rows.iter().all(|row| row[x] != b'#');
+
+        let mut total = 0;
+
+        for (i, &(ax, ay)) in galaxies.iter().enumerate() {
+            for &(bx, by) in &galaxies[i + 1..] {
+                total += steps(ax, bx, &empty_col) + steps(ay, by, &empty_row);
+            }
+        }
+
+        total
+    }
+
+    #[test]
+    fn test_generate_empty() {
+        for seed in 0..10 {
+            let params = "width=0,height=0,galaxies=0,empty=0".parse().unwrap();
+            let input = generate::<Day11>(seed, &params).unwrap();
+            let day = Day11::parse(&input).unwrap();
+
+            assert_eq!(input, "#\n");
+            assert_eq!(day.part1().unwrap(), Answer::from(0u64));
+
+            let params = "width=1,height=1,galaxies=1,empty=1".parse().unwrap();
+            let input = generate::<Day11>(seed, &params).unwrap();
+
+            assert!(Day11::parse(&input).unwrap().part2().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let params = "width=12,height=40,galaxies=10".parse().unwrap();
+            let input = generate::<Day11>(seed, &params).unwrap();
+            let day = Day11::parse(&input).unwrap();
+
+            assert_eq!(input.lines().count(), 40);
+            assert_eq!(day.part1().unwrap(), reference(&input, 2).into());
+            assert_eq!(day.part2().unwrap(), reference(&input, 1_000_000).into());
+        }
+    }
+}
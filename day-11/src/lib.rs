@@ -0,0 +1,136 @@
+//! Day 11: Cosmic Expansion
+//!
+//! Galaxies whose empty rows and columns grow, see [`Game`].
+
+mod generate;
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use aoc_core::{Answer, Solution};
+
+/// Positions of the galaxies in the image.
+#[derive(Debug)]
+pub struct Game {
+    /// `(x, y)` positions.
+    pub galaxies: HashSet<(u64, u64)>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Self {
+        let mut galaxies = HashSet::new();
+        for (y, line) in input.lines().enumerate() {
+            let y = y as u64;
+
+            for (x, c) in line.chars().enumerate() {
+                let x = x as u64;
+
+                if c == '#' {
+                    galaxies.insert((x, y));
+                }
+            }
+        }
+
+        Self { galaxies }
+    }
+
+    /// The image once every empty row and column is `expansion_factor`
+    /// times as large.
+    pub fn expand(&self, expansion_factor: u64) -> Result<Self> {
+        let max_x = self
+            .galaxies
+            .iter()
+            .map(|&(x, _)| x)
+            .max()
+            .ok_or_else(|| anyhow!("No values"))?;
+
+        let max_y = self
+            .galaxies
+            .iter()
+            .map(|&(_, y)| y)
+            .max()
+            .ok_or_else(|| anyhow!("No values"))?;
+
+        let growth = expansion_factor
+            .checked_sub(1)
+            .ok_or_else(|| anyhow!("Expansion factor must be at least 1"))?;
+
+        let empty_cols: Vec<_> = (0..=max_x)
+            .filter(|x| self.galaxies.iter().filter(|&(gx, _)| gx == x).count() == 0)
+            .collect();
+
+        let empty_rows: Vec<_> = (0..=max_y)
+            .filter(|y| self.galaxies.iter().filter(|&(_, gy)| gy == y).count() == 0)
+            .collect();
+
+        let galaxies = self
+            .galaxies
+            .iter()
+            .map(|&(x, y)| {
+                let move_x = empty_cols.iter().filter(|&&empty_x| empty_x < x).count() as u64;
+                let move_y = empty_rows.iter().filter(|&&empty_y| empty_y < y).count() as u64;
+
+                (x + move_x * growth, y + move_y * growth)
+            })
+            .collect();
+
+        Ok(Self { galaxies })
+    }
+
+    /// Sum of the distances between every pair of galaxies.
+    pub fn puzzle(&self) -> u64 {
+        self.galaxies
+            .iter()
+            .enumerate()
+            .flat_map(|(a, &pos_a)| {
+                self.galaxies
+                    .iter()
+                    .enumerate()
+                    .filter_map(
+                        move |(b, &pos_b)| {
+                            if a < b {
+                                Some((pos_a, pos_b))
+                            } else {
+                                None
+                            }
+                        },
+                    )
+            })
+            .map(|(a, b)| Self::distance(a, b))
+            .sum::<u64>()
+    }
+
+    /// Manhattan distance.
+    pub fn distance(a: (u64, u64), b: (u64, u64)) -> u64 {
+        a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+    }
+}
+
+pub struct Day11 {
+    game: Game,
+}
+
+impl Day11 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day11 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 11;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            game: Game::parse(input),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.expand(2)?.puzzle().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.expand(1_000_000)?.puzzle().into())
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+
+use day_11::{Day11, Game};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let game = Game::parse(SAMPLE);
+
+    assert_eq!(game.expand(2)?.puzzle(), 374);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let game = Game::parse(SAMPLE);
+
+    assert_eq!(game.expand(10)?.puzzle(), 1030);
+    assert_eq!(game.expand(100)?.puzzle(), 8410);
+
+    Ok(())
+}
+
+#[test]
+fn expand() -> Result<()> {
+    let game = Game::parse("#..\n...\n..#\n");
+    let expanded = game.expand(3)?;
+
+    assert!(expanded.galaxies.contains(&(4, 4)));
+    assert_eq!(Game::distance((0, 0), (4, 4)), 8);
+
+    // Taller than wide, the empty rows are past the last column.
+    let day = Day11::parse("#.\n..\n..\n..\n..\n#.\n")?;
+    assert_eq!(day.part1()?, Answer::from(9u64));
+    assert_eq!(day.part2()?, Answer::from(4_000_001u64));
+
+    assert!(game.expand(0).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day11::parse(SAMPLE)?;
+
+    assert_eq!(day.game().galaxies.len(), 9);
+    assert_eq!(day.part1()?, Answer::from(374u64));
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day12;
+
+impl Generate for Day12 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("rows", 1000, "Number of rows"),
+        Param::new("length", 20, "Maximum number of springs per row"),
+        Param::new(
+            "unknown",
+            40,
+            "Percentage of springs whose condition is unknown",
+        ),
+    ];
+
+    /// Conditions of each row are drawn first and the groups taken from
+    /// them, so that every row has at least one arrangement.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let length = params.size("length", 1);
+        let unknown = params.get("unknown");
+        let mut input = String::new();
+
+        for _ in 0..params.get("rows") {
+            let mut springs = (0..rng.gen_range(1..=length))
+                .map(|_| rng.gen_bool(0.5))
+                .collect::<Vec<_>>();
+
+            let at = rng.gen_range(0..springs.len());
+            springs[at] = true;
+
+            let groups = springs
+                .split(|&damaged| !damaged)
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>();
+
+            let record = springs
+                .iter()
+                .map(|&damaged| match damaged {
+                    _ if rng.gen_range(0..100) < unknown => '?',
+                    true => '#',
+                    false => '.',
+                })
+                .collect::<String>();
+
+            input.push_str(&format!("{record} {}\n", groups.join(",")));
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let params = "rows=50,length=12".parse().unwrap();
+            let input = generate::<Day12>(seed, &params).unwrap();
+            let day = Day12::parse(&input).unwrap();
+
+            assert!(day.game1().rows.iter().all(|r| r.option_count() > 0));
+        }
+    }
+}
@@ -0,0 +1,296 @@
+//! Day 12: Hot Springs
+//!
+//! Rows of springs whose damaged groups are known but whose condition is
+//! partly unknown, see [`Row`]. Part 2 unfolds every row five times.
+
+mod generate;
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{newline, space1, u128},
+    combinator::{map, value, verify},
+    multi::{many0, separated_list0},
+    sequence::{delimited, tuple},
+    IResult,
+};
+
+/// The condition records.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub rows: Vec<Row>,
+}
+
+impl Game {
+    /// Parses the records as written.
+    pub fn parse_1(input: &str) -> IResult<&str, Self> {
+        delimited(
+            many0(newline),
+            map(separated_list0(newline, Row::parse_1), |rows| Self { rows }),
+            many0(newline),
+        )(input)
+    }
+
+    /// Parses the records unfolded.
+    pub fn parse_2(input: &str) -> IResult<&str, Self> {
+        delimited(
+            many0(newline),
+            map(separated_list0(newline, Row::parse_2), |rows| Self { rows }),
+            many0(newline),
+        )(input)
+    }
+
+    /// Sum of the arrangements of every row.
+    pub fn puzzle(&self) -> u128 {
+        self.rows.iter().map(|row| row.option_count()).sum()
+    }
+}
+
+/// One condition record.
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub springs: Vec<Spring>,
+    /// Sizes of the contiguous groups of damaged springs, in order.
+    pub damaged_groups: Vec<u128>,
+}
+
+impl Row {
+    pub fn parse_1(input: &str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                many0(Spring::parse),
+                space1,
+                separated_list0(tag(","), verify(u128, |&size| size > 0)),
+            )),
+            |(springs, _, damaged_groups)| Self {
+                springs,
+                damaged_groups,
+            },
+        )(input)
+    }
+
+    /// Parses a row unfolded: five copies separated by unknown springs.
+    pub fn parse_2(input: &str) -> IResult<&str, Self> {
+        map(
+            Self::parse_1,
+            |Self {
+                 springs,
+                 damaged_groups,
+             }| {
+                let mut new_springs = springs.clone();
+                let mut new_damaged_groups = damaged_groups.clone();
+
+                for _ in 1..5 {
+                    new_springs.push(Spring::Unknown);
+                    new_springs.append(&mut springs.clone());
+                    new_damaged_groups.append(&mut damaged_groups.clone());
+                }
+
+                Self {
+                    springs: new_springs,
+                    damaged_groups: new_damaged_groups,
+                }
+            },
+        )(input)
+    }
+
+    /// Number of ways to replace the unknown springs so that the damaged
+    /// groups match.
+    ///
+    /// Runs of unknown springs followed by an operational one are counted
+    /// with combinations instead of trying every spring.
+    pub fn valid_count(springs: &[Spring], damaged_groups: &[u128]) -> u128 {
+        let damaged = damaged_groups
+            .iter()
+            .fold(0u128, |sum, &group| sum.saturating_add(group));
+
+        if !damaged_groups.is_empty()
+            && (springs.len() as u128) < damaged.saturating_add(damaged_groups.len() as u128 - 1)
+        {
+            return 0;
+        }
+
+        if (springs
+            .iter()
+            .filter(|&&s| s == Spring::Damaged || s == Spring::Unknown)
+            .count() as u128)
+            < damaged
+        {
+            return 0;
+        }
+
+        if damaged_groups.is_empty()
+            && springs
+                .iter()
+                .all(|&s| s == Spring::Operational || s == Spring::Unknown)
+        {
+            return 1;
+        }
+
+        if springs.starts_with(&[Spring::Unknown]) {
+            let pos = springs
+                .iter()
+                .position(|&s| s != Spring::Unknown)
+                .unwrap_or(springs.len());
+
+            if springs.get(pos) == Some(&Spring::Operational) {
+                let (start, end) = springs.split_at(pos);
+
+                let mut count = 0;
+
+                count += Self::valid_count(end, damaged_groups);
+
+                for i in 1..=damaged_groups.len() {
+                    let (start_groups, end_groups) = damaged_groups.split_at(i);
+
+                    let min_group_length =
+                        start_groups.iter().sum::<u128>() + (start_groups.len() as u128 - 1);
+
+                    if min_group_length > start.len() as u128 {
+                        break;
+                    }
+
+                    // number of combinations
+                    // ??? 1,1 -> 3 positions, 0 value -> 1 option
+                    // #.#
+                    //
+                    // ???? 1,1 -> 3 positions, 1 value -> 3 options
+                    // .#.# 0
+                    // #..# 1
+                    // #.#. 2
+                    //
+                    // ????? 1,1 -> 3 positions, 2 values -> 6 options
+                    // ..#.# 00
+                    // .#..# 01
+                    // #...# 02
+                    // .#.#. 11
+                    // .#..# 12
+                    // ..#.# 22
+                    //
+                    // Combinations with Repetitions: https://math.libretexts.org/Courses/Monroe_Community_College/MTH_220_Discrete_Math/7%3A_Combinatorics/7.5%3A_Combinations_WITH_Repetitions
+
+                    let c = combinations(
+                        start_groups.len() as u128 + 1,
+                        start.len() as u128 - min_group_length,
+                    );
+
+                    if c > 0 {
+                        count += c * Self::valid_count(end, end_groups);
+                    }
+                }
+
+                return count;
+            } else {
+                let mut springs_a = springs.to_vec();
+                springs_a[pos - 1] = Spring::Damaged;
+
+                let mut springs_b = springs.to_vec();
+                springs_b[pos - 1] = Spring::Operational;
+
+                return Self::valid_count(&springs_a, damaged_groups)
+                    + Self::valid_count(&springs_b, damaged_groups);
+            }
+        }
+
+        let mut count = 0;
+
+        if let Some(group) = damaged_groups.first() {
+            // Next groups starts at beginning of springs
+            let (start, end) = springs.split_at(*group as usize);
+
+            if start
+                .iter()
+                .all(|&s| s == Spring::Damaged || s == Spring::Unknown)
+            {
+                if let Some((&mid, end)) = end.split_first() {
+                    if mid == Spring::Operational || mid == Spring::Unknown {
+                        count += Self::valid_count(end, &damaged_groups[1..]);
+                    }
+                } else {
+                    count += Self::valid_count(end, &damaged_groups[1..]);
+                }
+            }
+
+            // Next groups does not start yet
+            let (start, end) = springs.split_at(1);
+            if start
+                .iter()
+                .all(|&s| s == Spring::Operational || s == Spring::Unknown)
+            {
+                count += Self::valid_count(end, damaged_groups);
+            }
+        }
+
+        count
+    }
+
+    pub fn option_count(&self) -> u128 {
+        Self::valid_count(&self.springs, &self.damaged_groups)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // ???.### 1,1,3
+        alt((
+            value(Spring::Operational, tag(".")),
+            value(Spring::Damaged, tag("#")),
+            value(Spring::Unknown, tag("?")),
+        ))(input)
+    }
+}
+
+/// Number of ways to put `r` items into `n` boxes, `(r + n - 1)! / (r! (n - 1)!)`.
+///
+/// Computed one factor at a time, every partial result being a binomial
+/// coefficient, so that it doesn't overflow before the result does.
+fn combinations(n: u128, r: u128) -> u128 {
+    (1..n).fold(1, |c, k| c * (r + k) / k)
+}
+
+pub struct Day12 {
+    game1: Game,
+    game2: Game,
+}
+
+impl Day12 {
+    /// Records as written, for part 1.
+    pub fn game1(&self) -> &Game {
+        &self.game1
+    }
+
+    /// Records unfolded, for part 2.
+    pub fn game2(&self) -> &Game {
+        &self.game2
+    }
+}
+
+impl Solution for Day12 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 12;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game1 = parse_all(input, Game::parse_1)?;
+        let game2 = parse_all(input, Game::parse_2)?;
+
+        Ok(Self { game1, game2 })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game1.puzzle().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game2.puzzle().into())
+    }
+}
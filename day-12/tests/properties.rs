@@ -0,0 +1,77 @@
+use proptest::prelude::*;
+
+use day_12::{Row, Spring};
+
+/// Sizes of the groups of damaged springs in `springs`, which are all known.
+fn groups(springs: &[Spring]) -> Vec<u128> {
+    springs
+        .split(|&s| s == Spring::Operational)
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len() as u128)
+        .collect()
+}
+
+/// Reference for [`Row::valid_count`], trying every replacement of the
+/// unknown springs.
+fn naive_valid_count(springs: &[Spring], damaged_groups: &[u128]) -> u128 {
+    let unknown = springs
+        .iter()
+        .enumerate()
+        .filter(|(_, &s)| s == Spring::Unknown)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    (0..1u32 << unknown.len())
+        .filter(|mask| {
+            let mut springs = springs.to_vec();
+
+            for (bit, &i) in unknown.iter().enumerate() {
+                springs[i] = if mask & (1 << bit) != 0 {
+                    Spring::Damaged
+                } else {
+                    Spring::Operational
+                };
+            }
+
+            groups(&springs) == damaged_groups
+        })
+        .count() as u128
+}
+
+fn spring() -> impl Strategy<Value = Spring> {
+    prop_oneof![
+        Just(Spring::Operational),
+        Just(Spring::Damaged),
+        Just(Spring::Unknown),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn valid_count_matches_naive(
+        springs in prop::collection::vec(spring(), 0..14),
+        damaged_groups in prop::collection::vec(1..5u128, 0..5),
+    ) {
+        prop_assert_eq!(
+            Row::valid_count(&springs, &damaged_groups),
+            naive_valid_count(&springs, &damaged_groups)
+        );
+    }
+
+    /// Unknown springs only, the case counted with combinations.
+    #[test]
+    fn valid_count_of_unknown_matches_naive(
+        unknown in 0..14usize,
+        damaged_groups in prop::collection::vec(1..4u128, 0..5),
+        rest in prop::collection::vec(spring(), 0..4),
+    ) {
+        let mut springs = vec![Spring::Unknown; unknown];
+        springs.push(Spring::Operational);
+        springs.extend(rest);
+
+        prop_assert_eq!(
+            Row::valid_count(&springs, &damaged_groups),
+            naive_valid_count(&springs, &damaged_groups)
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+
+use day_12::{Day12, Game, Row, Spring};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let game = parse_all(SAMPLE, Game::parse_1)?;
+
+    assert_eq!(game.puzzle(), 21);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let game = parse_all(SAMPLE, Game::parse_2)?;
+
+    assert_eq!(game.puzzle(), 525152);
+
+    Ok(())
+}
+
+#[test]
+fn rows() -> Result<()> {
+    let row = parse_all("???.### 1,1,3", Row::parse_1)?;
+
+    assert_eq!(
+        row.springs[..4],
+        [
+            Spring::Unknown,
+            Spring::Unknown,
+            Spring::Unknown,
+            Spring::Operational
+        ]
+    );
+    assert_eq!(row.damaged_groups, [1, 1, 3]);
+    assert_eq!(row.option_count(), 1);
+
+    let unfolded = parse_all(".??..??...?##. 1,1,3", Row::parse_2)?;
+
+    assert_eq!(unfolded.springs.len(), 5 * 14 + 4);
+    assert_eq!(unfolded.option_count(), 16384);
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day12::parse(SAMPLE)?;
+
+    assert_eq!(day.game1().rows.len(), 6);
+    assert_eq!(day.game2().rows[0].damaged_groups.len(), 15);
+    assert_eq!(day.part1()?, Answer::from(21u128));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    assert!(Day12::parse("???.### 1,0,3").is_err());
+}
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::{Day13, Pattern};
+
+fn render(rows: &[Vec<bool>]) -> String {
+    rows.iter()
+        .map(|row| row.iter().map(|&rock| if rock { '#' } else { '.' }))
+        .map(|row| row.collect::<String>() + "\n")
+        .collect()
+}
+
+fn transpose(rows: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..rows[0].len())
+        .map(|x| rows.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+/// Pattern reflected between columns `x - 1` and `x`, and with a smudge
+/// between rows `y - 1` and `y`, or `None` when these lines don't work.
+fn pattern<R: Rng>(rng: &mut R, width: usize, height: usize) -> Option<String> {
+    let x = rng.gen_range(1..width);
+    let y = rng.gen_range(1..height);
+
+    let mut rows = (0..height)
+        .map(|_| (0..width).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    for row in &mut rows {
+        for d in 0..x.min(width - x) {
+            row[x + d] = row[x - 1 - d];
+        }
+    }
+
+    // Copying whole rows keeps the columns reflected.
+    for d in 0..y.min(height - y) {
+        rows[y + d] = rows[y - 1 - d].clone();
+    }
+
+    // The smudge, in a column the vertical reflection doesn't cover.
+    let reflected = x - x.min(width - x)..x + x.min(width - x);
+    let column = (0..width).find(|c| !reflected.contains(c) && rng.gen_bool(0.5))?;
+    let row = y - 1 - rng.gen_range(0..y.min(height - y));
+    rows[row][column] = !rows[row][column];
+
+    let (rows, old, new) = match rng.gen_bool(0.5) {
+        true => (rows, x as u64, y as u64 * 100),
+        false => (transpose(&rows), x as u64 * 100, y as u64),
+    };
+
+    let text = render(&rows);
+    let (_, parsed) = Pattern::parse(&text).ok()?;
+
+    let valid = parsed.find_symmetry() == HashSet::from([old]) && parsed.value2().ok() == Some(new);
+
+    valid.then_some(text)
+}
+
+impl Generate for Day13 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("patterns", 100, "Number of patterns"),
+        Param::new("size", 17, "Maximum width and height of a pattern"),
+    ];
+
+    /// Every pattern has a single line of reflection, and a single smudge
+    /// which hides another line in the other direction.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let size = params.size("size", 5);
+
+        let patterns = (0..params.get("patterns"))
+            .map(|_| loop {
+                let (width, height) = (rng.gen_range(5..=size), rng.gen_range(5..=size));
+
+                if let Some(pattern) = pattern(rng, width, height) {
+                    break pattern;
+                }
+            })
+            .collect::<Vec<_>>();
+
+        patterns.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day13>(seed, &"patterns=20".parse().unwrap()).unwrap();
+            let day = Day13::parse(&input).unwrap();
+
+            assert_eq!(day.game().patterns.len(), 20);
+            assert!(day.part2().is_ok());
+        }
+    }
+}
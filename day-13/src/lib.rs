@@ -0,0 +1,225 @@
+//! Day 13: Point of Incidence
+//!
+//! Patterns of ash and rocks with a line of reflection, see [`Pattern`].
+
+mod generate;
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use aoc_core::{parse_all, Answer, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::newline,
+    combinator::{all_consuming, map, map_res, value},
+    multi::{many0, many1, separated_list0, separated_list1},
+    sequence::delimited,
+    IResult,
+};
+
+/// The patterns of the valley.
+#[derive(Debug)]
+pub struct Game {
+    pub patterns: Vec<Pattern>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        all_consuming(delimited(
+            many0(newline),
+            map(
+                separated_list0(many1(newline), Pattern::parse),
+                |patterns| Self { patterns },
+            ),
+            many0(newline),
+        ))(input)
+    }
+
+    /// Sum of the reflection values.
+    pub fn part1(&self) -> u64 {
+        self.patterns.iter().map(|p| p.value()).sum::<u64>()
+    }
+
+    /// Sum of the reflection values once each smudge is fixed.
+    pub fn part2(&self) -> Result<u64> {
+        Ok(self
+            .patterns
+            .iter()
+            .map(|p| p.value2())
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .sum::<u64>())
+    }
+}
+
+/// One pattern, tiles by `(x, y)` position.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pub map: HashMap<(u64, u64), Tile>,
+    pub max_x: u64,
+    pub max_y: u64,
+}
+
+impl Pattern {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map_res(
+            separated_list1(newline, many1(Tile::parse)),
+            |pattern| -> Result<Self> {
+                let mut map = HashMap::new();
+
+                for (y, line) in pattern.iter().enumerate() {
+                    let y = y as u64;
+
+                    for (x, &tile) in line.iter().enumerate() {
+                        let x = x as u64;
+
+                        map.insert((x, y), tile);
+                    }
+                }
+
+                let max_x = map
+                    .keys()
+                    .copied()
+                    .map(|(x, _)| x)
+                    .max()
+                    .ok_or(anyhow!("No keys"))?;
+
+                let max_y = map
+                    .keys()
+                    .copied()
+                    .map(|(_, y)| y)
+                    .max()
+                    .ok_or(anyhow!("No keys"))?;
+
+                Ok(Self { map, max_x, max_y })
+            },
+        )(input)
+    }
+
+    fn are_columns_eq(&self, a: u64, b: u64) -> bool {
+        (0..=self.max_y).all(|y| self.map.get(&(a, y)) == self.map.get(&(b, y)))
+    }
+
+    fn are_rows_eq(&self, a: u64, b: u64) -> bool {
+        (0..=self.max_x).all(|x| self.map.get(&(x, a)) == self.map.get(&(x, b)))
+    }
+
+    /// Whether the pattern reflects between columns `x` and `x + 1`.
+    pub fn is_vertical_symmetry(&self, x: u64) -> bool {
+        (0..=(x.min(self.max_x - (x + 1)))).all(|diff| self.are_columns_eq(x - diff, x + 1 + diff))
+    }
+
+    pub fn find_vertical_symmetry(&self) -> HashSet<u64> {
+        (0..self.max_x)
+            .filter_map(|x| {
+                if self.is_vertical_symmetry(x) {
+                    Some(x + 1)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the pattern reflects between rows `y` and `y + 1`.
+    pub fn is_horizontal_symmetry(&self, y: u64) -> bool {
+        (0..=(y.min(self.max_y - (y + 1)))).all(|diff| self.are_rows_eq(y - diff, y + 1 + diff))
+    }
+
+    pub fn find_horizontal_symmetry(&self) -> HashSet<u64> {
+        (0..self.max_y)
+            .filter_map(|y| {
+                if self.is_horizontal_symmetry(y) {
+                    Some((y + 1) * 100)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Values of every line of reflection: the columns to their left, or a
+    /// hundred times the rows above them.
+    pub fn find_symmetry(&self) -> HashSet<u64> {
+        self.find_vertical_symmetry()
+            .union(&self.find_horizontal_symmetry())
+            .copied()
+            .collect()
+    }
+
+    pub fn value(&self) -> u64 {
+        self.find_symmetry().iter().sum()
+    }
+
+    /// Value of the new line of reflection once the smudge is fixed.
+    pub fn value2(&self) -> Result<u64> {
+        let mut clone = self.clone();
+        let original_value = self.find_symmetry();
+
+        for (&key, &value) in self.map.iter() {
+            let new_value = value.inverse();
+
+            clone.map.insert(key, new_value);
+            let result = clone.find_symmetry();
+
+            let result: HashSet<_> = result.difference(&original_value).copied().collect();
+
+            if result.len() == 1 {
+                return Ok(result.iter().sum::<u64>());
+            }
+
+            clone.map.insert(key, value);
+        }
+
+        Err(anyhow!("No value"))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Tile {
+    Ash,
+    Rock,
+}
+
+impl Tile {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        alt((value(Self::Ash, tag(".")), value(Self::Rock, tag("#"))))(input)
+    }
+
+    pub fn inverse(&self) -> Self {
+        match self {
+            Self::Ash => Self::Rock,
+            Self::Rock => Self::Ash,
+        }
+    }
+}
+
+pub struct Day13 {
+    game: Game,
+}
+
+impl Day13 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day13 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 13;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game = parse_all(input, Game::parse)?;
+
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.part1().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
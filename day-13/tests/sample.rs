@@ -0,0 +1,53 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+
+use day_13::{Day13, Game, Tile};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let game = parse_all(SAMPLE, Game::parse)?;
+
+    assert_eq!(game.part1(), 405);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let game = parse_all(SAMPLE, Game::parse)?;
+
+    assert_eq!(game.part2()?, 400);
+
+    Ok(())
+}
+
+#[test]
+fn patterns() -> Result<()> {
+    let game = parse_all(SAMPLE, Game::parse)?;
+    let [first, second] = &game.patterns[..] else {
+        panic!("Expected 2 patterns");
+    };
+
+    assert_eq!((first.max_x, first.max_y), (8, 6));
+    assert_eq!(first.map[&(0, 0)], Tile::Rock);
+    assert!(first.is_vertical_symmetry(4));
+    assert_eq!(first.find_symmetry(), HashSet::from([5]));
+    assert_eq!(second.find_symmetry(), HashSet::from([400]));
+    assert_eq!(first.value2()?, 300);
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day13::parse(SAMPLE)?;
+
+    assert_eq!(day.game().patterns.len(), 2);
+    assert_eq!(day.part1()?, Answer::from(405u64));
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day14;
+
+impl Generate for Day14 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", 100, "Number of columns"),
+        Param::new("height", 100, "Number of rows"),
+        Param::new("rounded", 20, "Percentage of rounded rocks"),
+        Param::new("cubes", 10, "Percentage of cube-shaped rocks"),
+    ];
+
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let (rounded, cubes) = (params.get("rounded"), params.get("cubes"));
+        let mut input = String::new();
+
+        for _ in 0..params.size("height", 1) {
+            for _ in 0..params.size("width", 1) {
+                let roll = rng.gen_range(0..100);
+
+                input.push(match roll {
+                    _ if roll < rounded => 'O',
+                    _ if roll < rounded + cubes => '#',
+                    _ => '.',
+                });
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let params = "width=20,height=15".parse().unwrap();
+            let input = generate::<Day14>(seed, &params).unwrap();
+            let day = Day14::parse(&input).unwrap();
+
+            assert_eq!(day.game().map.width(), 20);
+            assert_eq!(day.game().map.height(), 15);
+            assert!(day.part2().is_ok());
+        }
+    }
+}
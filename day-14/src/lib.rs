@@ -0,0 +1,271 @@
+//! Day 14: Parabolic Reflector Dish
+//!
+//! A platform of rocks tilted in every direction, see [`Game`].
+
+mod generate;
+mod render;
+mod simulation;
+
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+use grid::{Direction, Grid, GridError, Position};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::newline,
+    combinator::{map_res, value},
+    multi::{many1, separated_list1},
+    IResult,
+};
+
+pub use crate::simulation::Tilting;
+
+/// The platform, displayed as in the puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    pub map: Grid<Option<Rock>>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map_res(separated_list1(newline, many1(Rock::parse)), |rows| {
+            Ok::<_, GridError>(Self {
+                map: Grid::from_rows(rows)?,
+            })
+        })(input)
+    }
+
+    /// Rolls all rounded rocks as far as possible towards `direction`.
+    pub fn tilt(&mut self, direction: Direction) {
+        let Position(max_x, max_y) = self.map.max_position();
+
+        if direction == Direction::Up {
+            for x in 0..=max_x {
+                let mut free_y = 0;
+
+                for y in 0..=max_y {
+                    if let Some(rock) = self.map[Position(x, y)] {
+                        if rock == Rock::Cube {
+                            free_y = y + 1;
+                        } else {
+                            if y != free_y {
+                                self.map[Position(x, free_y)] = Some(rock);
+                                self.map[Position(x, y)] = None;
+                            }
+                            free_y += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        if direction == Direction::Down {
+            for x in 0..=max_x {
+                let mut free_y = max_y;
+
+                for y in (0..=max_y).rev() {
+                    if let Some(rock) = self.map[Position(x, y)] {
+                        if rock == Rock::Cube {
+                            free_y = y - 1;
+                        } else {
+                            if y != free_y {
+                                self.map[Position(x, free_y)] = Some(rock);
+                                self.map[Position(x, y)] = None;
+                            }
+
+                            free_y -= 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        if direction == Direction::Left {
+            for y in 0..=max_y {
+                let mut free_x = 0;
+
+                for x in 0..=max_x {
+                    if let Some(rock) = self.map[Position(x, y)] {
+                        if rock == Rock::Cube {
+                            free_x = x + 1;
+                        } else {
+                            if x != free_x {
+                                self.map[Position(free_x, y)] = Some(rock);
+                                self.map[Position(x, y)] = None;
+                            }
+                            free_x += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        if direction == Direction::Right {
+            for y in 0..=max_y {
+                let mut free_x = max_x;
+
+                for x in (0..=max_x).rev() {
+                    if let Some(rock) = self.map[Position(x, y)] {
+                        if rock == Rock::Cube {
+                            free_x = x - 1;
+                        } else {
+                            if x != free_x {
+                                self.map[Position(free_x, y)] = Some(rock);
+                                self.map[Position(x, y)] = None;
+                            }
+
+                            free_x -= 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Tilts north, west, south and east.
+    pub fn tilt_round(&mut self) {
+        for direction in [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ] {
+            self.tilt(direction);
+        }
+    }
+
+    /// Runs `rounds` spin cycles, skipping ahead once the platform repeats.
+    pub fn tilt_multiple_rounds(&mut self, rounds: usize) {
+        let mut history = Some(HashMap::new());
+
+        let mut index = 0;
+
+        while index < rounds {
+            self.tilt_round();
+            index += 1;
+
+            let history_index = if let Some(ref history) = history {
+                history
+                    .iter()
+                    .find_map(|(history_index, history_game)| {
+                        if history_game == self {
+                            Some(history_index)
+                        } else {
+                            None
+                        }
+                    })
+                    .copied()
+            } else {
+                None
+            };
+
+            if let Some(history_index) = history_index {
+                let diff = index - history_index;
+
+                let remaining_index = rounds - index;
+
+                index += (remaining_index / diff) * diff;
+
+                history = None;
+            }
+
+            if let Some(ref mut history) = history {
+                history.insert(index, self.clone());
+            }
+        }
+    }
+
+    /// Total load on the north support beams.
+    pub fn puzzle(&self) -> u64 {
+        let height = self.map.height() as u64;
+
+        self.map
+            .rows()
+            .enumerate()
+            .map(|(y, row)| {
+                let row_number = height - y as u64;
+
+                let rounded_rocks = row
+                    .iter()
+                    .filter(|&&rock| rock == Some(Rock::Rounded))
+                    .count() as u64;
+
+                row_number * rounded_rocks
+            })
+            .sum()
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = String::new();
+        for row in self.map.rows() {
+            for rock in row {
+                match rock {
+                    Some(Rock::Cube) => result.push('#'),
+                    Some(Rock::Rounded) => result.push('O'),
+                    None => result.push('.'),
+                }
+            }
+            result.push('\n');
+        }
+
+        write!(f, "{}", result)
+    }
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq)]
+pub enum Rock {
+    Rounded,
+    Cube,
+}
+
+impl Rock {
+    /// Parses a rock, or `None` for an empty space.
+    pub fn parse(input: &str) -> IResult<&str, Option<Self>> {
+        alt((
+            value(Some(Self::Rounded), tag("O")),
+            value(Some(Self::Cube), tag("#")),
+            value(None, tag(".")),
+        ))(input)
+    }
+}
+
+pub struct Day14 {
+    game: Game,
+}
+
+impl Day14 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day14 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 14;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game = parse_all(input, Game::parse)?;
+
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut game = self.game.clone();
+
+        game.tilt(Direction::Up);
+
+        Ok(game.puzzle().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut game = self.game.clone();
+
+        game.tilt_multiple_rounds(1_000_000_000);
+
+        Ok(game.puzzle().into())
+    }
+}
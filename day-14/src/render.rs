@@ -0,0 +1,43 @@
+use aoc_core::{Part, Render};
+use grid::{Direction, Grid, Pixel, Rgb};
+
+use crate::{Day14, Rock};
+
+impl Render for Day14 {
+    /// The platform once tilted north for part 1, after all the spin cycles
+    /// for part 2.
+    fn render(&self, part: Part) -> anyhow::Result<Grid<Pixel>> {
+        let mut game = self.game.clone();
+
+        match part {
+            Part::One => game.tilt(Direction::Up),
+            Part::Two => game.tilt_multiple_rounds(1_000_000_000),
+        }
+
+        Ok(game.map.map(|rock| match rock {
+            Some(Rock::Rounded) => Pixel::new('O', Rgb::ORANGE),
+            Some(Rock::Cube) => Pixel::new('#', Rgb::GREY),
+            None => Pixel::new('.', Rgb::DARK_GREY),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let day = Day14::parse(include_str!("sample-input.txt")).unwrap();
+
+        let mut game = day.game().clone();
+        game.tilt(Direction::Up);
+
+        let grid = day.render(Part::One).unwrap();
+
+        assert_eq!(grid.to_string(), game.to_string());
+        assert_eq!(grid.rows().next().unwrap()[0], Pixel::new('O', Rgb::ORANGE));
+    }
+}
@@ -0,0 +1,107 @@
+use aoc_core::{Part, Simulate, Simulation};
+use grid::Direction;
+
+use crate::{Day14, Game};
+
+/// The platform tilted north once for part 1, or spun cycle after cycle for
+/// part 2.
+#[derive(Debug, Clone)]
+pub struct Tilting {
+    pub game: Game,
+    /// Tilts of one step.
+    pub directions: Vec<Direction>,
+    pub steps: usize,
+    pub total_steps: usize,
+}
+
+impl Tilting {
+    pub fn new(game: Game, part: Part) -> Self {
+        let (directions, total_steps) = match part {
+            Part::One => (vec![Direction::Up], 1),
+            Part::Two => (
+                vec![
+                    Direction::Up,
+                    Direction::Left,
+                    Direction::Down,
+                    Direction::Right,
+                ],
+                1_000_000_000,
+            ),
+        };
+
+        Self {
+            game,
+            directions,
+            steps: 0,
+            total_steps,
+        }
+    }
+}
+
+impl Simulation for Tilting {
+    fn step(&mut self) -> anyhow::Result<()> {
+        if !self.is_done() {
+            for &direction in &self.directions {
+                self.game.tilt(direction);
+            }
+
+            self.steps += 1;
+        }
+
+        Ok(())
+    }
+
+    fn state(&self) -> String {
+        format!(
+            "Step {} of {}, load {}\n{}",
+            self.steps,
+            self.total_steps,
+            self.game.puzzle(),
+            self.game
+        )
+    }
+
+    fn is_done(&self) -> bool {
+        self.steps >= self.total_steps
+    }
+}
+
+impl Simulate for Day14 {
+    fn simulation(&self, part: Part) -> anyhow::Result<Box<dyn Simulation>> {
+        Ok(Box::new(Tilting::new(self.game.clone(), part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_simulation() {
+        let day = Day14::parse(include_str!("sample-input.txt")).unwrap();
+
+        let mut simulation = day.simulation(Part::One).unwrap();
+        simulation.step().unwrap();
+
+        assert!(simulation.is_done());
+        assert!(simulation
+            .state()
+            .starts_with("Step 1 of 1, load 136\nOOOO.#.O..\n"));
+
+        let mut game = day.game().clone();
+        let mut simulation = day.simulation(Part::Two).unwrap();
+
+        for _ in 0..3 {
+            game.tilt_round();
+            simulation.step().unwrap();
+        }
+
+        assert!(!simulation.is_done());
+        assert_eq!(
+            simulation.state(),
+            format!("Step 3 of 1000000000, load {}\n{game}", game.puzzle())
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+use grid::Direction;
+
+use day_14::{Day14, Game};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let mut game = parse_all(SAMPLE, Game::parse)?;
+
+    game.tilt(Direction::Up);
+
+    assert_eq!(game.puzzle(), 136);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let mut game = parse_all(SAMPLE, Game::parse)?;
+
+    game.tilt_multiple_rounds(1_000_000_000);
+
+    assert_eq!(game.puzzle(), 64);
+
+    Ok(())
+}
+
+#[test]
+fn spin_cycle() -> Result<()> {
+    let mut game = parse_all(SAMPLE, Game::parse)?;
+
+    game.tilt_round();
+
+    assert_eq!(
+        game.to_string(),
+        ".....#....\n\
+         ....#...O#\n\
+         ...OO##...\n\
+         .OO#......\n\
+         .....OOO#.\n\
+         .O#...O#.#\n\
+         ....O#....\n\
+         ......OOOO\n\
+         #...O###..\n\
+         #..OO#....\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day14::parse(SAMPLE)?;
+
+    assert_eq!(day.game().to_string(), SAMPLE);
+    assert_eq!(day.part1()?, Answer::from(136u64));
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day15;
+
+impl Generate for Day15 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("steps", 4000, "Number of steps"),
+        Param::new("labels", 500, "Number of different lens labels"),
+        Param::new("removals", 30, "Percentage of steps removing a lens"),
+    ];
+
+    /// A single line of steps on labels of two to six lowercase letters.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let labels = (0..params.size("labels", 1))
+            .map(|_| {
+                (0..rng.gen_range(2..=6))
+                    .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        let steps = (0..params.size("steps", 1))
+            .map(|_| {
+                let label = &labels[rng.gen_range(0..labels.len())];
+
+                match rng.gen_range(0..100) < params.get("removals") {
+                    true => format!("{label}-"),
+                    false => format!("{label}={}", rng.gen_range(1..=9)),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        steps.join(",") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day15>(seed, &"steps=100".parse().unwrap()).unwrap();
+            let day = Day15::parse(&input).unwrap();
+
+            assert_eq!(input.split(',').count(), 100);
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
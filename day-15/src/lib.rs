@@ -0,0 +1,161 @@
+//! Day 15: Lens Library
+//!
+//! The HASH algorithm, see [`hash`], applied to the steps of the
+//! initialization sequence: as plain strings for part 1 ([`Game1`]), as
+//! lens operations on boxes for part 2 ([`Game2`]).
+
+mod generate;
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, none_of, u64},
+    combinator::{map, recognize, verify},
+    multi::{many0, separated_list1},
+    sequence::tuple,
+    IResult,
+};
+
+/// The initialization sequence as strings.
+pub struct Game1 {
+    pub steps: Vec<String>,
+}
+
+impl Game1 {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_list1(
+                tag(","),
+                map(recognize(many0(none_of(",\n"))), str::to_string),
+            ),
+            |steps| Self { steps },
+        )(input)
+    }
+
+    /// Sum of the hashes of the steps.
+    pub fn puzzle(&self) -> u64 {
+        self.steps.iter().map(|s| hash(s) as u64).sum::<u64>()
+    }
+}
+
+/// The initialization sequence as lens operations.
+pub struct Game2 {
+    pub operations: Vec<Operation>,
+}
+
+impl Game2 {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(separated_list1(tag(","), Operation::parse), |operations| {
+            Self { operations }
+        })(input)
+    }
+
+    /// Lenses in each box once all operations are applied, by box number.
+    pub fn boxes(&self) -> HashMap<u8, Vec<(&str, u64)>> {
+        let mut map: HashMap<u8, Vec<(&str, u64)>> = HashMap::new();
+
+        for op in &self.operations {
+            match op {
+                Operation::Add(name, focus) => {
+                    let vec = map.entry(hash(name)).or_default();
+
+                    match vec.iter_mut().find(|(n, _)| n == name) {
+                        Some((_, f)) => *f = *focus,
+                        None => vec.push((name, *focus)),
+                    }
+                }
+                Operation::Remove(name) => {
+                    map.entry(hash(name))
+                        .and_modify(|vec| vec.retain(|(n, _)| n != name));
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Total focusing power of the lenses.
+    pub fn puzzle(&self) -> u64 {
+        self.boxes()
+            .iter()
+            .map(|(h, vec)| {
+                (*h as u64 + 1)
+                    * vec
+                        .iter()
+                        .enumerate()
+                        .map(|(index, (_, focus))| (index as u64 + 1) * focus)
+                        .sum::<u64>()
+            })
+            .sum()
+    }
+}
+
+/// A lens with its focal length added to its box, or removed from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Add(String, u64),
+    Remove(String),
+}
+
+impl Operation {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let name = || map(alpha1, str::to_string);
+        // Focal lengths are a single digit.
+        let focus = verify(u64, |focus| (1..=9).contains(focus));
+
+        alt((
+            map(tuple((name(), tag("="), focus)), |(s, _, focus)| {
+                Self::Add(s, focus)
+            }),
+            map(tuple((name(), tag("-"))), |(s, _)| Self::Remove(s)),
+        ))(input)
+    }
+}
+
+/// The HASH algorithm.
+pub fn hash(input: &str) -> u8 {
+    input
+        .bytes()
+        .fold(0u8, |acc, b| acc.wrapping_add(b).wrapping_mul(17))
+}
+
+pub struct Day15 {
+    game1: Game1,
+    game2: Game2,
+}
+
+impl Day15 {
+    /// Steps as read for part 1.
+    pub fn game1(&self) -> &Game1 {
+        &self.game1
+    }
+
+    /// Steps as read for part 2.
+    pub fn game2(&self) -> &Game2 {
+        &self.game2
+    }
+}
+
+impl Solution for Day15 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 15;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game1 = parse_all(input, Game1::parse)?;
+        let game2 = parse_all(input, Game2::parse)?;
+
+        Ok(Self { game1, game2 })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game1.puzzle().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game2.puzzle().into())
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+
+use day_15::{hash, Day15, Game1, Game2, Operation};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn test_hash() {
+    assert_eq!(hash("HASH"), 52);
+}
+
+#[test]
+fn test_part1() -> Result<()> {
+    let (_, game) = Game1::parse(SAMPLE)?;
+    assert_eq!(game.puzzle(), 1320);
+
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let (_, game) = Game2::parse(SAMPLE)?;
+    assert_eq!(game.puzzle(), 145);
+
+    Ok(())
+}
+
+#[test]
+fn test_boxes() -> Result<()> {
+    let (_, game) = Game2::parse(SAMPLE)?;
+    let boxes = game.boxes();
+
+    assert_eq!(game.operations[1], Operation::Remove("cm".to_string()));
+    assert_eq!(boxes[&0], [("rn", 1), ("cm", 2)]);
+    assert_eq!(boxes[&3], [("ot", 7), ("ab", 5), ("pc", 6)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_solution() -> Result<()> {
+    let day = Day15::parse(SAMPLE)?;
+
+    assert_eq!(day.game1().steps.len(), 11);
+    assert_eq!(day.game2().operations.len(), 11);
+    assert_eq!(day.part2()?, Answer::from(145u64));
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid() {
+    assert!(Day15::parse("rn=10").is_err());
+    assert!(Day15::parse("rn=0").is_err());
+}
@@ -0,0 +1,51 @@
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day16;
+
+const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+impl Generate for Day16 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", 110, "Number of columns"),
+        Param::new("height", 110, "Number of rows"),
+        Param::new("devices", 10, "Percentage of mirrors and splitters"),
+    ];
+
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let devices = params.get("devices");
+        let mut input = String::new();
+
+        for _ in 0..params.size("height", 1) {
+            for _ in 0..params.size("width", 1) {
+                match rng.gen_range(0..100) < devices {
+                    true => input.push(*DEVICES.choose(rng).unwrap()),
+                    false => input.push('.'),
+                }
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let params = "width=20,height=15".parse().unwrap();
+            let input = generate::<Day16>(seed, &params).unwrap();
+            let day = Day16::parse(&input).unwrap();
+
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Day 16: The Floor Will Be Lava
+//!
+//! A beam of light bouncing through mirrors and splitters, see [`Game`].
+
+mod generate;
+mod render;
+
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{parse_all, Answer, ParseError, Solution};
+use grid::{Direction, Grid, GridError, Position};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::newline,
+    combinator::{map_res, value},
+    multi::{many0, many1, separated_list1},
+    sequence::delimited,
+    IResult,
+};
+
+#[derive(Debug)]
+pub enum GameError {
+    Parse(ParseError),
+    Grid(GridError),
+    NoBounds,
+}
+
+type Result<T, E = GameError> = std::result::Result<T, E>;
+
+impl std::error::Error for GameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            Self::Grid(err) => Some(err),
+            Self::NoBounds => None,
+        }
+    }
+}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Self::Parse(_) => "cannot parse game input",
+            Self::Grid(_) => "cannot build game map",
+            Self::NoBounds => "game has no bounds",
+        };
+
+        description.fmt(f)
+    }
+}
+
+impl From<ParseError> for GameError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<GridError> for GameError {
+    fn from(err: GridError) -> Self {
+        Self::Grid(err)
+    }
+}
+
+/// The contraption, parsed with [`str::parse`].
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub map: Grid<Option<Tile>>,
+}
+
+/// Nom parser of a `T`.
+pub trait Parser<T> {
+    fn parse(input: &str) -> IResult<&str, T>;
+}
+
+impl std::str::FromStr for Game {
+    type Err = GameError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let game = parse_all(
+            input,
+            delimited(many0(newline), Self::parse, many0(newline)),
+        )?;
+
+        Ok(game)
+    }
+}
+
+impl Parser<Game> for Game {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map_res(separated_list1(newline, many1(Tile::parse)), |rows| {
+            Ok::<_, GameError>(Self {
+                map: Grid::from_rows(rows)?,
+            })
+        })(input)
+    }
+}
+
+impl Game {
+    /// Tiles energized by a beam entering the top-left corner heading right.
+    pub fn part1(&self) -> u64 {
+        self.calculate_energy(Position(0, 0), Direction::Right)
+    }
+
+    /// Most tiles energized by a beam entering from any edge.
+    pub fn part2(&self) -> Result<u64> {
+        self.edge_starts()
+            .map(|(pos, dir)| self.calculate_energy(pos, dir))
+            .max()
+            .ok_or(GameError::NoBounds)
+    }
+
+    /// Where the beam of [`Game::part2`] enters, and its direction.
+    pub fn best_start(&self) -> Result<(Position, Direction)> {
+        self.edge_starts()
+            .max_by_key(|&(pos, dir)| self.calculate_energy(pos, dir))
+            .ok_or(GameError::NoBounds)
+    }
+
+    /// Every tile on the edge with the direction pointing inside.
+    fn edge_starts(&self) -> impl Iterator<Item = (Position, Direction)> {
+        let (min_x, min_y) = (0, 0);
+        let Position(max_x, max_y) = self.map.max_position();
+
+        let horizontal = [(min_x, Direction::Right), (max_x, Direction::Left)]
+            .into_iter()
+            .flat_map(move |(start_x, dir)| {
+                (min_y..=max_y).map(move |y| (Position(start_x, y), dir))
+            });
+
+        let vertical = [(min_y, Direction::Down), (max_y, Direction::Up)]
+            .into_iter()
+            .flat_map(move |(start_y, dir)| {
+                (min_x..=max_x).map(move |x| (Position(x, start_y), dir))
+            });
+
+        horizontal.chain(vertical)
+    }
+
+    /// Number of tiles the beam goes through.
+    pub fn calculate_energy(&self, start_pos: Position, start_dir: Direction) -> u64 {
+        self.energized(start_pos, start_dir).len() as u64
+    }
+
+    /// Tiles the beam goes through.
+    pub fn energized(&self, start_pos: Position, start_dir: Direction) -> HashSet<Position> {
+        let mut beams = VecDeque::new();
+        let mut visited = HashSet::new();
+        let mut energized = HashSet::new();
+
+        beams.push_back((start_pos, start_dir));
+
+        while let Some((pos, dir)) = beams.pop_front() {
+            let Some(&tile) = self.map.get(pos) else {
+                continue;
+            };
+
+            if !visited.insert((pos, dir)) {
+                continue;
+            }
+
+            energized.insert(pos);
+
+            let next_dirs = if let Some(next_tile) = tile {
+                Beam::encounter(dir, next_tile)
+            } else {
+                vec![dir]
+            };
+
+            for d in next_dirs {
+                beams.push_back((pos.move_dir(d), d));
+            }
+        }
+
+        energized
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    MirrorUL,  // upper-left to downer-right
+    MirrorUR,  // upper-right to downer-left
+    SplitterU, // vertical
+    SplitterL, // horizontal
+}
+
+impl Parser<Option<Tile>> for Tile {
+    fn parse(input: &str) -> IResult<&str, Option<Self>> {
+        alt((
+            value(None, tag(".")),
+            value(Some(Self::MirrorUL), tag("\\")),
+            value(Some(Self::MirrorUR), tag("/")),
+            value(Some(Self::SplitterU), tag("|")),
+            value(Some(Self::SplitterL), tag("-")),
+        ))(input)
+    }
+}
+
+/// How a beam of light moves.
+pub struct Beam;
+
+impl Beam {
+    /// Directions of the beam after it meets `tile` heading `dir`.
+    pub fn encounter(dir: Direction, tile: Tile) -> Vec<Direction> {
+        match (dir, tile) {
+            (Direction::Right, Tile::MirrorUL) => vec![Direction::Down],
+            (Direction::Right, Tile::MirrorUR) => vec![Direction::Up],
+            (Direction::Right, Tile::SplitterU) => vec![Direction::Up, Direction::Down],
+            (Direction::Right, Tile::SplitterL) => vec![Direction::Right],
+
+            (Direction::Left, _) => Self::encounter(Direction::Right, tile)
+                .iter()
+                .map(|d| d.inverse())
+                .collect(),
+
+            (Direction::Up, Tile::MirrorUL) => vec![Direction::Left],
+            (Direction::Up, Tile::MirrorUR) => vec![Direction::Right],
+            (Direction::Up, Tile::SplitterU) => vec![Direction::Up],
+            (Direction::Up, Tile::SplitterL) => vec![Direction::Left, Direction::Right],
+
+            (Direction::Down, _) => Self::encounter(Direction::Up, tile)
+                .iter()
+                .map(|d| d.inverse())
+                .collect(),
+        }
+    }
+}
+
+pub struct Day16 {
+    game: Game,
+}
+
+impl Day16 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day16 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 16;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            game: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        Ok(self.game.part1().into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
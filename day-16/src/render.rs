@@ -0,0 +1,77 @@
+use aoc_core::{Part, Render};
+use grid::{Direction, Grid, Pixel, Position, Rgb};
+
+use crate::{Day16, Tile};
+
+impl Render for Day16 {
+    /// The contraption with the energized tiles in yellow, for the beam of
+    /// part 2 entering where it energizes the most.
+    fn render(&self, part: Part) -> anyhow::Result<Grid<Pixel>> {
+        let (start_pos, start_dir) = match part {
+            Part::One => (Position(0, 0), Direction::Right),
+            Part::Two => self.game.best_start()?,
+        };
+
+        let energized = self.game.energized(start_pos, start_dir);
+
+        Ok(self.game.map.map_with_position(|pos, tile| {
+            let is_energized = energized.contains(&pos);
+
+            let glyph = match tile {
+                None if is_energized => '#',
+                None => '.',
+                Some(Tile::MirrorUL) => '\\',
+                Some(Tile::MirrorUR) => '/',
+                Some(Tile::SplitterU) => '|',
+                Some(Tile::SplitterL) => '-',
+            };
+
+            match is_energized {
+                true => Pixel::new(glyph, Rgb::YELLOW),
+                false => Pixel::new(glyph, Rgb::DARK_GREY),
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let day = Day16::parse(include_str!("sample-input.txt")).unwrap();
+
+        for (part, energy) in [(Part::One, 46), (Part::Two, 51)] {
+            let grid = day.render(part).unwrap();
+
+            assert_eq!((grid.width(), grid.height()), (10, 10));
+            assert_eq!(
+                grid.iter().filter(|(_, p)| p.colour == Rgb::YELLOW).count(),
+                energy
+            );
+        }
+
+        // As drawn in the puzzle.
+        let energized = day.render(Part::One).unwrap().map(|p| match p.colour {
+            Rgb::YELLOW => '#',
+            _ => '.',
+        });
+
+        assert_eq!(
+            energized.to_string(),
+            "######....\n\
+             .#...#....\n\
+             .#...#####\n\
+             .#...##...\n\
+             .#...##...\n\
+             .#...##...\n\
+             .#..####..\n\
+             ########..\n\
+             .#######..\n\
+             .#...#.#..\n"
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_core::{Answer, Solution};
+use grid::{Direction, Position};
+
+use day_16::{Beam, Day16, Game, GameError, Tile};
+
+type Result<T, E = GameError> = std::result::Result<T, E>;
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let game = SAMPLE.parse::<Game>()?;
+
+    assert_eq!(game.part1(), 46);
+
+    Ok(())
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let game = SAMPLE.parse::<Game>()?;
+
+    assert_eq!(game.part2()?, 51);
+    assert_eq!(game.calculate_energy(Position(3, 0), Direction::Down), 51);
+
+    Ok(())
+}
+
+#[test]
+fn beam() -> Result<()> {
+    let game = SAMPLE.parse::<Game>()?;
+
+    assert_eq!(game.map[Position(1, 0)], Some(Tile::SplitterU));
+    assert_eq!(
+        Beam::encounter(Direction::Left, Tile::MirrorUR),
+        [Direction::Down]
+    );
+    assert_eq!(
+        Beam::encounter(Direction::Down, Tile::SplitterL),
+        [Direction::Right, Direction::Left]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_error() {
+    assert!(matches!("..x.".parse::<Game>(), Err(GameError::Parse(_))));
+}
+
+#[test]
+fn solution() -> anyhow::Result<()> {
+    let day = Day16::parse(SAMPLE)?;
+
+    assert_eq!(day.game().map.width(), 10);
+    assert_eq!(day.part1()?, Answer::from(46u64));
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day17;
+
+impl Generate for Day17 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("width", 141, "Number of columns, at least 5"),
+        Param::new("height", 141, "Number of rows, at least 5"),
+        Param::new("max", 9, "Highest heat loss of a block, at most 9"),
+    ];
+
+    /// Heat losses grow towards the centre, like in the real map.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let (width, height) = (params.size("width", 5), params.size("height", 5));
+        let max = params.get("max").clamp(1, 9) as f64;
+        let mut input = String::new();
+
+        for y in 0..height {
+            for x in 0..width {
+                let dx = (x as f64 / (width - 1) as f64 - 0.5).abs();
+                let dy = (y as f64 / (height - 1) as f64 - 0.5).abs();
+                let centre = 1.0 - (dx + dy);
+
+                let loss = (centre * max + rng.gen_range(-2.0..2.0)).clamp(1.0, max);
+                input.push(char::from(b'0' + loss.round() as u8));
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let params = "width=15,height=12".parse().unwrap();
+            let input = generate::<Day17>(seed, &params).unwrap();
+            let day = Day17::parse(&input).unwrap();
+
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Day 17: Clumsy Crucible
+//!
+//! Moving a crucible across a city while losing the least heat, see
+//! [`Game`]. Crucibles can only move a few blocks in a straight line.
+
+mod generate;
+mod render;
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use aoc_core::{parse_all, Answer, Solution};
+use graph::{dijkstra, Path};
+use grid::{Direction, Grid, Position, Turn};
+use nom::{
+    character::complete::{newline, one_of},
+    combinator::map_res,
+    multi::{many0, many1, separated_list1},
+    sequence::delimited,
+    IResult,
+};
+
+/// Heat lost by entering each block, parsed with [`str::parse`].
+#[derive(Debug)]
+pub struct Game {
+    pub map: Grid<u64>,
+}
+
+impl FromStr for Game {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let game = parse_all(
+            input,
+            delimited(many0(newline), Game::parse, many0(newline)),
+        )?;
+
+        Ok(game)
+    }
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map_res(
+            separated_list1(
+                newline,
+                many1(map_res(one_of("0123456789"), |c| {
+                    c.to_digit(10)
+                        .map(u64::from)
+                        .ok_or(anyhow!("Invalid digit character"))
+                })),
+            ),
+            |rows| -> Result<Self> {
+                Ok(Self {
+                    map: Grid::from_rows(rows)?,
+                })
+            },
+        )(input)
+    }
+
+    /// Least heat lost from the top-left to the bottom-right block, by a
+    /// crucible moving between `min_steps` and `max_steps` blocks before
+    /// turning.
+    pub fn puzzle(&self, min_steps: u64, max_steps: u64) -> Result<u64> {
+        Ok(self.path(min_steps, max_steps)?.cost)
+    }
+
+    /// Path losing the least heat, see [`Game::puzzle`].
+    pub fn path(&self, min_steps: u64, max_steps: u64) -> Result<Path<Crucible, u64>> {
+        let start_pos = Position(0, 0);
+
+        let end_pos = self.map.max_position();
+
+        let starts = [Direction::Right, Direction::Down].map(|dir| Crucible {
+            pos: start_pos,
+            dir,
+            steps: 0,
+        });
+
+        dijkstra(
+            starts,
+            |&crucible| crucible.moves(min_steps, max_steps, &self.map),
+            |crucible| crucible.pos == end_pos && crucible.steps >= min_steps,
+        )
+        .ok_or(anyhow!("End unreachable"))
+    }
+
+    pub fn part1(&self) -> Result<u64> {
+        self.puzzle(0, 3)
+    }
+
+    pub fn part2(&self) -> Result<u64> {
+        self.puzzle(4, 10)
+    }
+}
+
+/// Search state: where the crucible is, where it is heading and how many
+/// blocks it moved straight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crucible {
+    pub pos: Position,
+    pub dir: Direction,
+    pub steps: u64,
+}
+
+impl Crucible {
+    /// Next states with the heat lost by entering their block.
+    pub fn moves(self, min_steps: u64, max_steps: u64, map: &Grid<u64>) -> Vec<(Self, u64)> {
+        let mut dirs = Vec::new();
+
+        if self.steps < max_steps {
+            dirs.push((self.dir, self.steps + 1));
+        }
+
+        if self.steps >= min_steps {
+            for turn in [Turn::Clockwise, Turn::CounterClockwise] {
+                dirs.push((self.dir.turn(turn), 1));
+            }
+        }
+
+        dirs.into_iter()
+            .filter_map(|(dir, steps)| {
+                let pos = self.pos.move_dir(dir);
+
+                map.get(pos).map(|&heat| (Self { pos, dir, steps }, heat))
+            })
+            .collect()
+    }
+}
+
+pub struct Day17 {
+    game: Game,
+}
+
+impl Day17 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day17 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 17;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            game: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.part1()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
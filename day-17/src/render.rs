@@ -0,0 +1,58 @@
+use aoc_core::{Part, Render};
+use grid::{Grid, Pixel, Rgb};
+
+use crate::Day17;
+
+impl Render for Day17 {
+    /// The heat loss of every block, from dark to light, and the path of the
+    /// crucible in red.
+    fn render(&self, part: Part) -> anyhow::Result<Grid<Pixel>> {
+        let map = &self.game.map;
+
+        let path = match part {
+            Part::One => self.game.path(0, 3)?,
+            Part::Two => self.game.path(4, 10)?,
+        };
+
+        let mut grid = map.map(|&heat| {
+            let glyph = char::from_digit(heat as u32, 10).unwrap_or('?');
+
+            Pixel::new(glyph, Rgb::DARK_GREY.mix(Rgb::WHITE, heat as f64 / 9.0))
+        });
+
+        for crucible in path.nodes.iter().skip(1) {
+            grid[crucible.pos] = Pixel::new(crucible.dir.arrow(), Rgb::RED);
+        }
+
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let day = Day17::parse(include_str!("sample-input.txt")).unwrap();
+
+        let grid = day.render(Part::One).unwrap();
+
+        // The puzzle's path, which has 28 steps.
+        assert_eq!(
+            grid.iter().filter(|(_, p)| p.colour == Rgb::RED).count(),
+            28
+        );
+        assert_eq!(
+            grid.rows()
+                .next()
+                .unwrap()
+                .iter()
+                .map(|p| p.glyph)
+                .collect::<String>(),
+            "2>>34^>>>1323"
+        );
+    }
+}
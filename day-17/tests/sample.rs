@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+use grid::Position;
+
+use day_17::{Day17, Game};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() -> Result<()> {
+    let game = Game::from_str(SAMPLE)?;
+
+    assert_eq!(game.part1()?, 102);
+
+    Ok(())
+}
+
+#[test]
+fn part2_1() -> Result<()> {
+    let game = Game::from_str(SAMPLE)?;
+
+    assert_eq!(game.part2()?, 94);
+
+    Ok(())
+}
+
+#[test]
+fn part2_2() -> Result<()> {
+    let game = Game::from_str(include_str!("../src/sample-input-2.txt"))?;
+
+    assert_eq!(game.part2()?, 71);
+
+    Ok(())
+}
+
+#[test]
+fn path() -> Result<()> {
+    let game = Game::from_str(SAMPLE)?;
+    let path = game.path(0, 3)?;
+
+    let heat: u64 = path.nodes[1..].iter().map(|c| game.map[c.pos]).sum();
+
+    assert_eq!(path.nodes[0].pos, Position(0, 0));
+    assert_eq!(path.nodes.last().unwrap().pos, game.map.max_position());
+    assert_eq!(heat, path.cost);
+    assert!(path.nodes.iter().all(|c| c.steps <= 3));
+
+    Ok(())
+}
+
+#[test]
+fn solution() -> Result<()> {
+    let day = Day17::parse(SAMPLE)?;
+
+    assert_eq!(day.game().map[Position(0, 0)], 2);
+    assert_eq!(day.part1()?, Answer::from(102u64));
+
+    Ok(())
+}
@@ -0,0 +1,239 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use aoc_core::{Generate, Param, Params};
+use grid::{Direction, Position};
+use rand::Rng;
+
+use crate::Day18;
+
+/// Cells of a random tree of `nodes` nodes, drawn three times larger with
+/// one cell wide corridors so that its outline is a simple polygon.
+fn tree<R: Rng>(rng: &mut R, nodes: usize) -> HashSet<Position> {
+    let mut tree = HashSet::from([Position(0, 0)]);
+    let mut cells = HashSet::from([Position(1, 1)]);
+    let mut frontier = Position(0, 0)
+        .neighbours4()
+        .map(|n| (Position(0, 0), n))
+        .collect::<Vec<_>>();
+
+    while tree.len() < nodes {
+        let (from, to) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+
+        if !tree.insert(to) {
+            continue;
+        }
+
+        let (a, b) = (
+            Position(from.0 * 3 + 1, from.1 * 3 + 1),
+            Position(to.0 * 3 + 1, to.1 * 3 + 1),
+        );
+        let (dx, dy) = ((b.0 - a.0) / 3, (b.1 - a.1) / 3);
+
+        cells.extend((1..=3).map(|i| Position(a.0 + dx * i, a.1 + dy * i)));
+        frontier.extend(to.neighbours4().map(|n| (to, n)));
+    }
+
+    cells
+}
+
+/// Corners of the outline of `cells`, clockwise.
+fn outline(cells: &HashSet<Position>) -> Vec<Position> {
+    let mut next = HashMap::new();
+
+    // Each side without a neighbour, clockwise around its cell.
+    for &Position(x, y) in cells {
+        let sides = [
+            (Direction::Up, (x, y), (x + 1, y)),
+            (Direction::Right, (x + 1, y), (x + 1, y + 1)),
+            (Direction::Down, (x + 1, y + 1), (x, y + 1)),
+            (Direction::Left, (x, y + 1), (x, y)),
+        ];
+
+        for (dir, from, to) in sides {
+            if !cells.contains(&Position(x, y).move_dir(dir)) {
+                next.insert(Position(from.0, from.1), Position(to.0, to.1));
+            }
+        }
+    }
+
+    let start = *next.keys().min().unwrap();
+    let mut corners = vec![start];
+    let mut pos = next[&start];
+
+    while pos != start {
+        corners.push(pos);
+        pos = next[&pos];
+    }
+
+    // Only keep the corners where the direction changes.
+    let count = corners.len();
+
+    (0..count)
+        .filter(|&i| {
+            let (a, b, c) = (
+                corners[(i + count - 1) % count],
+                corners[i],
+                corners[(i + 1) % count],
+            );
+            (b.0 - a.0, b.1 - a.1) != (c.0 - b.0, c.1 - b.1)
+        })
+        .map(|i| corners[i])
+        .collect()
+}
+
+/// Moves every distinct coordinate apart by a random distance of 2 to
+/// `max`, which keeps the polygon simple and its trench from touching
+/// itself.
+fn stretch<R: Rng>(rng: &mut R, corners: &[Position], max: u64) -> Vec<(Direction, u64)> {
+    let mut scale = |values: BTreeSet<i64>| {
+        let mut coordinate = 0;
+
+        values
+            .into_iter()
+            .map(|v| {
+                coordinate += rng.gen_range(2..=max.max(2)) as i64;
+                (v, coordinate)
+            })
+            .collect::<HashMap<_, _>>()
+    };
+
+    let xs = scale(corners.iter().map(|p| p.0).collect());
+    let ys = scale(corners.iter().map(|p| p.1).collect());
+
+    let corners = corners
+        .iter()
+        .map(|p| Position(xs[&p.0], ys[&p.1]))
+        .collect::<Vec<_>>();
+
+    (0..corners.len())
+        .map(|i| {
+            let (a, b) = (corners[i], corners[(i + 1) % corners.len()]);
+
+            let dir = match ((b.0 - a.0).signum(), (b.1 - a.1).signum()) {
+                (1, _) => Direction::Right,
+                (-1, _) => Direction::Left,
+                (_, 1) => Direction::Down,
+                _ => Direction::Up,
+            };
+
+            (dir, a.manhattan(b))
+        })
+        .collect()
+}
+
+/// Splits the longest instructions until there are `count` of them.
+fn split(instructions: &mut Vec<(Direction, u64)>, count: usize) -> bool {
+    while instructions.len() < count {
+        let (i, &(dir, steps)) = instructions
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, (_, steps))| *steps)
+            .unwrap();
+
+        if steps < 2 {
+            return false;
+        }
+
+        instructions[i].1 = steps / 2;
+        instructions.insert(i + 1, (dir, steps - steps / 2));
+    }
+
+    true
+}
+
+impl Generate for Day18 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("nodes", 60, "Size of the random shapes"),
+        Param::new("steps", 12, "Maximum distance between corners in part 1"),
+        Param::new(
+            "steps2",
+            200_000,
+            "Maximum distance between corners in part 2",
+        ),
+    ];
+
+    /// Both parts dig the outline of a random tree, see [`tree`]. The
+    /// shorter plan has some instructions split in two, so that both have
+    /// the same length.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let nodes = params.size("nodes", 1);
+        let steps2 = params
+            .get("steps2")
+            .clamp(1, 0xfffff / (nodes as u64 * 3 + 1));
+
+        let plan = |rng: &mut R, steps| {
+            let corners = outline(&tree(rng, nodes));
+            stretch(rng, &corners, steps)
+        };
+
+        let (plan1, plan2) = loop {
+            let mut plan1 = plan(rng, params.get("steps").max(1));
+            let mut plan2 = plan(rng, steps2);
+
+            let count = plan1.len().max(plan2.len());
+
+            if split(&mut plan1, count) && split(&mut plan2, count) {
+                break (plan1, plan2);
+            }
+        };
+
+        let code = |dir| match dir {
+            Direction::Right => ('R', 0),
+            Direction::Down => ('D', 1),
+            Direction::Left => ('L', 2),
+            Direction::Up => ('U', 3),
+        };
+
+        plan1
+            .into_iter()
+            .zip(plan2)
+            .map(|((dir1, steps1), (dir2, steps2))| {
+                format!(
+                    "{} {steps1} (#{steps2:05x}{})\n",
+                    code(dir1).0,
+                    code(dir2).1
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_outline() {
+        // A single cell, then an L of three cells.
+        let square = outline(&HashSet::from([Position(0, 0)]));
+        assert_eq!(
+            square,
+            [
+                Position(0, 0),
+                Position(1, 0),
+                Position(1, 1),
+                Position(0, 1)
+            ]
+        );
+
+        let cells = HashSet::from([Position(0, 0), Position(0, 1), Position(1, 1)]);
+        assert_eq!(outline(&cells).len(), 6);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day18>(seed, &"nodes=10".parse().unwrap()).unwrap();
+            let day = Day18::parse(&input).unwrap();
+
+            assert_eq!(
+                day.game1().instructions.len(),
+                day.game2().instructions.len()
+            );
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Day 18: Lavaduct Lagoon
+//!
+//! A dig plan tracing a loop whose area is measured, see [`Game`]. Part 2
+//! reads the instructions from the colour codes, see [`parser`].
+
+mod game;
+mod generate;
+pub mod parser;
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+
+pub use crate::game::{Direction, Game, Instruction};
+
+pub struct Day18 {
+    game1: Game,
+    game2: Game,
+}
+
+impl Day18 {
+    /// Dig plan as read for part 1.
+    pub fn game1(&self) -> &Game {
+        &self.game1
+    }
+
+    /// Dig plan as read from the colour codes, for part 2.
+    pub fn game2(&self) -> &Game {
+        &self.game2
+    }
+}
+
+impl Solution for Day18 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 18;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game1 = parse_all(input, parser::v1::parse_game)?;
+        let game2 = parse_all(input, parser::v2::parse_game)?;
+
+        Ok(Self { game1, game2 })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game1.puzzle().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game2.puzzle().into())
+    }
+}
@@ -1,8 +1,11 @@
+//! Nom parsers of the dig plan, one module per way to read it.
+
+/// Instructions as written.
 pub mod v1 {
     use nom::{
         branch::alt,
         bytes::complete::tag,
-        character::complete::{alphanumeric1, newline, space1, u64},
+        character::complete::{alphanumeric1, newline, space1, u32},
         combinator::{map, value},
         multi::separated_list1,
         sequence::{separated_pair, terminated, tuple},
@@ -21,10 +24,10 @@ pub mod v1 {
 
         map(
             terminated(
-                separated_pair(parse_direction, space1, u64),
+                separated_pair(parse_direction, space1, u32),
                 tuple((space1, tag("(#"), alphanumeric1, tag(")"))),
             ),
-            |(dir, steps)| Instruction::new(dir, steps),
+            |(dir, steps)| Instruction::new(dir, steps.into()),
         )(input)
     }
 
@@ -61,6 +64,7 @@ pub mod v1 {
     }
 }
 
+/// Instructions hidden in the colour codes.
 pub mod v2 {
     use nom::{
         branch::alt,
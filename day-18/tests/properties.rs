@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+
+use aoc_core::{generate, parse_all, Params};
+use day_18::{parser, Day18, Direction, Game, Instruction};
+use grid::Position;
+
+/// Reference for [`Game::puzzle`], digging the trench meter by meter and
+/// flooding the outside within its bounding box.
+fn naive_puzzle(game: &Game) -> u128 {
+    let mut pos = Position(0, 0);
+    let mut trench = HashSet::from([pos]);
+
+    for ins in &game.instructions {
+        for _ in 0..ins.steps {
+            pos = pos.move_dir(ins.dir);
+            trench.insert(pos);
+        }
+    }
+
+    let min_x = trench.iter().map(|p| p.0).min().unwrap() - 1;
+    let max_x = trench.iter().map(|p| p.0).max().unwrap() + 1;
+    let min_y = trench.iter().map(|p| p.1).min().unwrap() - 1;
+    let max_y = trench.iter().map(|p| p.1).max().unwrap() + 1;
+
+    let start = Position(min_x, min_y);
+    let mut outside = HashSet::from([start]);
+    let mut stack = vec![start];
+
+    while let Some(pos) = stack.pop() {
+        for next in pos.neighbours4() {
+            if (min_x..=max_x).contains(&next.0)
+                && (min_y..=max_y).contains(&next.1)
+                && !trench.contains(&next)
+                && outside.insert(next)
+            {
+                stack.push(next);
+            }
+        }
+    }
+
+    ((max_x - min_x + 1) * (max_y - min_y + 1)) as u128 - outside.len() as u128
+}
+
+/// Dig plan of part 1 of a generated input.
+fn plan() -> impl Strategy<Value = Game> {
+    (any::<u64>(), 1..25u64, 1..6u64).prop_map(|(seed, nodes, steps)| {
+        let mut params = Params::default();
+        params.set("nodes", nodes);
+        params.set("steps", steps);
+
+        let input = generate::<Day18>(seed, &params).unwrap();
+
+        parse_all(&input, parser::v1::parse_game).unwrap()
+    })
+}
+
+/// The same plan dug the other way around.
+fn reversed(game: &Game) -> Game {
+    Game::new(
+        game.instructions
+            .iter()
+            .rev()
+            .map(|ins| Instruction::new(ins.dir.inverse(), ins.steps))
+            .collect(),
+    )
+}
+
+proptest! {
+    #[test]
+    fn puzzle_matches_naive(game in plan()) {
+        prop_assert_eq!(game.puzzle(), naive_puzzle(&game));
+    }
+
+    #[test]
+    fn puzzle_ignores_orientation(game in plan()) {
+        prop_assert_eq!(reversed(&game).puzzle(), game.puzzle());
+    }
+}
+
+#[test]
+fn naive_puzzle_of_square() {
+    let game = Game::new(
+        [
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+        ]
+        .map(|dir| Instruction::new(dir, 2))
+        .into(),
+    );
+
+    assert_eq!(naive_puzzle(&game), 9);
+    assert_eq!(game.puzzle(), 9);
+}
@@ -0,0 +1,47 @@
+use aoc_core::{Answer, Solution};
+
+use day_18::{parser, Day18, Direction, Instruction};
+
+const SAMPLE_INPUT: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() {
+    let (_, game) = parser::v1::parse_game(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(game.puzzle(), 62);
+}
+
+#[test]
+fn part2() {
+    let (_, game) = parser::v2::parse_game(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(game.puzzle(), 952408144115);
+}
+
+#[test]
+fn solution() {
+    let day = Day18::parse(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(
+        day.game1().instructions[0],
+        Instruction::new(Direction::Right, 6)
+    );
+    assert_eq!(day.game2().instructions[0].steps, 461937);
+    assert_eq!(day.part2().unwrap(), Answer::from(952408144115u64));
+}
+
+#[test]
+fn large_steps() {
+    assert!(Day18::parse("R 18446744073709551615 (#70c710)").is_err());
+
+    let day = Day18::parse(
+        "R 4294967295 (#70c710)\nD 4294967295 (#70c710)\n\
+         L 4294967295 (#70c710)\nU 4294967295 (#70c710)",
+    )
+    .unwrap();
+
+    assert_eq!(
+        day.part1().unwrap(),
+        Answer::from(4294967296u128 * 4294967296)
+    );
+}
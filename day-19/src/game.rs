@@ -1,16 +1,20 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Display};
 
 use anyhow::{anyhow, Result};
+use aoc_core::trace;
 
 const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
 
+/// The workflows and the part ratings to sort.
 #[derive(Debug, Clone)]
 pub struct Game {
+    /// Workflows by name.
     pub workflows: HashMap<String, Workflow>,
     pub ratings: Vec<Rating>,
 }
 
 impl Game {
+    /// Sum of the rating values of the accepted parts.
     pub fn part1(&self) -> Result<u64> {
         self.ratings
             .iter()
@@ -21,6 +25,8 @@ impl Game {
             })
     }
 
+    /// Number of distinct ratings that would be accepted, each value being
+    /// between 1 and 4000.
     pub fn part2(&self) -> Result<u64> {
         let workflow = self
             .workflows
@@ -30,18 +36,26 @@ impl Game {
         self.ops_combination_count(&workflow.ops, &[])
     }
 
-    fn eval_rating(&self, rating: &Rating) -> Result<Action> {
+    /// Final action for `rating`, starting at the `in` workflow.
+    pub fn eval_rating(&self, rating: &Rating) -> Result<Action> {
         let mut action = Action::Workflow("in".to_string());
 
         while let Action::Workflow(workflow) = action {
+            let name = workflow;
             let workflow = self
                 .workflows
-                .get(&workflow)
-                .ok_or(anyhow!("Workflow not found: {workflow}"))?;
+                .get(&name)
+                .ok_or(anyhow!("Workflow not found: {name}"))?;
 
             action = workflow
                 .eval(rating)
                 .ok_or(anyhow!("Eval did not find any result"))?;
+
+            trace::emit("workflow", |e| {
+                e.field("part", rating.to_string())
+                    .field("name", name)
+                    .field("next", action.to_string())
+            });
         }
 
         Ok(action)
@@ -78,6 +92,7 @@ impl Game {
     }
 }
 
+/// Named list of operations, tried in order.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Workflow {
     pub name: String,
@@ -85,7 +100,8 @@ pub struct Workflow {
 }
 
 impl Workflow {
-    fn eval(&self, rating: &Rating) -> Option<Action> {
+    /// Action of the first operation matching `rating`.
+    pub fn eval(&self, rating: &Rating) -> Option<Action> {
         for op in &self.ops {
             if op.eval(rating) {
                 return Some(op.action.clone());
@@ -96,6 +112,7 @@ impl Workflow {
     }
 }
 
+/// Action taken if the condition holds, or always without one.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Operation {
     pub cond: Option<Condition>,
@@ -103,11 +120,12 @@ pub struct Operation {
 }
 
 impl Operation {
-    fn eval(&self, rating: &Rating) -> bool {
-        self.cond.as_ref().map_or(true, |cond| cond.eval(rating))
+    pub fn eval(&self, rating: &Rating) -> bool {
+        self.cond.as_ref().is_none_or(|cond| cond.eval(rating))
     }
 }
 
+/// Comparison of a rating category with a value, such as `a<2006`.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Condition {
     pub category: String,
@@ -116,7 +134,7 @@ pub struct Condition {
 }
 
 impl Condition {
-    fn eval(&self, rating: &Rating) -> bool {
+    pub fn eval(&self, rating: &Rating) -> bool {
         let category_value = rating.category_value(&self.category);
 
         match self.sign {
@@ -127,7 +145,8 @@ impl Condition {
         }
     }
 
-    fn inverse(&self) -> Self {
+    /// Condition holding exactly when `self` doesn't.
+    pub fn inverse(&self) -> Self {
         Self {
             value: self.value,
             category: self.category.clone(),
@@ -140,22 +159,22 @@ impl Condition {
         }
     }
 
-    fn combination_count(conds: &[Self]) -> u64 {
+    /// Number of ratings matching all `conds`.
+    pub fn combination_count(conds: &[Self]) -> u64 {
         CATEGORIES
             .iter()
             .map(|&category| {
                 conds.iter().filter(|cond| cond.category == category).fold(
                     (1, 4000),
                     |(min, max), cond| match cond.sign {
-                        Sign::Greater => (min.max(cond.value + 1), max),
-                        Sign::Less => (min, max.min(cond.value - 1)),
+                        Sign::Greater => (min.max(cond.value.saturating_add(1)), max),
+                        Sign::Less => (min, max.min(cond.value.saturating_sub(1))),
                         Sign::GreaterEq => (min.max(cond.value), max),
                         Sign::LessEq => (min, max.min(cond.value)),
                     },
                 )
             })
-            .filter(|&(min, max)| min <= max)
-            .map(|(min, max)| max - min + 1)
+            .map(|(min, max)| (max + 1).saturating_sub(min))
             .product()
     }
 }
@@ -175,41 +194,55 @@ pub enum Action {
     Workflow(String),
 }
 
+/// `A`, `R` or the name of the workflow, as in the input.
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Accept => "A".fmt(f),
+            Self::Reject => "R".fmt(f),
+            Self::Workflow(name) => name.fmt(f),
+        }
+    }
+}
+
+/// Values of a part by category.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Rating(pub HashMap<String, u64>);
 
 impl Rating {
-    fn category_value(&self, category: &str) -> u64 {
+    /// Value of `category`, zero if the part has none.
+    pub fn category_value(&self, category: &str) -> u64 {
         self.0.get(category).copied().unwrap_or_default()
     }
 
-    fn sum_of_rating_values(&self) -> u64 {
+    pub fn sum_of_rating_values(&self) -> u64 {
         self.0.values().sum()
     }
 }
 
+/// `{x=787,m=2655,a=1222,s=2876}`, as in the input, with the usual
+/// categories first.
+impl Display for Rating {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut values = self.0.iter().collect::<Vec<_>>();
+        values.sort_by_key(|&(category, _)| {
+            let position = CATEGORIES.iter().position(|c| c == category);
+            (position.unwrap_or(CATEGORIES.len()), category)
+        });
+
+        let values = values
+            .into_iter()
+            .map(|(category, value)| format!("{category}={value}"))
+            .collect::<Vec<_>>();
+
+        write!(f, "{{{}}}", values.join(","))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::parser::parse_game;
-
     use super::*;
 
-    const SAMPLE_INPUT: &str = include_str!("sample-input.txt");
-
-    #[test]
-    fn test_part1() {
-        let game = parse_game(SAMPLE_INPUT).unwrap().1;
-
-        assert_eq!(game.part1().unwrap(), 19114);
-    }
-
-    #[test]
-    fn test_part2() {
-        let game = parse_game(SAMPLE_INPUT).unwrap().1;
-
-        assert_eq!(game.part2().unwrap(), 167409079868000);
-    }
-
     #[test]
     fn test_combination_count() {
         assert_eq!(Condition::combination_count(&[]), 256000000000000);
@@ -247,5 +280,22 @@ mod tests {
             ),
             1
         );
+
+        // Contradicting conditions, and bounds at the ends of the values.
+        let cond = |sign, value| Condition {
+            category: "m".to_string(),
+            sign,
+            value,
+        };
+
+        assert_eq!(
+            Condition::combination_count(&[cond(Sign::Less, 10), cond(Sign::Greater, 20)]),
+            0
+        );
+        assert_eq!(Condition::combination_count(&[cond(Sign::Less, 0)]), 0);
+        assert_eq!(
+            Condition::combination_count(&[cond(Sign::Greater, u64::MAX)]),
+            0
+        );
     }
 }
@@ -0,0 +1,118 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day19;
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+impl Generate for Day19 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("workflows", 500, "Number of workflows"),
+        Param::new(
+            "rules",
+            4,
+            "Maximum number of conditional rules per workflow",
+        ),
+        Param::new(
+            "depth",
+            12,
+            "Maximum number of workflows a part goes through",
+        ),
+        Param::new("ratings", 200, "Number of part ratings"),
+    ];
+
+    /// Workflows form a tree below `in`, built breadth first so that it
+    /// isn't deeper than needed.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let count = params.size("workflows", 1);
+        let rules = params.size("rules", 1);
+        let depth = params.size("depth", 1);
+
+        let mut names = HashSet::from(["in".to_string()]);
+        let mut queue = VecDeque::from([("in".to_string(), 1)]);
+        let mut workflows = Vec::new();
+
+        while let Some((name, level)) = queue.pop_front() {
+            let mut actions = Vec::new();
+
+            for _ in 0..=rng.gen_range(1..=rules) {
+                let branch = names.len() < count && level < depth && rng.gen_bool(0.6);
+
+                let action = match branch {
+                    true => loop {
+                        let name = (0..rng.gen_range(2..=3))
+                            .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+                            .collect::<String>();
+
+                        if names.insert(name.clone()) {
+                            queue.push_back((name.clone(), level + 1));
+                            break name;
+                        }
+                    },
+                    false => ["A", "R"].choose(rng).unwrap().to_string(),
+                };
+
+                actions.push(action);
+            }
+
+            let fallback = actions.pop().unwrap();
+            let mut ops = actions
+                .into_iter()
+                .map(|action| {
+                    let category = CATEGORIES.choose(rng).unwrap();
+                    let sign = ['<', '>'].choose(rng).unwrap();
+
+                    format!("{category}{sign}{}:{action}", rng.gen_range(1..=4000))
+                })
+                .collect::<Vec<_>>();
+            ops.push(fallback);
+
+            workflows.push(format!("{name}{{{}}}", ops.join(",")));
+        }
+
+        workflows.shuffle(rng);
+
+        let ratings = (0..params.size("ratings", 1)).map(|_| {
+            let values = CATEGORIES.map(|c| format!("{c}={}", rng.gen_range(1..=4000)));
+
+            format!("{{{}}}", values.join(","))
+        });
+
+        let ratings = ratings.collect::<Vec<_>>();
+
+        format!("{}\n\n{}\n", workflows.join("\n"), ratings.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let params = "workflows=50,ratings=20".parse().unwrap();
+            let input = generate::<Day19>(seed, &params).unwrap();
+            let day = Day19::parse(&input).unwrap();
+
+            assert!(day.game().workflows.len() <= 50);
+            assert_eq!(day.game().ratings.len(), 20);
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_generate_empty() {
+        let params = "workflows=0,rules=0,depth=0,ratings=0".parse().unwrap();
+        let input = generate::<Day19>(0, &params).unwrap();
+        let day = Day19::parse(&input).unwrap();
+
+        assert_eq!(day.game().ratings.len(), 1);
+        assert!(day.part1().is_ok());
+    }
+}
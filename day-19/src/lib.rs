@@ -0,0 +1,42 @@
+//! Day 19: Aplenty
+//!
+//! Machine part ratings sorted through workflows, see [`Game`].
+
+mod game;
+mod generate;
+pub mod parser;
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+
+pub use crate::game::{Action, Condition, Game, Operation, Rating, Sign, Workflow};
+use crate::parser::parse_game;
+
+pub struct Day19 {
+    game: Game,
+}
+
+impl Day19 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day19 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 19;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game = parse_all(input, parse_game)?;
+
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.part1()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
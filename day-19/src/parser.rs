@@ -1,7 +1,9 @@
+//! Nom parsers of the workflows and ratings.
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{alpha1, newline, u64},
+    character::complete::{alpha1, newline, u32, u64},
     combinator::{map, value},
     multi::{many0, many1, separated_list1},
     sequence::{delimited, separated_pair, tuple},
@@ -85,17 +87,17 @@ fn parse_condition(input: &str) -> IResult<&str, Condition> {
     )(input)
 }
 
-fn parse_rating(input: &str) -> IResult<&str, Rating> {
+pub fn parse_rating(input: &str) -> IResult<&str, Rating> {
     // Example: "{x=787,m=2655,a=1222,s=2876}"
 
     delimited(
         tag("{"),
         map(
-            separated_list1(tag(","), separated_pair(alpha1::<&str, _>, tag("="), u64)),
+            separated_list1(tag(","), separated_pair(alpha1::<&str, _>, tag("="), u32)),
             |r| {
                 Rating(
                     r.into_iter()
-                        .map(|(name, value)| (name.to_string(), value))
+                        .map(|(name, value)| (name.to_string(), value.into()))
                         .collect(),
                 )
             },
@@ -0,0 +1,73 @@
+use aoc_core::{trace, Answer, Solution};
+
+use day_19::{
+    parser::{parse_game, parse_rating, parse_workflow},
+    Action, Day19,
+};
+
+const SAMPLE_INPUT: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn test_part1() {
+    let game = parse_game(SAMPLE_INPUT).unwrap().1;
+
+    assert_eq!(game.part1().unwrap(), 19114);
+}
+
+#[test]
+fn test_part2() {
+    let game = parse_game(SAMPLE_INPUT).unwrap().1;
+
+    assert_eq!(game.part2().unwrap(), 167409079868000);
+}
+
+#[test]
+fn test_eval() {
+    let game = parse_game(SAMPLE_INPUT).unwrap().1;
+    let (_, rating) = parse_rating("{x=787,m=2655,a=1222,s=2876}").unwrap();
+    let (_, workflow) = parse_workflow("px{a<2006:qkq,m>2090:A,rfg}").unwrap();
+
+    assert_eq!(rating.category_value("m"), 2655);
+    assert_eq!(rating.sum_of_rating_values(), 7540);
+    assert_eq!(
+        workflow.eval(&rating),
+        Some(Action::Workflow("qkq".to_string()))
+    );
+    assert_eq!(game.eval_rating(&rating).unwrap(), Action::Accept);
+    assert_eq!(game.eval_rating(&game.ratings[1]).unwrap(), Action::Reject);
+}
+
+#[test]
+fn test_trace() {
+    let game = parse_game(SAMPLE_INPUT).unwrap().1;
+    let rating = &game.ratings[0];
+
+    let (action, events) = trace::record(trace::Filter::all(), || game.eval_rating(rating));
+    let steps = events
+        .iter()
+        .map(|e| (e.fields["name"].as_str(), e.fields["next"].as_str()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(action.unwrap(), Action::Accept);
+    assert_eq!(
+        steps,
+        [
+            (Some("in"), Some("qqz")),
+            (Some("qqz"), Some("qs")),
+            (Some("qs"), Some("lnx")),
+            (Some("lnx"), Some("A")),
+        ]
+    );
+    assert_eq!(
+        events[0].to_string(),
+        "workflow name=in next=qqz part={x=787,m=2655,a=1222,s=2876}"
+    );
+}
+
+#[test]
+fn test_solution() {
+    let day = Day19::parse(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(day.game().workflows.len(), 11);
+    assert_eq!(day.part1().unwrap(), Answer::from(19114u64));
+}
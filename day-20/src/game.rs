@@ -1,16 +1,51 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use anyhow::{anyhow, Result};
+use aoc_core::math::{crt, Congruence};
 
-use crate::lcm::lcm_of_slice;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    /// No module sends pulses to `rx`.
+    NoRxInput,
+    /// An input of the module feeding `rx` fires twice on the same press.
+    InvalidCycle(String),
+    NeverTogether,
+    /// A conjunction received a pulse before its inputs were known.
+    UninitializedConjunction(String),
+    /// A conjunction received a pulse from a module that is not its input.
+    UnknownInput {
+        module: String,
+        from: String,
+    },
+}
+
+type Result<T, E = GameError> = std::result::Result<T, E>;
+
+impl std::error::Error for GameError {}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoRxInput => write!(f, "no module sends pulses to rx"),
+            Self::InvalidCycle(module) => write!(f, "invalid cycle of module {module}"),
+            Self::NeverTogether => write!(f, "inputs of rx never fire together"),
+            Self::UninitializedConjunction(module) => {
+                write!(f, "conjunction {module} has no inputs set")
+            }
+            Self::UnknownInput { module, from } => {
+                write!(f, "conjunction {module} received a pulse from {from}")
+            }
+        }
+    }
+}
 
-#[derive(Debug)]
+/// The modules and their current state.
+#[derive(Debug, Clone)]
 pub struct Game {
     map: HashMap<String, Module>,
 }
 
 impl Game {
-    pub fn new(mut modules: Vec<Module>) -> Result<Self> {
+    pub fn new(mut modules: Vec<Module>) -> Self {
         let inputs = Self::find_inputs(&modules);
 
         for module in modules.iter_mut() {
@@ -24,31 +59,56 @@ impl Game {
 
         let map = modules.into_iter().map(|m| (m.name.clone(), m)).collect();
 
-        Ok(Self { map })
+        Self { map }
+    }
+
+    pub fn module(&self, name: &str) -> Option<&Module> {
+        self.map.get(name)
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = &Module> {
+        self.map.values()
+    }
+
+    /// Pushes the button once, returns the number of low and high pulses
+    /// sent, the button's included.
+    pub fn press_button(&mut self) -> Result<(u64, u64)> {
+        let mut pulse_count = (0u64, 0u64);
+
+        self.send_signal(
+            "button",
+            "broadcaster",
+            Signal::Low,
+            Some(&mut pulse_count),
+            &[],
+        )?;
+
+        Ok(pulse_count)
     }
 
+    /// Product of the low and high pulses sent by 1000 button presses.
     pub fn part1(&mut self) -> Result<u64> {
         let mut pulse_count = (0u64, 0u64);
 
         for _ in 0..1000 {
-            self.send_signal(
-                "button",
-                "broadcaster",
-                Signal::Low,
-                Some(&mut pulse_count),
-                &[],
-            )?;
+            let (low, high) = self.press_button()?;
+
+            pulse_count.0 += low;
+            pulse_count.1 += high;
         }
 
         Ok(pulse_count.0 * pulse_count.1)
     }
 
-    pub fn part2(&mut self) -> Result<u64> {
+    /// Fewest button presses sending a low pulse to `rx`.
+    ///
+    /// Each input of the module feeding `rx` fires on a cycle, which may
+    /// start with an offset. The first two presses firing it give the
+    /// cycle, and the cycles are combined with the Chinese Remainder Theorem.
+    pub fn part2(&mut self) -> Result<i128> {
         let inputs = Self::find_inputs(&self.map.values().cloned().collect::<Vec<_>>());
 
-        let modules = inputs
-            .get("rx")
-            .ok_or(anyhow!("Input not found for module: rx",))?;
+        let modules = inputs.get("rx").ok_or(GameError::NoRxInput)?;
 
         let modules_inputs = modules
             .iter()
@@ -59,29 +119,43 @@ impl Game {
             .map(String::as_str)
             .collect::<Vec<_>>(); // Collection into vec
 
-        let mut target_results: HashMap<String, u64> = HashMap::new();
+        let mut target_results: HashMap<String, Vec<i128>> = HashMap::new();
+
+        let mut i = 0;
+
+        loop {
+            i += 1;
 
-        for i in 1.. {
             let targets =
                 self.send_signal("button", "broadcaster", Signal::Low, None, &modules_inputs)?;
 
-            for t in &targets {
-                if !target_results.contains_key(t) {
-                    target_results.insert(t.clone(), i);
+            for t in targets {
+                let presses = target_results.entry(t).or_default();
+
+                if presses.len() < 2 && presses.last() != Some(&i) {
+                    presses.push(i);
                 }
             }
 
             if modules_inputs
                 .iter()
-                .all(|t| target_results.contains_key(&t.to_string()))
+                .all(|&t| target_results.get(t).is_some_and(|p| p.len() == 2))
             {
-                return Ok(lcm_of_slice(
-                    &target_results.values().copied().collect::<Vec<_>>(),
-                ));
+                let cycles = target_results
+                    .iter()
+                    .map(|(m, p)| {
+                        Congruence::new(p[0], p[1] - p[0])
+                            .ok_or_else(|| GameError::InvalidCycle(m.clone()))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let first = target_results.values().map(|p| p[0]).max().unwrap_or(1);
+
+                return crt(cycles)
+                    .and_then(|c| c.first_from(first))
+                    .ok_or(GameError::NeverTogether);
             }
         }
-
-        panic!("Unreachable");
     }
 
     fn find_inputs(modules: &[Module]) -> HashMap<String, Vec<String>> {
@@ -98,7 +172,7 @@ impl Game {
         res
     }
 
-    fn send_signal(
+    pub(crate) fn send_signal(
         &mut self,
         from: &str,
         module_name: &str,
@@ -136,10 +210,11 @@ impl Game {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Module {
-    name: String,
-    outputs: Vec<String>,
-    behavior: ModuleBehavior,
-    signal_count: (u64, u64),
+    pub name: String,
+    pub outputs: Vec<String>,
+    pub behavior: ModuleBehavior,
+    /// Low and high pulses received so far.
+    pub signal_count: (u64, u64),
 }
 
 impl Module {
@@ -152,7 +227,9 @@ impl Module {
         }
     }
 
-    fn process_signal(
+    /// Handles a pulse sent by `from`, returns the pulses to send next as
+    /// `(from, to, signal)`.
+    pub fn process_signal(
         &mut self,
         from: &str,
         signal: Signal,
@@ -182,11 +259,14 @@ impl Module {
                 let signals = conjunction
                     .incoming_signals
                     .as_mut()
-                    .ok_or(anyhow!("Conjunction not inited"))?;
+                    .ok_or_else(|| GameError::UninitializedConjunction(self.name.clone()))?;
 
                 let entry = signals
                     .get_mut(from)
-                    .ok_or(anyhow!("Incoming signal not exists: {}", from))?;
+                    .ok_or_else(|| GameError::UnknownInput {
+                        module: self.name.clone(),
+                        from: from.to_string(),
+                    })?;
 
                 *entry = signal;
 
@@ -218,9 +298,9 @@ pub enum ModuleBehavior {
     Broadcaster,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct FlipFlop {
-    state: State,
+    pub state: State,
 }
 
 impl FlipFlop {
@@ -229,9 +309,10 @@ impl FlipFlop {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Conjunction {
-    incoming_signals: Option<HashMap<String, Signal>>,
+    /// Last pulse received from each input, set once the inputs are known.
+    pub incoming_signals: Option<HashMap<String, Signal>>,
 }
 
 impl Conjunction {
@@ -251,9 +332,10 @@ impl Conjunction {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum State {
     On,
+    #[default]
     Off,
 }
 
@@ -271,22 +353,3 @@ impl State {
         }
     }
 }
-
-#[cfg(test)]
-mod test {
-    use crate::parser::parse_game;
-
-    const SAMPLE_INPUT: &str = include_str!("sample-input.txt");
-    const SAMPLE_INPUT_2: &str = include_str!("sample-input-2.txt");
-
-    #[test]
-    fn test_part1() {
-        let mut game = parse_game(SAMPLE_INPUT).unwrap().1;
-
-        assert_eq!(game.part1().unwrap(), 32000000);
-
-        let mut game2 = parse_game(SAMPLE_INPUT_2).unwrap().1;
-
-        assert_eq!(game2.part1().unwrap(), 11687500);
-    }
-}
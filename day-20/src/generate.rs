@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day20;
+
+impl Generate for Day20 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("counters", 4, "Number of counters feeding rx"),
+        Param::new("bits", 12, "Flip-flops per counter, between 2 and 20"),
+    ];
+
+    /// The broadcaster starts binary counters of flip-flops. Each has a
+    /// conjunction that fires once the count reaches a random odd period and
+    /// resets it, and these signals are combined before `rx`.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let bits = params.size("bits", 2).min(20);
+        let mut used = HashSet::from(["rx".to_string()]);
+
+        let mut name = |rng: &mut R| loop {
+            let name = (0..2)
+                .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+                .collect::<String>();
+
+            if used.insert(name.clone()) {
+                break name;
+            }
+        };
+
+        let last = name(rng);
+        let mut modules = Vec::new();
+        let mut starts = Vec::new();
+
+        for _ in 0..params.size("counters", 1) {
+            let period = rng.gen_range(1 << (bits - 1)..1 << bits) | 1;
+            let flip_flops = (0..bits).map(|_| name(rng)).collect::<Vec<_>>();
+            let (hub, inverter) = (name(rng), name(rng));
+
+            let mut hub_outputs = vec![inverter.clone()];
+
+            for (bit, flip_flop) in flip_flops.iter().enumerate() {
+                let mut outputs = flip_flops
+                    .get(bit + 1)
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if period & (1 << bit) != 0 {
+                    outputs.push(hub.clone());
+                }
+
+                if period & (1 << bit) == 0 || bit == 0 {
+                    hub_outputs.push(flip_flop.clone());
+                }
+
+                outputs.shuffle(rng);
+                modules.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+            }
+
+            hub_outputs.shuffle(rng);
+            modules.push(format!("&{hub} -> {}", hub_outputs.join(", ")));
+            modules.push(format!("&{inverter} -> {last}"));
+            starts.push(flip_flops[0].clone());
+        }
+
+        modules.push(format!("&{last} -> rx"));
+        modules.push(format!("broadcaster -> {}", starts.join(", ")));
+        modules.shuffle(rng);
+
+        modules.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let params = "counters=3,bits=6".parse().unwrap();
+            let input = generate::<Day20>(seed, &params).unwrap();
+            let day = Day20::parse(&input).unwrap();
+
+            assert!(day.part1().is_ok());
+            assert!(day.part2().is_ok());
+        }
+    }
+}
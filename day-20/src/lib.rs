@@ -0,0 +1,48 @@
+//! Day 20: Pulse Propagation
+//!
+//! Modules sending low and high pulses to each other whenever the button is
+//! pressed, see [`Game`].
+
+mod game;
+mod generate;
+pub mod parser;
+mod simulation;
+
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+
+pub use crate::game::{
+    Conjunction, FlipFlop, Game, GameError, Module, ModuleBehavior, Signal, State,
+};
+use crate::parser::parse_input;
+pub use crate::simulation::Presses;
+
+pub struct Day20 {
+    game: Game,
+}
+
+impl Day20 {
+    /// Modules before the button is pressed.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day20 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 20;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            game: parse_input(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.clone().part1()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.clone().part2()?.into())
+    }
+}
@@ -1,19 +1,7 @@
 use anyhow::Result;
 
-use crate::parser::parse_input;
-
-mod game;
-mod lcm;
-mod parser;
+use day_20::Day20;
 
 fn main() -> Result<()> {
-    let mut game = parse_input(include_str!("input.txt"))?;
-
-    println!("Part 1: {}", game.part1()?);
-
-    let mut game2 = parse_input(include_str!("input.txt"))?;
-
-    println!("Part 2: {:?}", game2.part2()?);
-
-    Ok(())
+    aoc_core::main::<Day20>()
 }
@@ -1,9 +1,12 @@
+//! Nom parsers of the module configuration.
+
 use anyhow::Result;
+use aoc_core::parse_all;
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{alpha1, newline, space0},
-    combinator::{all_consuming, map, map_res},
+    combinator::map,
     multi::{many0, separated_list0, separated_list1},
     sequence::{delimited, preceded, tuple},
     IResult,
@@ -11,15 +14,15 @@ use nom::{
 
 use crate::game::{Conjunction, FlipFlop, Game, Module, ModuleBehavior};
 
+/// Parses the whole input, surrounding blank lines included.
 pub fn parse_input(input: &str) -> Result<Game> {
-    let (_, game) = all_consuming(delimited(many0(newline), parse_game, many0(newline)))(input)
-        .map_err(|e| e.to_owned())?;
+    let game = parse_all(input, delimited(many0(newline), parse_game, many0(newline)))?;
 
     Ok(game)
 }
 
 pub fn parse_game(input: &str) -> IResult<&str, Game> {
-    map_res(separated_list1(newline, parse_module), Game::new)(input)
+    map(separated_list1(newline, parse_module), Game::new)(input)
 }
 
 fn parse_module(input: &str) -> IResult<&str, Module> {
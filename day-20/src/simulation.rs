@@ -0,0 +1,149 @@
+use aoc_core::{Part, Simulate, Simulation};
+
+use crate::{Day20, Game, ModuleBehavior, Signal, State};
+
+/// Button presses, until the 1000th for part 1 or until `rx` receives a low
+/// pulse for part 2.
+#[derive(Debug, Clone)]
+pub struct Presses {
+    pub game: Game,
+    pub part: Part,
+    pub presses: u64,
+    /// Low and high pulses sent so far.
+    pub pulses: (u64, u64),
+    /// Whether `rx` received a low pulse.
+    pub rx_low: bool,
+}
+
+impl Presses {
+    pub fn new(game: Game, part: Part) -> Self {
+        Self {
+            game,
+            part,
+            presses: 0,
+            pulses: (0, 0),
+            rx_low: false,
+        }
+    }
+}
+
+impl Simulation for Presses {
+    fn step(&mut self) -> anyhow::Result<()> {
+        if self.is_done() {
+            return Ok(());
+        }
+
+        let targets = self.game.send_signal(
+            "button",
+            "broadcaster",
+            Signal::Low,
+            Some(&mut self.pulses),
+            &["rx"],
+        )?;
+
+        self.presses += 1;
+        self.rx_low |= !targets.is_empty();
+
+        Ok(())
+    }
+
+    /// The presses and pulses so far, then every module as in the input
+    /// followed by what it remembers.
+    fn state(&self) -> String {
+        let (low, high) = self.pulses;
+
+        let mut lines = vec![format!(
+            "Presses {}, low pulses {low}, high pulses {high}",
+            self.presses
+        )];
+
+        let mut modules = self.game.modules().collect::<Vec<_>>();
+        modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for module in modules {
+            let (name, outputs) = (&module.name, module.outputs.join(", "));
+
+            lines.push(match &module.behavior {
+                ModuleBehavior::FlipFlop(flip_flop) => match flip_flop.state {
+                    State::On => format!("%{name} -> {outputs}: on"),
+                    State::Off => format!("%{name} -> {outputs}: off"),
+                },
+                ModuleBehavior::Conjunction(conjunction) => {
+                    let mut inputs = conjunction
+                        .incoming_signals
+                        .iter()
+                        .flatten()
+                        .map(|(input, signal)| match signal {
+                            Signal::Low => format!("{input}=low"),
+                            Signal::High => format!("{input}=high"),
+                        })
+                        .collect::<Vec<_>>();
+                    inputs.sort();
+
+                    format!("&{name} -> {outputs}: {}", inputs.join(", "))
+                }
+                ModuleBehavior::Broadcaster => format!("{name} -> {outputs}"),
+            });
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    fn is_done(&self) -> bool {
+        match self.part {
+            Part::One => self.presses >= 1000,
+            Part::Two => self.rx_low,
+        }
+    }
+}
+
+impl Simulate for Day20 {
+    fn simulation(&self, part: Part) -> anyhow::Result<Box<dyn Simulation>> {
+        Ok(Box::new(Presses::new(self.game.clone(), part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_simulation() {
+        let day = Day20::parse(include_str!("sample-input-2.txt")).unwrap();
+
+        let mut simulation = day.simulation(Part::One).unwrap();
+
+        assert_eq!(
+            simulation.state(),
+            "Presses 0, low pulses 0, high pulses 0\n\
+             %a -> inv, con: off\n\
+             %b -> con: off\n\
+             broadcaster -> a\n\
+             &con -> output: a=low, b=low\n\
+             &inv -> b: a=low\n"
+        );
+
+        simulation.step().unwrap();
+
+        assert_eq!(
+            simulation.state(),
+            "Presses 1, low pulses 4, high pulses 4\n\
+             %a -> inv, con: on\n\
+             %b -> con: on\n\
+             broadcaster -> a\n\
+             &con -> output: a=high, b=high\n\
+             &inv -> b: a=high\n"
+        );
+
+        for _ in 0..1000 {
+            simulation.step().unwrap();
+        }
+
+        assert!(simulation.is_done());
+        assert!(simulation
+            .state()
+            .starts_with("Presses 1000, low pulses 4250, high pulses 2750\n"));
+    }
+}
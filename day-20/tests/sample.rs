@@ -0,0 +1,75 @@
+use aoc_core::{Answer, Solution};
+
+use day_20::{
+    parser::parse_game, Conjunction, Day20, GameError, Module, ModuleBehavior, Signal, State,
+};
+
+const SAMPLE_INPUT: &str = include_str!("../src/sample-input.txt");
+const SAMPLE_INPUT_2: &str = include_str!("../src/sample-input-2.txt");
+
+#[test]
+fn test_part1() {
+    let mut game = parse_game(SAMPLE_INPUT).unwrap().1;
+
+    assert_eq!(game.part1().unwrap(), 32000000);
+
+    let mut game2 = parse_game(SAMPLE_INPUT_2).unwrap().1;
+
+    assert_eq!(game2.part1().unwrap(), 11687500);
+}
+
+#[test]
+fn test_press_button() {
+    let mut game = parse_game(SAMPLE_INPUT_2).unwrap().1;
+
+    assert_eq!(game.press_button().unwrap(), (4, 4));
+
+    let ModuleBehavior::FlipFlop(ref a) = game.module("a").unwrap().behavior else {
+        panic!("a is a flip-flop");
+    };
+    assert_eq!(a.state, State::On);
+
+    let ModuleBehavior::Conjunction(ref con) = game.module("con").unwrap().behavior else {
+        panic!("con is a conjunction");
+    };
+    let signals = con.incoming_signals.as_ref().unwrap();
+    assert_eq!(signals["a"], Signal::High);
+    assert_eq!(signals["b"], Signal::High);
+}
+
+#[test]
+fn test_errors() {
+    let mut game = parse_game(SAMPLE_INPUT).unwrap().1;
+
+    assert_eq!(game.part2(), Err(GameError::NoRxInput));
+
+    let mut con = Module::new(
+        "con".to_string(),
+        vec![],
+        ModuleBehavior::Conjunction(Conjunction::new()),
+    );
+
+    assert_eq!(
+        con.process_signal("a", Signal::Low),
+        Err(GameError::UninitializedConjunction("con".to_string()))
+    );
+
+    let game = parse_game(SAMPLE_INPUT_2).unwrap().1;
+    let mut con = game.module("con").unwrap().clone();
+
+    assert_eq!(
+        con.process_signal("x", Signal::Low),
+        Err(GameError::UnknownInput {
+            module: "con".to_string(),
+            from: "x".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_solution() {
+    let day = Day20::parse(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(day.game().modules().count(), 5);
+    assert_eq!(day.part1().unwrap(), Answer::from(32000000u64));
+}
@@ -0,0 +1,54 @@
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day21;
+
+impl Generate for Day21 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("size", 131, "Width and height of the garden, made odd"),
+        Param::new("rocks", 15, "Percentage of rocks"),
+    ];
+
+    /// Square garden with the start in its centre. Like the real input, the
+    /// row and column of the start and the border are free of rocks.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let size = params.size("size", 1) | 1;
+        let centre = size / 2;
+        let mut input = String::new();
+
+        for y in 0..size {
+            for x in 0..size {
+                let clear =
+                    x == centre || y == centre || x % (size - 1) == 0 || y % (size - 1) == 0;
+
+                input.push(match (x, y) {
+                    _ if (x, y) == (centre, centre) => 'S',
+                    _ if !clear && rng.gen_range(0..100) < params.get("rocks") => '#',
+                    _ => '.',
+                });
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = generate::<Day21>(seed, &"size=20".parse().unwrap()).unwrap();
+            let day = Day21::parse(&input).unwrap();
+
+            assert_eq!(input.lines().count(), 21);
+            assert!(day.part1().is_ok());
+        }
+    }
+}
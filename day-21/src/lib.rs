@@ -0,0 +1,143 @@
+//! Day 21: Step Counter
+//!
+//! Garden plots reachable in an exact number of steps on an infinitely
+//! repeated map, see [`Game`].
+
+mod generate;
+mod simulation;
+
+use std::collections::HashSet;
+
+use aoc_core::{Answer, Solution};
+use grid::{Grid, GridError, Position};
+
+pub use crate::simulation::Walk;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GameError {
+    Grid(GridError),
+    NoStart,
+}
+
+type Result<T, E = GameError> = std::result::Result<T, E>;
+
+impl std::error::Error for GameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Grid(err) => Some(err),
+            Self::NoStart => None,
+        }
+    }
+}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Self::Grid(_) => "cannot parse the garden",
+            Self::NoStart => "start position not found",
+        };
+
+        f.write_str(description)
+    }
+}
+
+impl From<GridError> for GameError {
+    fn from(err: GridError) -> Self {
+        Self::Grid(err)
+    }
+}
+
+/// The garden, `true` for plots and `false` for rocks.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub map: Grid<bool>,
+    pub start_pos: Position,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Result<Self> {
+        let chars = Grid::parse(input, |c| matches!(c, 'S' | '.' | '#').then_some(c))?;
+
+        let start_pos = chars.find(|&c| c == 'S').ok_or(GameError::NoStart)?;
+
+        let map = chars.map(|&c| c != '#');
+
+        Ok(Self { map, start_pos })
+    }
+
+    /// Plots reachable with one more step from any of `positions`.
+    pub fn step(&self, positions: &HashSet<Position>) -> HashSet<Position> {
+        let mut next_visited = HashSet::new();
+
+        for p in positions {
+            for next_point in p.neighbours4() {
+                if *self.map.get_wrapped(next_point) {
+                    next_visited.insert(next_point);
+                }
+            }
+        }
+
+        next_visited
+    }
+
+    /// Plots reachable in exactly `step_count` steps.
+    pub fn reachable(&self, step_count: u64) -> HashSet<Position> {
+        let mut visited = HashSet::from([self.start_pos]);
+
+        for _ in 0..step_count {
+            visited = self.step(&visited);
+        }
+
+        visited
+    }
+
+    /// Plots reachable in exactly 64 steps.
+    pub fn part1(&self) -> u64 {
+        self.reachable(64).len() as u64
+    }
+
+    /// Plots reachable after each of the first `step_count` steps, one line
+    /// per step.
+    pub fn part2(&self, step_count: u64) -> String {
+        let mut visited = HashSet::from([self.start_pos]);
+
+        let mut results = Vec::new();
+
+        for i in 1..=step_count {
+            visited = self.step(&visited);
+
+            results.push(format!("\n- {i}: {}", visited.len()));
+        }
+
+        results.join("")
+    }
+}
+
+pub struct Day21 {
+    game: Game,
+}
+
+impl Day21 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day21 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 21;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        Ok(Self {
+            game: Game::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        Ok(self.game.part1().into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        Ok(self.game.part2(10).into())
+    }
+}
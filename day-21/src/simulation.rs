@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use aoc_core::{Part, Simulate, Simulation};
+use grid::Position;
+
+use crate::{Day21, Game};
+
+/// Plots reached after each step, up to 64 steps for part 1 and 10 for
+/// part 2.
+#[derive(Debug)]
+pub struct Walk {
+    pub game: Game,
+    pub positions: HashSet<Position>,
+    pub steps: u64,
+    pub total_steps: u64,
+}
+
+impl Walk {
+    pub fn new(game: Game, part: Part) -> Self {
+        let total_steps = match part {
+            Part::One => 64,
+            Part::Two => 10,
+        };
+
+        Self {
+            positions: HashSet::from([game.start_pos]),
+            game,
+            steps: 0,
+            total_steps,
+        }
+    }
+}
+
+impl Simulation for Walk {
+    fn step(&mut self) -> anyhow::Result<()> {
+        if !self.is_done() {
+            self.positions = self.game.step(&self.positions);
+            self.steps += 1;
+        }
+
+        Ok(())
+    }
+
+    /// The plots reached as `O`, on as many copies of the garden as they
+    /// spread over.
+    fn state(&self) -> String {
+        let mut state = format!(
+            "Step {} of {}, {} plots\n",
+            self.steps,
+            self.total_steps,
+            self.positions.len()
+        );
+
+        let Position(max_x, max_y) = self.game.map.max_position();
+
+        let min_x = self.positions.iter().map(|p| p.0).min().unwrap_or(0).min(0);
+        let max_x = self
+            .positions
+            .iter()
+            .map(|p| p.0)
+            .max()
+            .unwrap_or(0)
+            .max(max_x);
+        let min_y = self.positions.iter().map(|p| p.1).min().unwrap_or(0).min(0);
+        let max_y = self
+            .positions
+            .iter()
+            .map(|p| p.1)
+            .max()
+            .unwrap_or(0)
+            .max(max_y);
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let pos = Position(x, y);
+
+                state.push(
+                    match (
+                        self.positions.contains(&pos),
+                        *self.game.map.get_wrapped(pos),
+                    ) {
+                        (true, _) => 'O',
+                        (false, true) => '.',
+                        (false, false) => '#',
+                    },
+                );
+            }
+
+            state.push('\n');
+        }
+
+        state
+    }
+
+    fn is_done(&self) -> bool {
+        self.steps >= self.total_steps
+    }
+}
+
+impl Simulate for Day21 {
+    fn simulation(&self, part: Part) -> anyhow::Result<Box<dyn Simulation>> {
+        Ok(Box::new(Walk::new(self.game.clone(), part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_simulation() {
+        let day = Day21::parse(include_str!("sample-input.txt")).unwrap();
+
+        let mut simulation = day.simulation(Part::One).unwrap();
+
+        for _ in 0..3 {
+            simulation.step().unwrap();
+        }
+
+        // As drawn in the puzzle.
+        assert_eq!(
+            simulation.state(),
+            "Step 3 of 64, 6 plots\n\
+             ...........\n\
+             .....###.#.\n\
+             .###.##..#.\n\
+             ..#.#.O.#..\n\
+             ...O#O#....\n\
+             .##.O.####.\n\
+             .##O.#...#.\n\
+             ....O..##..\n\
+             .##.#.####.\n\
+             .##..##.##.\n\
+             ...........\n"
+        );
+    }
+}
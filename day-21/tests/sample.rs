@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use aoc_core::{Answer, Solution};
+use grid::Position;
+
+use day_21::{Day21, Game, GameError};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn reachable() -> Result<()> {
+    let game = Game::parse(SAMPLE)?;
+
+    assert_eq!(game.reachable(6).len(), 16);
+    assert_eq!(game.reachable(10).len(), 50);
+
+    Ok(())
+}
+
+#[test]
+fn step() -> Result<()> {
+    let game = Game::parse(SAMPLE)?;
+    let first = game.step(&HashSet::from([game.start_pos]));
+
+    assert_eq!(game.start_pos, Position(5, 5));
+    assert_eq!(first, HashSet::from([Position(5, 4), Position(4, 5)]));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(Game::parse("...\n.#.\n").unwrap_err(), GameError::NoStart);
+    assert!(matches!(Game::parse("S.\n.x\n"), Err(GameError::Grid(_))));
+}
+
+#[test]
+fn part2() -> Result<()> {
+    let day = Day21::parse(SAMPLE)?;
+
+    assert_eq!(day.game().part2(2), "\n- 1: 2\n- 2: 4");
+    assert!(matches!(day.part2()?, Answer::Text(_)));
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day22;
+
+impl Generate for Day22 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("bricks", 1200, "Number of bricks"),
+        Param::new("floor", 10, "Width and depth of the floor"),
+        Param::new("length", 4, "Maximum number of cubes per brick"),
+    ];
+
+    /// Bricks lie along one of the axes and never overlap. They are spread
+    /// over a height that leaves about a third of the space empty.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let bricks = params.size("bricks", 1);
+        let floor = params.size("floor", 1) as i64;
+        let length = params.size("length", 1) as i64;
+
+        let height = (bricks as i64 * (length + 1) / 2 * 3 / (floor * floor * 2)).max(length) + 1;
+        let mut filled = HashSet::new();
+        let mut input = String::new();
+        let mut count = 0;
+
+        while count < bricks {
+            let axis = rng.gen_range(0..3);
+            let size = rng.gen_range(0..length);
+
+            let start = [
+                rng.gen_range(0..floor),
+                rng.gen_range(0..floor),
+                rng.gen_range(1..=height),
+            ];
+            let mut end = start;
+            end[axis] += size;
+
+            if end[0] >= floor || end[1] >= floor {
+                continue;
+            }
+
+            let cubes = (0..=size)
+                .map(|i| {
+                    let mut cube = start;
+                    cube[axis] += i;
+                    cube
+                })
+                .collect::<Vec<_>>();
+
+            if cubes.iter().any(|c| filled.contains(c)) {
+                continue;
+            }
+
+            filled.extend(cubes);
+            count += 1;
+            input.push_str(&format!(
+                "{},{},{}~{},{},{}\n",
+                start[0], start[1], start[2], end[0], end[1], end[2]
+            ));
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = generate::<Day22>(seed, &"bricks=30".parse().unwrap()).unwrap();
+            let day = Day22::parse(&input).unwrap();
+
+            assert_eq!(day.game().bricks.len(), 30);
+            assert!(day.part1().is_ok());
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Day 22: Sand Slabs
+//!
+//! Bricks of sand falling until they rest on each other, see [`Game`].
+
+mod generate;
+mod simulation;
+
+use std::collections::VecDeque;
+
+use anyhow::Result;
+use aoc_core::{parse_all, Answer, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::{complete::i64, streaming::newline},
+    combinator::{cut, map, verify},
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+
+/// The snapshot of falling bricks.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub bricks: Vec<Brick>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // Example: "1,0,1"
+        map(separated_list1(newline, Brick::parse), |bricks| Self {
+            bricks,
+        })(input)
+    }
+
+    /// Number of bricks that can be removed without any other falling.
+    pub fn part1(&mut self) -> u64 {
+        self.settle();
+
+        self.bricks
+            .iter()
+            .filter(|&removed_brick| {
+                let mut game = self.clone();
+                game.bricks.retain(|b| b != removed_brick);
+
+                game.bricks.iter().all(|brick| !game.can_brick_fall(brick))
+            })
+            .count() as u64
+    }
+
+    /// Sum over every brick of the other bricks falling once it is removed.
+    pub fn part2(&mut self) -> u64 {
+        self.settle();
+
+        self.bricks
+            .iter()
+            .map(|removed_brick| {
+                let mut game = self.clone();
+                game.bricks.retain(|b| b != removed_brick);
+
+                game.settle()
+            })
+            .sum::<u64>()
+    }
+
+    /// Lets every brick fall as far as it can, lowest first. Returns the
+    /// number of bricks that moved.
+    pub fn settle(&mut self) -> u64 {
+        let mut settling = Settling::new(std::mem::take(&mut self.bricks));
+
+        while settling.settle_next() {}
+
+        self.bricks = settling.settled;
+
+        settling.fallen
+    }
+
+    pub fn can_brick_fall(&self, brick: &Brick) -> bool {
+        let other_bricks = self
+            .bricks
+            .iter()
+            .filter(|&b| b != brick)
+            .collect::<Vec<_>>();
+
+        brick.can_fall(&other_bricks)
+    }
+}
+
+/// Bricks coming to rest one at a time, lowest first, see [`Game::settle`].
+#[derive(Debug, Clone)]
+pub struct Settling {
+    /// Bricks yet to fall, lowest first.
+    pub falling: VecDeque<Brick>,
+    pub settled: Vec<Brick>,
+    /// Number of settled bricks that moved.
+    pub fallen: u64,
+}
+
+impl Settling {
+    pub fn new(mut bricks: Vec<Brick>) -> Self {
+        bricks.sort_by_key(|b| b.start.2.min(b.end.2));
+
+        Self {
+            falling: bricks.into(),
+            settled: Vec::new(),
+            fallen: 0,
+        }
+    }
+
+    /// Lets the lowest falling brick fall onto the settled ones, returns
+    /// whether there was one.
+    pub fn settle_next(&mut self) -> bool {
+        let Some(mut brick) = self.falling.pop_front() else {
+            return false;
+        };
+
+        let other_bricks = self
+            .settled
+            .iter()
+            .filter(|&b| b != &brick)
+            .collect::<Vec<_>>();
+
+        let mut has_fallen = false;
+
+        while brick.can_fall(&other_bricks) {
+            brick.fall();
+            has_fallen = true;
+        }
+
+        if has_fallen {
+            self.fallen += 1;
+        }
+
+        self.settled.push(brick);
+
+        true
+    }
+}
+
+/// A line of cubes from `start` to `end`, both included, `end` being on
+/// no axis before `start`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Brick {
+    pub start: Point3D,
+    pub end: Point3D,
+}
+
+impl Brick {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // Example: "1,0,1~1,2,1"
+        let (input, Point3D(x0, y0, z0)) = Point3D::parse(input)?;
+        let (input, _) = tag("~")(input)?;
+
+        // The end is never before the start, on any axis. Invalid coordinates
+        // are reported where they are rather than ending the list of bricks.
+        map(
+            tuple((
+                cut(verify(i64, move |&x| x >= x0)),
+                tag(","),
+                cut(verify(i64, move |&y| y >= y0)),
+                tag(","),
+                cut(verify(i64, move |&z| z >= z0)),
+            )),
+            move |(x, _, y, _, z)| Self {
+                start: Point3D(x0, y0, z0),
+                end: Point3D(x, y, z),
+            },
+        )(input)
+    }
+
+    /// Moves the brick one level down.
+    pub fn fall(&mut self) {
+        self.start.2 -= 1;
+        self.end.2 -= 1;
+    }
+
+    fn insersets_with(&self, next_brick: &Brick) -> bool {
+        // Check if the current brick intersects with the next brick in any dimension
+        let x_overlap = self.start.0 <= next_brick.end.0 && self.end.0 >= next_brick.start.0;
+        let y_overlap = self.start.1 <= next_brick.end.1 && self.end.1 >= next_brick.start.1;
+        let z_overlap = self.end.2 == next_brick.start.2;
+
+        x_overlap && y_overlap && z_overlap
+    }
+
+    /// Whether the brick can move down without hitting the ground or any of
+    /// `other_bricks`.
+    pub fn can_fall(&self, other_bricks: &[&Brick]) -> bool {
+        if self.start.2 == 1 || self.end.2 == 1 {
+            return false;
+        }
+
+        let mut next_brick = self.clone();
+        next_brick.fall();
+
+        other_bricks.iter().all(|b| !b.insersets_with(&next_brick))
+    }
+}
+
+/// `(x, y, z)` position, `z` being the height above the ground at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Point3D(pub i64, pub i64, pub i64);
+
+impl Point3D {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // Example: "1,0,1"
+        map(
+            // Cubes are above the ground.
+            tuple((i64, tag(","), i64, tag(","), cut(verify(i64, |&z| z >= 1)))),
+            |(x, _, y, _, z)| Self(x, y, z),
+        )(input)
+    }
+}
+
+pub struct Day22 {
+    game: Game,
+}
+
+impl Day22 {
+    /// Bricks as in the snapshot, before they settle.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day22 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 22;
+
+    fn parse(input: &str) -> Result<Self> {
+        let game = parse_all(input, Game::parse)?;
+
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.game.clone().part1().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.game.clone().part2().into())
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_core::{Part, Simulate, Simulation};
+
+use crate::{Brick, Day22, Settling};
+
+impl Simulation for Settling {
+    fn step(&mut self) -> anyhow::Result<()> {
+        self.settle_next();
+
+        Ok(())
+    }
+
+    /// The bricks seen from the front, with `x` going right and `z` up:
+    /// `#` for settled bricks and `o` for falling ones.
+    fn state(&self) -> String {
+        let bricks = self.settled.iter().chain(&self.falling);
+
+        let max_x = bricks
+            .clone()
+            .map(|b| b.start.0.max(b.end.0))
+            .max()
+            .unwrap_or(0);
+        let max_z = bricks.map(|b| b.start.2.max(b.end.2)).max().unwrap_or(0);
+
+        let covers = |b: &Brick, x, z| {
+            (b.start.0.min(b.end.0)..=b.start.0.max(b.end.0)).contains(&x)
+                && (b.start.2.min(b.end.2)..=b.start.2.max(b.end.2)).contains(&z)
+        };
+
+        let mut state = format!(
+            "Settled {} of {}, {} fell\n",
+            self.settled.len(),
+            self.settled.len() + self.falling.len(),
+            self.fallen
+        );
+
+        for z in (1..=max_z).rev() {
+            for x in 0..=max_x {
+                state.push(if self.settled.iter().any(|b| covers(b, x, z)) {
+                    '#'
+                } else if self.falling.iter().any(|b| covers(b, x, z)) {
+                    'o'
+                } else {
+                    '.'
+                });
+            }
+
+            state.push('\n');
+        }
+
+        state.push_str(&"-".repeat(max_x as usize + 1));
+        state.push('\n');
+
+        state
+    }
+
+    fn is_done(&self) -> bool {
+        self.falling.is_empty()
+    }
+}
+
+impl Simulate for Day22 {
+    /// Both parts start by letting the bricks settle.
+    fn simulation(&self, _part: Part) -> anyhow::Result<Box<dyn Simulation>> {
+        Ok(Box::new(Settling::new(self.game.bricks.clone())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_simulation() {
+        let day = Day22::parse(include_str!("sample-input.txt")).unwrap();
+
+        let mut simulation = day.simulation(Part::One).unwrap();
+
+        for _ in 0..3 {
+            simulation.step().unwrap();
+        }
+
+        assert_eq!(
+            simulation.state(),
+            "Settled 3 of 7, 1 fell\n\
+             .o.\n\
+             .o.\n\
+             ...\n\
+             ooo\n\
+             ..o\n\
+             o..\n\
+             ...\n\
+             ###\n\
+             .#.\n\
+             ---\n"
+        );
+
+        while !simulation.is_done() {
+            simulation.step().unwrap();
+        }
+
+        assert!(simulation.state().starts_with("Settled 7 of 7, 5 fell\n"));
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_core::{Answer, ParseError, Solution};
+
+use day_22::{Brick, Day22, Game, Point3D};
+
+const SAMPLE_INPUT: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn test_part1() {
+    let (_, mut game) = Game::parse(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(game.part1(), 5);
+}
+
+#[test]
+fn test_part2() {
+    let (_, mut game) = Game::parse(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(game.part2(), 7);
+}
+
+#[test]
+fn test_settle() {
+    let (_, mut game) = Game::parse(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(game.settle(), 5);
+    assert_eq!(game.settle(), 0);
+    assert!(game.bricks.iter().all(|b| !game.can_brick_fall(b)));
+
+    let (_, brick) = Brick::parse("1,1,8~1,1,9").unwrap();
+
+    assert_eq!(
+        game.bricks.last(),
+        Some(&Brick {
+            start: Point3D(1, 1, 5),
+            end: Point3D(1, 1, 6),
+        })
+    );
+    assert!(brick.can_fall(&game.bricks.iter().collect::<Vec<_>>()));
+}
+
+#[test]
+fn test_invalid() {
+    // Bricks at or below the ground would fall forever.
+    let err = Day22::parse("1,0,1~1,2,1\n0,0,0~2,0,0").err().unwrap();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((err.line, err.column), (2, 5));
+
+    let err = Day22::parse("1,2,1~1,0,1").err().unwrap();
+    let err = err.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((err.line, err.column), (1, 9));
+
+    assert!(Day22::parse("1,0,3~1,0,2").is_err());
+    assert!(Day22::parse("1,0,1~1,0,-1").is_err());
+}
+
+#[test]
+fn test_solution() {
+    let day = Day22::parse(SAMPLE_INPUT).unwrap();
+
+    assert_eq!(day.game().bricks.len(), 7);
+    assert_eq!(day.part2().unwrap(), Answer::from(7u64));
+}
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Param, Params};
+use grid::{Direction, Grid, Position};
+use rand::Rng;
+
+use crate::Day23;
+
+impl Generate for Day23 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("junctions", 6, "Junctions per row and column"),
+        Param::new("corridor", 30, "Maximum length of a corridor"),
+        Param::new(
+            "edges",
+            85,
+            "Percentage of corridors kept between junctions",
+        ),
+    ];
+
+    /// Junctions on a lattice with straight corridors of random lengths.
+    /// Slopes make every corridor one way, to the right or downwards, and a
+    /// random monotone path from the start to the end is always kept.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let count = params.size("junctions", 1);
+        let corridor = params.size("corridor", 2) as i64;
+
+        let offsets = |rng: &mut R| {
+            (0..count)
+                .scan(1, |offset, _| {
+                    let current = *offset;
+                    *offset += rng.gen_range(2..=corridor) + 1;
+                    Some(current)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let (xs, ys) = (offsets(rng), offsets(rng));
+
+        // Corridors from a junction to the right or downwards.
+        let mut kept = HashSet::new();
+        let (mut i, mut j) = (0, 0);
+
+        while (i, j) != (count - 1, count - 1) {
+            let dir = match (i + 1 < count, j + 1 < count) {
+                (true, true) if rng.gen_bool(0.5) => Direction::Right,
+                (true, _) => Direction::Right,
+                _ => Direction::Down,
+            };
+
+            kept.insert((i, j, dir));
+            (i, j) = if dir == Direction::Right {
+                (i + 1, j)
+            } else {
+                (i, j + 1)
+            };
+        }
+
+        let width = xs[count - 1] as usize + 2;
+        let height = ys[count - 1] as usize + 2;
+        let mut map = Grid::new(width, height, '#');
+
+        map[Position(1, 0)] = '.';
+        map[Position(xs[count - 1], ys[count - 1] + 1)] = '.';
+
+        for i in 0..count {
+            for j in 0..count {
+                let junction = Position(xs[i], ys[j]);
+                map[junction] = '.';
+
+                for (dir, target, slope) in [
+                    (
+                        Direction::Right,
+                        xs.get(i + 1).map(|&x| Position(x, ys[j])),
+                        '>',
+                    ),
+                    (
+                        Direction::Down,
+                        ys.get(j + 1).map(|&y| Position(xs[i], y)),
+                        'v',
+                    ),
+                ] {
+                    let Some(target) = target else {
+                        continue;
+                    };
+
+                    let keep = rng.gen_range(0..100) < params.get("edges");
+
+                    if !keep && !kept.contains(&(i, j, dir)) {
+                        continue;
+                    }
+
+                    let mut pos = junction.move_dir(dir);
+
+                    while pos != target {
+                        map[pos] = '.';
+                        pos = pos.move_dir(dir);
+                    }
+
+                    map[junction.move_dir(dir)] = slope;
+                    map[target.move_dir(dir.inverse())] = slope;
+                }
+            }
+        }
+
+        map.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let params = "junctions=4,corridor=6".parse().unwrap();
+            let input = generate::<Day23>(seed, &params).unwrap();
+            let day = Day23::parse(&input).unwrap();
+
+            let (part1, part2) = (day.game().part1().unwrap(), day.game().part2().unwrap());
+
+            assert!(part1 > 0);
+            assert!(part2 >= part1);
+        }
+    }
+}
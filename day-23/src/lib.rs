@@ -0,0 +1,288 @@
+//! Day 23: A Long Walk
+//!
+//! The longest hike through a maze of paths and slopes, see [`Game`].
+
+mod generate;
+mod render;
+
+use aoc_core::{parse_all, Answer, Solution};
+use graph::{longest_path, Graph, Interner, VertexId};
+use grid::{Direction, Grid, GridError, Position};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::newline,
+    combinator::{map_res, value},
+    multi::{many1, separated_list1},
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameError {
+    /// The map has no path tile to start or end the hike on.
+    NoPathTile,
+}
+
+type Result<T, E = GameError> = std::result::Result<T, E>;
+
+impl std::error::Error for GameError {}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoPathTile => write!(f, "no path tile to start or end the hike"),
+        }
+    }
+}
+
+/// The hiking trails map.
+#[derive(Debug)]
+pub struct Game {
+    pub map: Grid<Tile>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map_res(separated_list1(newline, many1(Tile::parse)), |rows| {
+            Ok::<_, GridError>(Self {
+                map: Grid::from_rows(rows)?,
+            })
+        })(input)
+    }
+
+    /// Longest hike, following slopes unless `ignore_direction` is set.
+    ///
+    /// The maze is reduced to its junctions, see [`Game::junction_graph`],
+    /// before trying every path.
+    pub fn find_longest_path(
+        &self,
+        start_pos: Position,
+        end_pos: Position,
+        ignore_direction: bool,
+    ) -> u64 {
+        let (junctions, graph) = self.junction_graph(start_pos, end_pos, ignore_direction);
+
+        let (Some(start), Some(end)) = (junctions.get(&start_pos), junctions.get(&end_pos)) else {
+            return 0;
+        };
+
+        longest_path(&graph, start, end).map_or(0, |path| path.cost)
+    }
+
+    /// Tiles of the longest hike, `start_pos` and `end_pos` included, empty
+    /// when there is none. See [`Game::find_longest_path`].
+    pub fn find_longest_hike(
+        &self,
+        start_pos: Position,
+        end_pos: Position,
+        ignore_direction: bool,
+    ) -> Vec<Position> {
+        let (junctions, graph) = self.junction_graph(start_pos, end_pos, ignore_direction);
+
+        let (Some(start), Some(end)) = (junctions.get(&start_pos), junctions.get(&end_pos)) else {
+            return vec![];
+        };
+
+        let Some(path) = longest_path(&graph, start, end) else {
+            return vec![];
+        };
+
+        let mut hike = vec![start_pos];
+
+        // Junctions may be linked by several corridors, the longest is the
+        // one taken.
+        for pair in path.nodes.windows(2) {
+            let junction = *junctions.label(pair[0]);
+
+            let corridor = self
+                .find_pos_options(junction, ignore_direction)
+                .into_iter()
+                .filter_map(|next| {
+                    self.follow_corridor(junction, next, &junctions, ignore_direction)
+                })
+                .filter(|&(to, _)| to == pair[1])
+                .map(|(_, tiles)| tiles)
+                .max_by_key(Vec::len)
+                .unwrap_or_default();
+
+            hike.extend(corridor);
+        }
+
+        hike
+    }
+
+    /// The junctions of the maze, `start_pos` and `end_pos` included, linked
+    /// by the length of the corridors between them.
+    pub fn junction_graph(
+        &self,
+        start_pos: Position,
+        end_pos: Position,
+        ignore_direction: bool,
+    ) -> (Interner<Position>, Graph<u64>) {
+        let mut junctions = Interner::new();
+        let mut graph = Graph::new();
+
+        junctions.intern(start_pos);
+        junctions.intern(end_pos);
+
+        for (pos, tile) in self.map.iter() {
+            if tile.can_visit() && self.find_pos_options(pos, true).len() > 2 {
+                junctions.intern(pos);
+            }
+        }
+
+        for (from, &junction) in junctions.labels().iter().enumerate() {
+            for next in self.find_pos_options(junction, ignore_direction) {
+                if let Some((to, tiles)) =
+                    self.follow_corridor(junction, next, &junctions, ignore_direction)
+                {
+                    graph.add_edge(VertexId(from), to, tiles.len() as u64);
+                }
+            }
+        }
+
+        (junctions, graph)
+    }
+
+    /// Walks from `junction` through `next` to the following junction,
+    /// returning its id with the tiles stepped on, that junction included.
+    /// `None` for dead ends.
+    fn follow_corridor(
+        &self,
+        junction: Position,
+        next: Position,
+        junctions: &Interner<Position>,
+        ignore_direction: bool,
+    ) -> Option<(VertexId, Vec<Position>)> {
+        let (mut previous, mut pos, mut tiles) = (junction, next, vec![next]);
+
+        loop {
+            if let Some(id) = junctions.get(&pos) {
+                return Some((id, tiles));
+            }
+
+            let options = self
+                .find_pos_options(pos, ignore_direction)
+                .into_iter()
+                .filter(|&p| p != previous)
+                .collect::<Vec<_>>();
+
+            let [p] = options[..] else {
+                return None;
+            };
+
+            (previous, pos) = (pos, p);
+            tiles.push(p);
+        }
+    }
+
+    /// Path tile in the top row.
+    pub fn find_start(&self) -> Result<Position> {
+        self.map
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Path)
+            .min_by_key(|(Position(_, y), _)| *y)
+            .map(|(pos, _)| pos)
+            .ok_or(GameError::NoPathTile)
+    }
+
+    /// Path tile in the bottom row.
+    pub fn find_end(&self) -> Result<Position> {
+        self.map
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Path)
+            .max_by_key(|(Position(_, y), _)| *y)
+            .map(|(pos, _)| pos)
+            .ok_or(GameError::NoPathTile)
+    }
+
+    /// Longest hike down the slopes.
+    pub fn part1(&self) -> Result<u64> {
+        let (start_pos, end_pos) = (self.find_start()?, self.find_end()?);
+
+        Ok(self.find_longest_path(start_pos, end_pos, false))
+    }
+
+    /// Longest hike, climbing slopes too.
+    pub fn part2(&self) -> Result<u64> {
+        let (start_pos, end_pos) = (self.find_start()?, self.find_end()?);
+
+        Ok(self.find_longest_path(start_pos, end_pos, true))
+    }
+
+    /// Tiles reachable in one step from `pos`.
+    pub fn find_pos_options(&self, pos: Position, ignore_direction: bool) -> Vec<Position> {
+        let dirs = match self.map.get(pos) {
+            Some(Tile::Path) => Direction::ALL.to_vec(),
+            Some(Tile::Slope(dir)) => {
+                if ignore_direction {
+                    Direction::ALL.to_vec()
+                } else {
+                    [*dir].to_vec()
+                }
+            }
+            _ => vec![],
+        };
+
+        dirs.into_iter()
+            .map(|dir| pos.move_dir(dir))
+            .filter(|&p| match self.map.get(p) {
+                Some(tile) => tile.can_visit(),
+                _ => false,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+impl Tile {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            value(Self::Path, tag(".")),
+            value(Self::Forest, tag("#")),
+            value(Self::Slope(Direction::Up), tag("^")),
+            value(Self::Slope(Direction::Right), tag(">")),
+            value(Self::Slope(Direction::Down), tag("v")),
+            value(Self::Slope(Direction::Left), tag("<")),
+        ))(input)
+    }
+
+    pub fn can_visit(&self) -> bool {
+        matches!(self, Self::Path | Self::Slope(_))
+    }
+}
+
+pub struct Day23 {
+    game: Game,
+}
+
+impl Day23 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day23 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 23;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let game = parse_all(input, Game::parse)?;
+
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        Ok(self.game.part1()?.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
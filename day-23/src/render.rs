@@ -0,0 +1,50 @@
+use aoc_core::{Part, Render};
+use grid::{Grid, Pixel, Rgb};
+
+use crate::{Day23, Tile};
+
+impl Render for Day23 {
+    /// The trails with the longest hike in red, down the slopes only for
+    /// part 1.
+    fn render(&self, part: Part) -> anyhow::Result<Grid<Pixel>> {
+        let (start_pos, end_pos) = (self.game.find_start()?, self.game.find_end()?);
+
+        let hike = self
+            .game
+            .find_longest_hike(start_pos, end_pos, part == Part::Two);
+
+        let mut grid = self.game.map.map(|tile| match tile {
+            Tile::Path => Pixel::new('.', Rgb::GREY),
+            Tile::Forest => Pixel::new('#', Rgb::GREEN.mix(Rgb::BLACK, 0.6)),
+            Tile::Slope(dir) => Pixel::new(dir.arrow(), Rgb::WHITE),
+        });
+
+        for pos in hike {
+            grid[pos] = Pixel::new('O', Rgb::RED);
+        }
+
+        Ok(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let day = Day23::parse(include_str!("sample-input.txt")).unwrap();
+
+        // A hike of n steps goes through n + 1 tiles.
+        for (part, steps) in [(Part::One, 94), (Part::Two, 154)] {
+            let grid = day.render(part).unwrap();
+
+            assert_eq!(
+                grid.iter().filter(|(_, p)| p.glyph == 'O').count(),
+                steps + 1
+            );
+        }
+    }
+}
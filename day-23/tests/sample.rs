@@ -0,0 +1,52 @@
+use aoc_core::{parse_all, Answer, Solution};
+use grid::Position;
+
+use day_23::{Day23, Game, GameError, Tile};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() {
+    let game = parse_all(SAMPLE, Game::parse).unwrap();
+
+    assert_eq!(game.part1().unwrap(), 94);
+}
+
+#[test]
+fn part2() {
+    let game = parse_all(SAMPLE, Game::parse).unwrap();
+
+    assert_eq!(game.part2().unwrap(), 154);
+}
+
+#[test]
+fn junctions() {
+    let game = parse_all(SAMPLE, Game::parse).unwrap();
+    let (start, end) = (game.find_start().unwrap(), game.find_end().unwrap());
+
+    assert_eq!(start, Position(1, 0));
+    assert_eq!(end, Position(21, 22));
+    assert_eq!(game.map[start], Tile::Path);
+
+    let (junctions, graph) = game.junction_graph(start, end, false);
+
+    assert_eq!(junctions.len(), 9);
+    assert_eq!(graph.edges(junctions.get(&start).unwrap()).len(), 1);
+}
+
+#[test]
+fn solution() {
+    let day = Day23::parse(SAMPLE).unwrap();
+
+    assert_eq!(day.game().map.width(), 23);
+    assert_eq!(day.part1().unwrap(), Answer::from(94u64));
+}
+
+#[test]
+fn invalid() {
+    let day = Day23::parse("#").unwrap();
+
+    assert!(day.part1().is_err());
+    assert!(day.part2().is_err());
+    assert_eq!(day.game().part1(), Err(GameError::NoPathTile));
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Param, Params};
+use rand::Rng;
+
+use crate::Day24;
+
+impl Generate for Day24 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("hailstones", 300, "Number of hailstones"),
+        Param::new("velocity", 300, "Largest speed along an axis"),
+    ];
+
+    /// A rock is thrown first, then every hailstone is placed so that the
+    /// rock hits it at a distinct time, which keeps part 2 solvable.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let speed = params.get("velocity").max(1) as i64;
+        let velocity =
+            |rng: &mut R| [0; 3].map(|_| rng.gen_range(1..=speed) * if rng.gen() { 1 } else { -1 });
+
+        let rock = [0; 3].map(|_| rng.gen_range(200_000_000_000_000..400_000_000_000_000i64));
+        let rock_velocity = velocity(rng);
+
+        let mut times = HashSet::new();
+        let mut input = String::new();
+
+        while times.len() < params.size("hailstones", 1) {
+            let time = rng.gen_range(10_000_000_000..400_000_000_000i64);
+            let hail_velocity = velocity(rng);
+
+            if hail_velocity == rock_velocity || !times.insert(time) {
+                continue;
+            }
+
+            let [x, y, z] =
+                [0, 1, 2].map(|i| rock[i] + (rock_velocity[i] - hail_velocity[i]) * time);
+            let [dx, dy, dz] = hail_velocity;
+
+            input.push_str(&format!("{x}, {y}, {z} @ {dx}, {dy}, {dz}\n"));
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate::<Day24>(seed, &"hailstones=50".parse().unwrap()).unwrap();
+            let day = Day24::parse(&input).unwrap();
+
+            assert_eq!(input.lines().count(), 50);
+            assert!(day.part1().is_ok());
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Day 24: Never Tell Me The Odds
+//!
+//! Hailstones moving in straight lines, see [`Game`]. Part 2 hands the
+//! equations to the `z3` solver, which must be installed.
+
+mod generate;
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use aoc_core::{parse_all, Answer, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i64, newline, space0},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+
+#[derive(Debug)]
+pub enum GameError {
+    /// Fewer hailstones than needed to pin down the rock's path.
+    TooFewHailstones(usize),
+    /// `z3` could not be run or talked to.
+    Z3(io::Error),
+    /// The last line printed by `z3` is not the answer.
+    Z3Output(String),
+}
+
+type Result<T, E = GameError> = std::result::Result<T, E>;
+
+impl std::error::Error for GameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Z3(err) => Some(err),
+            Self::TooFewHailstones(_) | Self::Z3Output(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooFewHailstones(found) => {
+                write!(f, "at least three hailstones are needed, found {found}")
+            }
+            Self::Z3(_) => write!(f, "cannot run z3"),
+            Self::Z3Output(line) => write!(f, "unexpected output of z3: {line}"),
+        }
+    }
+}
+
+impl From<io::Error> for GameError {
+    fn from(err: io::Error) -> Self {
+        Self::Z3(err)
+    }
+}
+
+/// The hailstones.
+#[derive(Debug)]
+pub struct Game {
+    pub lines: Vec<Line>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_list1(
+                newline,
+                map(
+                    separated_pair(
+                        Point3D::parse,
+                        tuple((space0, tag("@"), space0)),
+                        Point3D::parse,
+                    ),
+                    |(p, v)| Line(p, v),
+                ),
+            ),
+            |lines| Self { lines },
+        )(input)
+    }
+
+    /// Future crossings of hailstone paths inside the test area, ignoring
+    /// the z axis.
+    pub fn part1(&self) -> u64 {
+        self.count_intersections(200000000000000.0, 400000000000000.0)
+    }
+
+    /// Future crossings of hailstone paths with both x and y between `min`
+    /// and `max`.
+    pub fn count_intersections(&self, min: f64, max: f64) -> u64 {
+        let range = (min, max);
+
+        self.lines
+            .iter()
+            .enumerate()
+            .flat_map(|(i1, l1)| {
+                self.lines
+                    .iter()
+                    .enumerate()
+                    .filter(move |(i2, _)| i1 < *i2)
+                    .filter_map(|(_, l2)| l1.intersect_2d(l2))
+                    .filter(|p| {
+                        p.0 >= range.0 && p.0 <= range.1 && p.1 >= range.0 && p.1 <= range.1
+                    })
+            })
+            .count() as u64
+    }
+
+    fn declare_const(name: &str) -> String {
+        format!("(declare-const {name} Int)")
+    }
+
+    fn assert(eq: &str) -> String {
+        format!("(assert ({eq}))")
+    }
+
+    /// SMT-LIB script finding the rock that hits the first three
+    /// hailstones, and evaluating the sum of its coordinates.
+    pub fn get_z3_command(&self) -> String {
+        let mut res = vec![
+            Self::declare_const("x"),
+            Self::declare_const("y"),
+            Self::declare_const("z"),
+            Self::declare_const("vx"),
+            Self::declare_const("vy"),
+            Self::declare_const("vz"),
+        ];
+
+        for (index, _) in self.lines.iter().take(3).enumerate() {
+            res.push(Self::declare_const(&format!("t{index}")));
+        }
+
+        for (index, line) in self.lines.iter().take(3).enumerate() {
+            let Line(Point3D(x, y, z), Point3D(vx, vy, vz)) = line;
+
+            res.push(Self::assert(&format!(
+                "= (+ {x} (* t{index} {vx})) (+ x (* t{index} vx))",
+            )));
+
+            res.push(Self::assert(&format!(
+                "= (+ {y} (* t{index} {vy})) (+ y (* t{index} vy))",
+            )));
+
+            res.push(Self::assert(&format!(
+                "= (+ {z} (* t{index} {vz})) (+ z (* t{index} vz))",
+            )));
+        }
+
+        res.push("(check-sat)".to_string());
+        res.push("(eval (+ (+ x y) z))".to_string());
+
+        res.join("\n")
+    }
+
+    /// Sum of the coordinates of the rock's initial position.
+    pub fn part2(&self) -> Result<u64> {
+        if self.lines.len() < 3 {
+            return Err(GameError::TooFewHailstones(self.lines.len()));
+        }
+
+        let mut child = Command::new("z3")
+            .args(["-in"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        if let Some(ref mut stdin) = child.stdin {
+            stdin.write_all(self.get_z3_command().as_bytes())?;
+        }
+
+        let output = child.wait_with_output()?;
+        let output = String::from_utf8_lossy(&output.stdout);
+        let last = output.lines().last().unwrap_or_default();
+
+        last.parse::<u64>()
+            .map_err(|_| GameError::Z3Output(last.to_string()))
+    }
+}
+
+/// Hailstone position and velocity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line(pub Point3D, pub Point3D);
+
+impl Line {
+    /// Point where the paths of both hailstones cross in the future,
+    /// ignoring the z axis.
+    pub fn intersect_2d(&self, other: &Self) -> Option<Point3D> {
+        let Self(p_a, v_a) = *self;
+        let Self(p_b, v_b) = *other;
+
+        let det = v_a.0 * v_b.1 - v_a.1 * v_b.0;
+
+        if det.abs() < f64::EPSILON {
+            return None;
+        }
+
+        let t_a = ((p_b.0 - p_a.0) * v_b.1 - (p_b.1 - p_a.1) * v_b.0) / det;
+        let t_b = ((p_a.0 - p_b.0) * v_a.1 - (p_a.1 - p_b.1) * v_a.0) / det;
+
+        if t_a < 0.0 || t_b > 0.0 {
+            return None;
+        }
+
+        Some(Point3D(
+            p_a.0 + t_a * v_a.0,
+            p_a.1 + t_a * v_a.1,
+            0.0, /* z is ignored for 2D case */
+        ))
+    }
+}
+
+/// `(x, y, z)` coordinates, also used for velocities.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point3D(pub f64, pub f64, pub f64);
+
+impl Point3D {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            tuple((i64, tag(","), space0, i64, tag(","), space0, i64)),
+            |(x, _, _, y, _, _, z)| Self(x as f64, y as f64, z as f64),
+        )(input)
+    }
+}
+
+pub struct Day24 {
+    game: Game,
+}
+
+impl Day24 {
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+impl Solution for Day24 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 24;
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let game = parse_all(input, Game::parse)?;
+
+        Ok(Self { game })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        Ok(self.game.part1().into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        Ok(self.game.part2()?.into())
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_core::{parse_all, Solution};
+
+use day_24::{Day24, Game, GameError, Line, Point3D};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() {
+    let game = parse_all(SAMPLE, Game::parse).unwrap();
+
+    assert_eq!(game.count_intersections(7.0, 27.0), 2);
+}
+
+#[test]
+fn intersect_2d() {
+    let a = Line(Point3D(19.0, 13.0, 30.0), Point3D(-2.0, 1.0, -2.0));
+    let b = Line(Point3D(18.0, 19.0, 22.0), Point3D(-1.0, -1.0, -2.0));
+    let c = Line(Point3D(20.0, 19.0, 15.0), Point3D(1.0, -5.0, -3.0));
+
+    let Some(Point3D(x, y, _)) = a.intersect_2d(&b) else {
+        panic!("Paths should cross");
+    };
+
+    assert!((x - 14.333).abs() < 1e-3);
+    assert!((y - 15.333).abs() < 1e-3);
+    assert_eq!(a.intersect_2d(&c), None);
+}
+
+#[test]
+fn z3_command() {
+    let day = Day24::parse(SAMPLE).unwrap();
+    let command = day.game().get_z3_command();
+
+    assert_eq!(day.game().lines.len(), 5);
+    assert!(command.contains("(assert (= (+ 19 (* t0 -2)) (+ x (* t0 vx))))"));
+    assert!(command.ends_with("(eval (+ (+ x y) z))"));
+}
+
+#[test]
+fn too_few_hailstones() {
+    let day = Day24::parse("19, 13, 30 @ -2,  1, -2").unwrap();
+
+    assert!(day.part2().is_err());
+    assert!(matches!(
+        day.game().part2(),
+        Err(GameError::TooFewHailstones(1))
+    ));
+}
@@ -0,0 +1,107 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use aoc_core::{Generate, Param, Params};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Day25;
+
+impl Generate for Day25 {
+    const PARAMS: &'static [Param] = &[
+        Param::new("components", 1500, "Number of components, at least 10"),
+        Param::new("extra", 1, "Random wires added per component"),
+    ];
+
+    /// Two groups wired so that cutting them takes at least four wires,
+    /// joined by exactly three wires.
+    ///
+    /// Each group is a ring where every component is also wired to the
+    /// second next one, with random wires added.
+    fn generate<R: Rng>(rng: &mut R, params: &Params) -> String {
+        let count = params.size("components", 10);
+        let mut names = HashSet::new();
+
+        while names.len() < count {
+            names.insert(
+                (0..3)
+                    .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+                    .collect::<String>(),
+            );
+        }
+
+        let mut names = names.into_iter().collect::<Vec<_>>();
+        names.sort_unstable();
+        names.shuffle(rng);
+
+        let split = rng
+            .gen_range(count / 3..=count - count / 3)
+            .clamp(5, count - 5);
+
+        // Wires by the indices of their ends in order, so that none is added
+        // twice.
+        let mut wires = BTreeSet::new();
+        let mut wire = |a: usize, b: usize| wires.insert((a.min(b), a.max(b)));
+
+        for group in [0..split, split..count] {
+            let n = group.len();
+
+            for i in 0..n {
+                let a = group.start + i;
+
+                wire(a, group.start + (i + 1) % n);
+                wire(a, group.start + (i + 2) % n);
+
+                for _ in 0..params.get("extra") {
+                    let b = rng.gen_range(group.clone());
+
+                    if a != b {
+                        wire(a, b);
+                    }
+                }
+            }
+        }
+
+        let mut joined = 0;
+
+        while joined < 3 {
+            joined += usize::from(wire(rng.gen_range(0..split), rng.gen_range(split..count)));
+        }
+
+        // Each wire is listed on the line of one of its ends.
+        let mut lines: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+
+        for (a, b) in wires {
+            let (a, b) = if rng.gen() { (a, b) } else { (b, a) };
+
+            lines.entry(a).or_default().push(&names[b]);
+        }
+
+        let mut lines = lines
+            .into_iter()
+            .map(|(name, others)| format!("{}: {}", names[name], others.join(" ")))
+            .collect::<Vec<_>>();
+        lines.shuffle(rng);
+
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{generate, Answer, Solution};
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = generate::<Day25>(seed, &"components=40".parse().unwrap()).unwrap();
+            let day = Day25::parse(&input).unwrap();
+
+            let Answer::Number(product) = day.part1().unwrap() else {
+                panic!("Expected a number");
+            };
+
+            assert!(product >= 5 * 35);
+        }
+    }
+}
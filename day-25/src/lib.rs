@@ -0,0 +1,128 @@
+//! Day 25: Snowverload
+//!
+//! Components joined by wires, three of which split them into two groups,
+//! see [`Wiring`].
+
+mod generate;
+
+use aoc_core::{parse_all, Answer, Part, Solution};
+use graph::{components, min_cut, Graph, Interner, VertexId};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha0, alpha1, newline, space0, space1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WiringError {
+    /// No component is separated from the first one by three wires.
+    NoCut,
+    /// Cutting the wires leaves this many groups instead of two.
+    Groups(usize),
+}
+
+type Result<T, E = WiringError> = std::result::Result<T, E>;
+
+impl std::error::Error for WiringError {}
+
+impl std::fmt::Display for WiringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoCut => write!(f, "no three wires split the components"),
+            Self::Groups(found) => write!(f, "expected 2 groups, found {found}"),
+        }
+    }
+}
+
+/// Components as vertices, each wire as an undirected edge of capacity 1.
+#[derive(Debug, Clone)]
+pub struct Wiring {
+    pub graph: Graph<u64>,
+}
+
+impl Wiring {
+    /// Cuts the three wires splitting the components into two groups, and
+    /// multiplies the group sizes.
+    ///
+    /// The first component is in one of the groups. Any other component
+    /// whose minimum cut to it is only three wires is in the other group.
+    pub fn part1(&self) -> Result<u64> {
+        let first = VertexId(0);
+
+        let cut = self
+            .graph
+            .vertices()
+            .skip(1)
+            .map(|v| min_cut(&self.graph, first, v))
+            .find(|cut| cut.value == 3)
+            .ok_or(WiringError::NoCut)?;
+
+        let mut graph = self.graph.clone();
+        for &(a, b) in &cut.edges {
+            graph.remove_undirected_edge(a, b);
+        }
+
+        match components(&graph)[..] {
+            [ref a, ref b] => Ok((a.len() * b.len()) as u64),
+            ref groups => Err(WiringError::Groups(groups.len())),
+        }
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        // Example: "jqt: rhn xhk nvd"
+
+        map(
+            separated_list1(
+                newline::<&str, _>,
+                map(
+                    tuple((alpha1, tag(":"), space0, separated_list1(space1, alpha0))),
+                    |(node, _, _, edges)| (node, edges),
+                ),
+            ),
+            |list| {
+                let mut names = Interner::new();
+                let mut graph = Graph::new();
+
+                for (node, edges) in list {
+                    let a = names.intern(node.to_string());
+
+                    for edge in edges {
+                        let b = names.intern(edge.to_string());
+                        graph.add_undirected_edge(a, b, 1);
+                    }
+                }
+
+                Self { graph }
+            },
+        )(input)
+    }
+}
+
+pub struct Day25 {
+    wiring: Wiring,
+}
+
+impl Day25 {
+    pub fn wiring(&self) -> &Wiring {
+        &self.wiring
+    }
+}
+
+impl Solution for Day25 {
+    const EVENT: &'static str = "2023";
+    const DAY: u8 = 25;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse(input: &str) -> anyhow::Result<Self> {
+        let wiring = parse_all(input, Wiring::parse)?;
+
+        Ok(Self { wiring })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        Ok(self.wiring.part1()?.into())
+    }
+}
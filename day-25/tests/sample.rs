@@ -0,0 +1,35 @@
+use aoc_core::{parse_all, Answer, Solution};
+
+use day_25::{Day25, Wiring, WiringError};
+
+const SAMPLE: &str = include_str!("../src/sample-input.txt");
+
+#[test]
+fn part1() {
+    let wiring = parse_all(SAMPLE, Wiring::parse).unwrap();
+
+    assert_eq!(wiring.part1().unwrap(), 54);
+}
+
+#[test]
+fn wiring() {
+    let wiring = parse_all(SAMPLE, Wiring::parse).unwrap();
+
+    assert_eq!(wiring.graph.vertex_count(), 15);
+    assert_eq!(wiring.graph.edge_count(), 2 * 33);
+}
+
+#[test]
+fn solution() {
+    let day = Day25::parse(SAMPLE).unwrap();
+
+    assert_eq!(day.part1().unwrap(), Answer::from(54u64));
+    assert!(day.part2().is_err());
+}
+
+#[test]
+fn no_cut() {
+    let wiring = parse_all("a: b\nb: c", Wiring::parse).unwrap();
+
+    assert_eq!(wiring.part1(), Err(WiringError::NoCut));
+}
@@ -0,0 +1,67 @@
+use crate::{Graph, VertexId};
+
+/// Connected components, ignoring edge directions.
+///
+/// Each component is sorted, and components are ordered by their first
+/// vertex.
+pub fn components<W>(graph: &Graph<W>) -> Vec<Vec<VertexId>> {
+    let mut parents = (0..graph.vertex_count()).collect::<Vec<_>>();
+
+    fn root(parents: &mut [usize], mut v: usize) -> usize {
+        while parents[v] != v {
+            parents[v] = parents[parents[v]];
+            v = parents[v];
+        }
+
+        v
+    }
+
+    for vertex in graph.vertices() {
+        for next in graph.neighbours(vertex) {
+            let (a, b) = (
+                root(&mut parents, vertex.index()),
+                root(&mut parents, next.index()),
+            );
+
+            parents[a.max(b)] = a.min(b);
+        }
+    }
+
+    let mut components: Vec<Vec<VertexId>> = Vec::new();
+    let mut component_of_root = vec![None; parents.len()];
+
+    for vertex in graph.vertices() {
+        let r = root(&mut parents, vertex.index());
+
+        let index = *component_of_root[r].get_or_insert_with(|| {
+            components.push(Vec::new());
+            components.len() - 1
+        });
+
+        components[index].push(vertex);
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_components() {
+        let mut graph = Graph::with_vertices(6);
+        graph.add_edge(VertexId(3), VertexId(0), ());
+        graph.add_edge(VertexId(1), VertexId(4), ());
+        graph.add_edge(VertexId(4), VertexId(5), ());
+
+        assert_eq!(
+            components(&graph),
+            [
+                vec![VertexId(0), VertexId(3)],
+                vec![VertexId(1), VertexId(4), VertexId(5)],
+                vec![VertexId(2)],
+            ]
+        );
+    }
+}
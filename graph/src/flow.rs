@@ -0,0 +1,210 @@
+use std::collections::VecDeque;
+
+use crate::{Graph, VertexId};
+
+/// Minimum cut between two vertices, as found by [`min_cut`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    /// Total capacity of the cut edges, equal to the maximum flow.
+    pub value: u64,
+    /// Vertices still reachable from the source once the cut is removed.
+    pub source_side: Vec<VertexId>,
+    /// Edges from the source side to the other side.
+    pub edges: Vec<(VertexId, VertexId)>,
+}
+
+struct Edge {
+    to: usize,
+    capacity: u64,
+    /// Index of the opposite edge in the list of `to`.
+    reverse: usize,
+}
+
+/// Residual network for the Edmonds-Karp algorithm.
+struct Network {
+    edges: Vec<Vec<Edge>>,
+}
+
+impl Network {
+    fn new(graph: &Graph<u64>, size: usize) -> Self {
+        let mut edges: Vec<Vec<Edge>> = (0..size).map(|_| Vec::new()).collect();
+
+        for from in graph.vertices() {
+            for &(to, capacity) in graph.edges(from) {
+                let (from, to) = (from.index(), to.index());
+                let (forward, backward) = (edges[from].len(), edges[to].len());
+
+                edges[from].push(Edge {
+                    to,
+                    capacity,
+                    reverse: backward + usize::from(from == to),
+                });
+                edges[to].push(Edge {
+                    to: from,
+                    capacity: 0,
+                    reverse: forward,
+                });
+            }
+        }
+
+        Self { edges }
+    }
+
+    /// Shortest augmenting path as `(vertex, edge index)` steps, or the
+    /// vertices reachable from `source` when there is none left.
+    fn augmenting_path(
+        &self,
+        source: usize,
+        sink: usize,
+    ) -> Result<Vec<(usize, usize)>, Vec<bool>> {
+        let mut previous: Vec<Option<(usize, usize)>> = vec![None; self.edges.len()];
+        let mut reached = vec![false; self.edges.len()];
+        let mut queue = VecDeque::from([source]);
+
+        reached[source] = true;
+
+        while let Some(v) = queue.pop_front() {
+            for (i, edge) in self.edges[v].iter().enumerate() {
+                if edge.capacity > 0 && !reached[edge.to] {
+                    reached[edge.to] = true;
+                    previous[edge.to] = Some((v, i));
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        if !reached[sink] {
+            return Err(reached);
+        }
+
+        let mut path = Vec::new();
+        let mut v = sink;
+
+        while let Some((u, i)) = previous[v] {
+            path.push((u, i));
+            v = u;
+        }
+
+        Ok(path)
+    }
+
+    /// Pushes as much flow as possible, returns it with the source side of
+    /// the resulting cut.
+    fn saturate(&mut self, source: usize, sink: usize) -> (u64, Vec<bool>) {
+        let mut flow = 0;
+
+        loop {
+            let path = match self.augmenting_path(source, sink) {
+                Ok(path) => path,
+                Err(reached) => return (flow, reached),
+            };
+
+            let amount = path
+                .iter()
+                .map(|&(v, i)| self.edges[v][i].capacity)
+                .min()
+                .unwrap_or(0);
+
+            for &(v, i) in &path {
+                let Edge { to, reverse, .. } = self.edges[v][i];
+
+                self.edges[v][i].capacity -= amount;
+                self.edges[to][reverse].capacity += amount;
+            }
+
+            flow += amount;
+        }
+    }
+}
+
+/// Maximum flow from `source` to `sink`, edge weights being capacities.
+pub fn max_flow(graph: &Graph<u64>, source: VertexId, sink: VertexId) -> u64 {
+    min_cut(graph, source, sink).value
+}
+
+/// Cheapest set of edges whose removal disconnects `sink` from `source`.
+///
+/// For an undirected graph, add each edge in both directions with the same
+/// capacity.
+pub fn min_cut(graph: &Graph<u64>, source: VertexId, sink: VertexId) -> Cut {
+    if source == sink {
+        return Cut {
+            value: 0,
+            source_side: vec![source],
+            edges: Vec::new(),
+        };
+    }
+
+    // Unknown vertices are isolated.
+    let size = graph
+        .vertex_count()
+        .max(source.index() + 1)
+        .max(sink.index() + 1);
+
+    let mut network = Network::new(graph, size);
+    let (value, reached) = network.saturate(source.index(), sink.index());
+
+    let source_side = (0..size)
+        .filter(|&v| reached[v])
+        .map(VertexId)
+        .collect::<Vec<_>>();
+
+    let edges = source_side
+        .iter()
+        .flat_map(|&v| graph.neighbours(v).map(move |n| (v, n)))
+        .filter(|(_, n)| !reached[n.index()])
+        .collect();
+
+    Cut {
+        value,
+        source_side,
+        edges,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(i: usize) -> VertexId {
+        VertexId(i)
+    }
+
+    #[test]
+    fn test_max_flow() {
+        // Classic example with a maximum flow of 23.
+        let mut graph = Graph::new();
+        for (from, to, capacity) in [
+            (0, 1, 16),
+            (0, 2, 13),
+            (1, 2, 10),
+            (2, 1, 4),
+            (1, 3, 12),
+            (3, 2, 9),
+            (2, 4, 14),
+            (4, 3, 7),
+            (3, 5, 20),
+            (4, 5, 4),
+        ] {
+            graph.add_edge(v(from), v(to), capacity);
+        }
+
+        assert_eq!(max_flow(&graph, v(0), v(5)), 23);
+        assert_eq!(max_flow(&graph, v(5), v(0)), 0);
+    }
+
+    #[test]
+    fn test_min_cut() {
+        // Two triangles joined by a single edge.
+        let mut graph = Graph::new();
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+            graph.add_undirected_edge(v(a), v(b), 1);
+        }
+
+        let cut = min_cut(&graph, v(0), v(5));
+
+        assert_eq!(cut.value, 1);
+        assert_eq!(cut.source_side, [v(0), v(1), v(2)]);
+        assert_eq!(cut.edges, [(v(2), v(3))]);
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+/// Index of a vertex in a [`Graph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VertexId(pub usize);
+
+impl VertexId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl Display for VertexId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Directed graph with weighted edges, stored as adjacency lists.
+///
+/// Undirected graphs add every edge in both directions, see
+/// [`Graph::add_undirected_edge`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W = u64> {
+    adjacency: Vec<Vec<(VertexId, W)>>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> Graph<W> {
+    pub fn new() -> Self {
+        Self {
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Graph with `count` vertices and no edges.
+    pub fn with_vertices(count: usize) -> Self {
+        Self {
+            adjacency: (0..count).map(|_| Vec::new()).collect(),
+        }
+    }
+
+    pub fn add_vertex(&mut self) -> VertexId {
+        self.adjacency.push(Vec::new());
+
+        VertexId(self.adjacency.len() - 1)
+    }
+
+    /// Adds missing vertices so that `vertex` is part of the graph.
+    fn ensure_vertex(&mut self, vertex: VertexId) {
+        if vertex.0 >= self.adjacency.len() {
+            self.adjacency.resize_with(vertex.0 + 1, Vec::new);
+        }
+    }
+
+    pub fn add_edge(&mut self, from: VertexId, to: VertexId, weight: W) {
+        self.ensure_vertex(from);
+        self.ensure_vertex(to);
+
+        self.adjacency[from.0].push((to, weight));
+    }
+
+    /// Removes every edge from `from` to `to`.
+    pub fn remove_edge(&mut self, from: VertexId, to: VertexId) {
+        if let Some(edges) = self.adjacency.get_mut(from.0) {
+            edges.retain(|&(v, _)| v != to);
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum()
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = VertexId> {
+        (0..self.adjacency.len()).map(VertexId)
+    }
+
+    /// Outgoing edges of `vertex`, empty for unknown vertices.
+    pub fn edges(&self, vertex: VertexId) -> &[(VertexId, W)] {
+        self.adjacency.get(vertex.0).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn neighbours(&self, vertex: VertexId) -> impl Iterator<Item = VertexId> + '_ {
+        self.edges(vertex).iter().map(|&(v, _)| v)
+    }
+}
+
+impl<W: Clone> Graph<W> {
+    pub fn add_undirected_edge(&mut self, a: VertexId, b: VertexId, weight: W) {
+        self.add_edge(a, b, weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    pub fn remove_undirected_edge(&mut self, a: VertexId, b: VertexId) {
+        self.remove_edge(a, b);
+        self.remove_edge(b, a);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edges() {
+        let mut graph = Graph::new();
+        let a = graph.add_vertex();
+        let b = graph.add_vertex();
+
+        graph.add_undirected_edge(a, b, 3);
+        graph.add_edge(b, VertexId(3), 1);
+
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.edges(b), &[(a, 3), (VertexId(3), 1)]);
+        assert_eq!(graph.edges(VertexId(10)), &[]);
+
+        graph.remove_undirected_edge(a, b);
+        assert_eq!(graph.neighbours(b).collect::<Vec<_>>(), [VertexId(3)]);
+        assert_eq!(graph.neighbours(a).count(), 0);
+    }
+}
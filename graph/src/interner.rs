@@ -0,0 +1,83 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::VertexId;
+
+/// Assigns dense [`VertexId`]s to vertex labels, in order of appearance.
+#[derive(Debug, Clone)]
+pub struct Interner<T> {
+    ids: HashMap<T, VertexId>,
+    labels: Vec<T>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash> Interner<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Id of `label`, assigning the next free one if it is new.
+    pub fn intern(&mut self, label: T) -> VertexId {
+        if let Some(&id) = self.ids.get(&label) {
+            return id;
+        }
+
+        let id = VertexId(self.labels.len());
+        self.labels.push(label.clone());
+        self.ids.insert(label, id);
+
+        id
+    }
+
+    pub fn get(&self, label: &T) -> Option<VertexId> {
+        self.ids.get(label).copied()
+    }
+
+    /// Label of `id`.
+    ///
+    /// # Panics
+    ///
+    /// If `id` was not returned by this interner.
+    pub fn label(&self, id: VertexId) -> &T {
+        &self.labels[id.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn labels(&self) -> &[T] {
+        &self.labels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let mut names = Interner::new();
+
+        let a = names.intern("jqt");
+        let b = names.intern("rhn");
+
+        assert_eq!(names.intern("jqt"), a);
+        assert_eq!((a, b), (VertexId(0), VertexId(1)));
+        assert_eq!(names.get(&"rhn"), Some(b));
+        assert_eq!(names.get(&"xhk"), None);
+        assert_eq!(*names.label(b), "rhn");
+        assert_eq!(names.len(), 2);
+    }
+}
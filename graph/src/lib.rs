@@ -0,0 +1,19 @@
+//! Graph algorithms shared by the graph based puzzles.
+//!
+//! - [`Graph`] stores weighted adjacency lists over dense [`VertexId`]s,
+//!   [`Interner`] maps puzzle labels such as names or positions to them.
+//! - [`bfs`], [`dijkstra`] and [`astar`] search implicit graphs given by a
+//!   successor function, so grids don't need to be converted first.
+//! - [`components`], [`max_flow`] and [`min_cut`] work on a [`Graph`].
+
+mod components;
+mod flow;
+mod graph;
+mod interner;
+mod search;
+
+pub use components::components;
+pub use flow::{max_flow, min_cut, Cut};
+pub use graph::{Graph, VertexId};
+pub use interner::Interner;
+pub use search::{astar, bfs, dijkstra, longest_path, Path};
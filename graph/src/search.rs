@@ -0,0 +1,281 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::{Graph, VertexId};
+
+/// Path found by [`dijkstra`], [`astar`] or [`longest_path`], from a start
+/// node to a goal node, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, W> {
+    pub nodes: Vec<N>,
+    pub cost: W,
+}
+
+/// Breadth-first search from `start`.
+///
+/// Returns the number of steps to every reachable node, `start` included.
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Cheapest path from any of `starts` to a node accepted by `is_goal`.
+///
+/// `successors` returns the neighbours of a node with the cost of moving
+/// there, which must not be negative.
+pub fn dijkstra<N, W, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, W>>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+    I: IntoIterator<Item = (N, W)>,
+{
+    astar(starts, successors, |_| W::default(), is_goal)
+}
+
+/// [`dijkstra`] guided by a `heuristic` that estimates the remaining cost.
+///
+/// The heuristic must never overestimate, otherwise the path found may not
+/// be the cheapest.
+pub fn astar<N, W, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> W,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, W>>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+    I: IntoIterator<Item = (N, W)>,
+{
+    // Nodes are stored once, the queue and the parent links refer to them by
+    // index so that `N` doesn't need to be ordered.
+    let mut nodes: Vec<(N, Option<usize>, W)> = Vec::new();
+    let mut indices: HashMap<N, usize> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if indices.contains_key(&start) {
+            continue;
+        }
+
+        let estimate = heuristic(&start);
+        indices.insert(start.clone(), nodes.len());
+        queue.push(Reverse((estimate, W::default(), nodes.len())));
+        nodes.push((start, None, W::default()));
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > nodes[index].2 {
+            continue;
+        }
+
+        let node = nodes[index].0.clone();
+
+        if is_goal(&node) {
+            let mut path = vec![node];
+            let mut parent = nodes[index].1;
+
+            while let Some(i) = parent {
+                path.push(nodes[i].0.clone());
+                parent = nodes[i].1;
+            }
+
+            path.reverse();
+
+            return Some(Path { nodes: path, cost });
+        }
+
+        for (next, weight) in successors(&node) {
+            let next_cost = cost + weight;
+
+            let next_index = match indices.get(&next) {
+                Some(&i) if nodes[i].2 <= next_cost => continue,
+                Some(&i) => {
+                    nodes[i].1 = Some(index);
+                    nodes[i].2 = next_cost;
+                    i
+                }
+                None => {
+                    indices.insert(next.clone(), nodes.len());
+                    nodes.push((next.clone(), Some(index), next_cost));
+                    nodes.len() - 1
+                }
+            };
+
+            queue.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+        }
+    }
+
+    None
+}
+
+/// Longest simple path from `start` to `end`.
+///
+/// Tries every path, so it is only usable on small graphs such as the
+/// junctions of a maze.
+pub fn longest_path<W>(
+    graph: &Graph<W>,
+    start: VertexId,
+    end: VertexId,
+) -> Option<Path<VertexId, W>>
+where
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    fn visit<W>(
+        graph: &Graph<W>,
+        vertex: VertexId,
+        end: VertexId,
+        length: W,
+        visited: &mut [bool],
+        nodes: &mut Vec<VertexId>,
+        longest: &mut Option<Path<VertexId, W>>,
+    ) where
+        W: Copy + Ord + Add<Output = W>,
+    {
+        nodes.push(vertex);
+
+        if vertex == end {
+            if longest.as_ref().is_none_or(|path| length > path.cost) {
+                *longest = Some(Path {
+                    nodes: nodes.clone(),
+                    cost: length,
+                });
+            }
+        } else {
+            visited[vertex.index()] = true;
+
+            for &(next, weight) in graph.edges(vertex) {
+                if !visited[next.index()] {
+                    visit(graph, next, end, length + weight, visited, nodes, longest);
+                }
+            }
+
+            visited[vertex.index()] = false;
+        }
+
+        nodes.pop();
+    }
+
+    if start == end {
+        return Some(Path {
+            nodes: vec![start],
+            cost: W::default(),
+        });
+    }
+
+    if start.index() >= graph.vertex_count() {
+        return None;
+    }
+
+    let mut visited = vec![false; graph.vertex_count()];
+    let mut longest = None;
+
+    visit(
+        graph,
+        start,
+        end,
+        W::default(),
+        &mut visited,
+        &mut Vec::new(),
+        &mut longest,
+    );
+
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Moves on a line, where going right costs 1 and going left costs 3.
+    fn line(&n: &i32) -> Vec<(i32, u32)> {
+        vec![(n + 1, 1), (n - 1, 3)]
+    }
+
+    #[test]
+    fn test_bfs() {
+        let distances = bfs(0, |&n: &i32| {
+            [n + 1, n - 1].into_iter().filter(|n| n.abs() <= 3)
+        });
+
+        assert_eq!(distances.len(), 7);
+        assert_eq!(distances[&-3], 3);
+        assert_eq!(distances[&0], 0);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra([0], line, |&n| n == -2).unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.nodes, [0, -1, -2]);
+
+        let path = dijkstra([0, -3], line, |&n| n == -2).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.nodes, [-3, -2]);
+
+        assert_eq!(
+            dijkstra(
+                [0],
+                |&n: &i32| [(n + 1, 1u32)].into_iter().filter(|n| n.0 < 5),
+                |&n| n == -1
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let path = astar([0], line, |&n| n.abs_diff(10), |&n| n == 10).unwrap();
+
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.nodes.len(), 11);
+    }
+
+    #[test]
+    fn test_longest_path() {
+        // 0 -> 1 -> 3 directly is 2, through 2 it is 1 + 5 + 1.
+        let mut graph = Graph::new();
+        graph.add_undirected_edge(VertexId(0), VertexId(1), 1);
+        graph.add_undirected_edge(VertexId(1), VertexId(3), 1);
+        graph.add_undirected_edge(VertexId(1), VertexId(2), 5);
+        graph.add_undirected_edge(VertexId(2), VertexId(3), 1);
+
+        let path = longest_path(&graph, VertexId(0), VertexId(3)).unwrap();
+
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.nodes, [0, 1, 2, 3].map(VertexId));
+        assert_eq!(longest_path(&graph, VertexId(0), VertexId(9)), None);
+        assert_eq!(
+            longest_path(&graph, VertexId(2), VertexId(2)).unwrap().cost,
+            0
+        );
+    }
+}
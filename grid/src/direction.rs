@@ -0,0 +1,86 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Turn {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Direction {
+    /// All directions, clockwise starting from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match (self, turn) {
+            (Self::Up, Turn::Clockwise) => Self::Right,
+            (Self::Right, Turn::Clockwise) => Self::Down,
+            (Self::Down, Turn::Clockwise) => Self::Left,
+            (Self::Left, Turn::Clockwise) => Self::Up,
+
+            (Self::Up, Turn::CounterClockwise) => Self::Left,
+            (Self::Right, Turn::CounterClockwise) => Self::Up,
+            (Self::Down, Turn::CounterClockwise) => Self::Right,
+            (Self::Left, Turn::CounterClockwise) => Self::Down,
+        }
+    }
+
+    /// Unit step as `(dx, dy)`, with `y` growing downwards.
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Right => (1, 0),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    /// `^`, `>`, `v` or `<`, as drawn in the puzzles.
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn(Turn::Clockwise).turn(Turn::CounterClockwise), dir);
+            assert_eq!(
+                dir.turn(Turn::Clockwise).turn(Turn::Clockwise),
+                dir.inverse()
+            );
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::Position;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidTile {
+        line: usize,
+        column: usize,
+        tile: char,
+    },
+}
+
+impl std::error::Error for GridError {}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "grid is empty"),
+            Self::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} tiles, expected {expected} like the first line"
+            ),
+            Self::InvalidTile { line, column, tile } => {
+                write!(f, "invalid tile {tile:?} at line {line}, column {column}")
+            }
+        }
+    }
+}
+
+/// Dense rectangular map stored row by row.
+///
+/// Positions are `(x, y)` with `(0, 0)` in the upper-left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().map_or(0, Vec::len);
+
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(GridError::Ragged {
+                    line: y + 1,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses one tile per character, one row per line. Blank lines around
+    /// the grid are ignored.
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let rows = input
+            .trim_matches('\n')
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        tile(c).ok_or(GridError::InvalidTile {
+                            line: y + 1,
+                            column: x + 1,
+                            tile: c,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Lower-right corner of the grid.
+    pub fn max_position(&self) -> Position {
+        Position(self.width as i64 - 1, self.height as i64 - 1)
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        (0..self.width as i64).contains(&pos.0) && (0..self.height as i64).contains(&pos.1)
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.1 as usize * self.width + pos.0 as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Treats the grid as infinitely repeated in every direction.
+    pub fn get_wrapped(&self, pos: Position) -> &T {
+        &self[pos.modulus(self.width as i64, self.height as i64)]
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position(x as i64, y as i64)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find_map(|(pos, tile)| if predicate(tile) { Some(pos) } else { None })
+    }
+
+    /// Orthogonal neighbours inside the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        pos.neighbours4().filter(|&p| self.contains(p))
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        pos.neighbours8().filter(|&p| self.contains(p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Like [`Grid::map`], also passing the position of each tile.
+    pub fn map_with_position<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(pos, tile)| f(pos, tile)).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| (x, y)))
+            .map(|(x, y)| self.cells[y * self.width + x].clone())
+            .collect();
+
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let mut grid = self.transpose();
+
+        for row in grid.cells.chunks_mut(grid.width) {
+            row.reverse();
+        }
+
+        grid
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let mut grid = self.transpose();
+        let width = grid.width;
+
+        let rows: Vec<_> = grid.cells.chunks(width).rev().flatten().cloned().collect();
+        grid.cells = rows;
+
+        grid
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position out of grid: {pos}"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position out of grid: {pos}"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                tile.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "ab\ncd\nef\n";
+
+    fn sample() -> Grid<char> {
+        Grid::parse(SAMPLE, Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Position(1, 2)], 'f');
+        assert_eq!(grid.get(Position(2, 0)), None);
+        assert_eq!(grid.to_string(), SAMPLE);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Grid::parse("", Some), Err(GridError::Empty));
+
+        assert_eq!(
+            Grid::parse("ab\nc", Some),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+
+        assert_eq!(
+            Grid::parse("ab\nc?", |c| (c != '?').then_some(c)),
+            Err(GridError::InvalidTile {
+                line: 2,
+                column: 2,
+                tile: '?'
+            })
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = sample();
+
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "bdf\nace\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+
+        assert_eq!(grid.neighbours4(Position(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Position(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_get_wrapped() {
+        let grid = sample();
+
+        assert_eq!(*grid.get_wrapped(Position(-1, -1)), 'f');
+        assert_eq!(*grid.get_wrapped(Position(2, 3)), 'a');
+    }
+
+    #[test]
+    fn test_map_with_position() {
+        let grid = sample().map_with_position(|Position(x, y), &c| match x == y {
+            true => c.to_ascii_uppercase(),
+            false => c,
+        });
+
+        assert_eq!(grid.to_string(), "Ab\ncD\nef\n");
+    }
+}
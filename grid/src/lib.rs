@@ -0,0 +1,16 @@
+//! Shared 2D geometry for the grid based puzzles.
+//!
+//! - [`Position`] and [`Direction`] replace the per-day point types.
+//! - [`Grid`] is a dense, row-major map parsed from the puzzle text.
+//! - A `Grid<`[`Pixel`]`>` renders to a terminal, PPM or SVG, see
+//!   [`ImageFormat`].
+
+mod direction;
+mod grid;
+mod position;
+mod render;
+
+pub use direction::{Direction, Turn};
+pub use grid::{Grid, GridError};
+pub use position::Position;
+pub use render::{ImageFormat, Pixel, Rgb};
@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+use crate::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position(pub i64, pub i64);
+
+impl Position {
+    pub fn move_dir(self, dir: Direction) -> Self {
+        self.move_by(dir, 1)
+    }
+
+    pub fn move_by(self, dir: Direction, count: i64) -> Self {
+        let (dx, dy) = dir.delta();
+
+        Self(self.0 + dx * count, self.1 + dy * count)
+    }
+
+    /// Orthogonal neighbours, in the order of [`Direction::ALL`].
+    pub fn neighbours4(self) -> impl Iterator<Item = Position> {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| self.move_dir(dir))
+    }
+
+    /// Orthogonal and diagonal neighbours, row by row.
+    pub fn neighbours8(self) -> impl Iterator<Item = Position> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&d| d != (0, 0))
+            .map(move |(dx, dy)| Self(self.0 + dx, self.1 + dy))
+    }
+
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+
+    /// Wraps the position into `0..width` x `0..height`, so that an infinitely
+    /// repeated map can be indexed by its original tile.
+    pub fn modulus(self, width: i64, height: i64) -> Self {
+        Self(self.0.rem_euclid(width), self.1.rem_euclid(height))
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.0, self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_dir() {
+        assert_eq!(Position(0, 0).move_dir(Direction::Up), Position(0, -1));
+        assert_eq!(Position(0, 0).move_by(Direction::Right, 3), Position(3, 0));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(Position(1, 1).neighbours4().count(), 4);
+        assert_eq!(Position(1, 1).neighbours8().count(), 8);
+        assert!(!Position(1, 1).neighbours8().any(|p| p == Position(1, 1)));
+    }
+
+    #[test]
+    fn test_modulus() {
+        assert_eq!(Position(-1, 12).modulus(11, 11), Position(10, 1));
+    }
+}
@@ -0,0 +1,251 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::Grid;
+
+/// 24-bit colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const DARK_GREY: Self = Self(64, 64, 64);
+    pub const GREY: Self = Self(140, 140, 140);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const RED: Self = Self(230, 60, 50);
+    pub const ORANGE: Self = Self(245, 150, 40);
+    pub const YELLOW: Self = Self(250, 220, 60);
+    pub const GREEN: Self = Self(80, 200, 90);
+    pub const BLUE: Self = Self(70, 130, 230);
+
+    /// Colour `t` of the way from `self` to `other`, `t` being in `0..=1`.
+    pub fn mix(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Self(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// One tile of a rendered grid: the character shown in a terminal and the
+/// colour of both the character and the raster cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pixel {
+    pub glyph: char,
+    pub colour: Rgb,
+}
+
+impl Pixel {
+    pub const fn new(glyph: char, colour: Rgb) -> Self {
+        Self { glyph, colour }
+    }
+}
+
+impl Display for Pixel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.glyph.fmt(f)
+    }
+}
+
+/// Output of [`Grid::render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Coloured characters for a terminal supporting 24-bit colours.
+    Ansi,
+    /// Binary portable pixmap, a few pixels per tile.
+    Ppm,
+    /// Scalable vector graphics, one square per tile.
+    Svg,
+}
+
+impl ImageFormat {
+    /// File extension of the format, `None` for terminal output.
+    pub fn extension(self) -> Option<&'static str> {
+        match self {
+            Self::Ansi => None,
+            Self::Ppm => Some("ppm"),
+            Self::Svg => Some("svg"),
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Self::Ansi),
+            "ppm" => Ok(Self::Ppm),
+            "svg" => Ok(Self::Svg),
+            _ => Err(format!(
+                "unknown image format: {s}, expected ansi, ppm or svg"
+            )),
+        }
+    }
+}
+
+impl Display for ImageFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ansi => "ansi".fmt(f),
+            Self::Ppm => "ppm".fmt(f),
+            Self::Svg => "svg".fmt(f),
+        }
+    }
+}
+
+/// Side of a tile in PPM pixels.
+const PPM_SCALE: usize = 4;
+
+/// Side of a tile in SVG user units.
+const SVG_SCALE: usize = 8;
+
+impl Grid<Pixel> {
+    /// The grid encoded as `format`.
+    pub fn render(&self, format: ImageFormat) -> Vec<u8> {
+        match format {
+            ImageFormat::Ansi => self.to_ansi().into_bytes(),
+            ImageFormat::Ppm => self.to_ppm(),
+            ImageFormat::Svg => self.to_svg().into_bytes(),
+        }
+    }
+
+    /// One line per row, switching colour only where it changes.
+    pub fn to_ansi(&self) -> String {
+        let mut result = String::new();
+
+        for row in self.rows() {
+            let mut colour = None;
+
+            for pixel in row {
+                if colour != Some(pixel.colour) {
+                    let Rgb(r, g, b) = pixel.colour;
+                    result.push_str(&format!("\x1b[38;2;{r};{g};{b}m"));
+                    colour = Some(pixel.colour);
+                }
+
+                result.push(pixel.glyph);
+            }
+
+            result.push_str("\x1b[0m\n");
+        }
+
+        result
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let (width, height) = (self.width() * PPM_SCALE, self.height() * PPM_SCALE);
+
+        let mut result = format!("P6\n{width} {height}\n255\n").into_bytes();
+
+        for row in self.rows() {
+            for _ in 0..PPM_SCALE {
+                for pixel in row {
+                    let Rgb(r, g, b) = pixel.colour;
+
+                    for _ in 0..PPM_SCALE {
+                        result.extend([r, g, b]);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// One rectangle per run of tiles of the same colour within a row.
+    pub fn to_svg(&self) -> String {
+        let (width, height) = (self.width() * SVG_SCALE, self.height() * SVG_SCALE);
+
+        let mut result = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+            self.width(),
+            self.height()
+        );
+
+        for (y, row) in self.rows().enumerate() {
+            let mut x = 0;
+
+            for run in row.chunk_by(|a, b| a.colour == b.colour) {
+                result.push_str(&format!(
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" fill=\"{}\"/>\n",
+                    run.len(),
+                    run[0].colour.hex()
+                ));
+
+                x += run.len();
+            }
+        }
+
+        result.push_str("</svg>\n");
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<Pixel> {
+        let red = Pixel::new('#', Rgb::RED);
+        let black = Pixel::new('.', Rgb::BLACK);
+
+        Grid::from_rows(vec![vec![red, red, black], vec![black, red, black]]).unwrap()
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 0.0), Rgb::BLACK);
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 2.0), Rgb::WHITE);
+    }
+
+    #[test]
+    fn test_ansi() {
+        assert_eq!(
+            grid().to_ansi(),
+            "\x1b[38;2;230;60;50m##\x1b[38;2;0;0;0m.\x1b[0m\n\
+             \x1b[38;2;0;0;0m.\x1b[38;2;230;60;50m#\x1b[38;2;0;0;0m.\x1b[0m\n"
+        );
+        assert_eq!(grid().to_string(), "##.\n.#.\n");
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = grid().to_ppm();
+        let header = b"P6\n12 8\n255\n";
+
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 12 * 8 * 3);
+        assert_eq!(ppm[header.len()..header.len() + 3], [230, 60, 50]);
+        assert_eq!(ppm[ppm.len() - 3..], [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = grid().to_svg();
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect ").count(), 5);
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#e63c32\"/>"));
+    }
+
+    #[test]
+    fn test_format() {
+        for format in [ImageFormat::Ansi, ImageFormat::Ppm, ImageFormat::Svg] {
+            assert_eq!(format.to_string().parse(), Ok(format));
+        }
+
+        assert!("png".parse::<ImageFormat>().is_err());
+    }
+}